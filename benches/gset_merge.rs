@@ -0,0 +1,47 @@
+//! Compares `GSet::merge` with `GSet::merge_copy` on a million `u64`s.
+//!
+//! Run with: `cargo bench --bench gset_merge`
+
+use crdt::{Crdt, GSet};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ELEMENTS: u64 = 1_000_000;
+const RUNS: u32 = 20;
+
+/// Returns the median time of `merge` over `RUNS` runs, each on a fresh
+/// clone of `base`.
+fn median(base: &GSet<u64>, other: &GSet<u64>, merge: fn(&mut GSet<u64>, &GSet<u64>)) -> Duration {
+    let mut times: Vec<Duration> = (0..RUNS)
+        .map(|_| {
+            let mut set = base.clone();
+            let start = Instant::now();
+            merge(&mut set, black_box(other));
+            let elapsed = start.elapsed();
+            black_box(set);
+            elapsed
+        })
+        .collect();
+    times.sort();
+    times[times.len() / 2]
+}
+
+fn compare(name: &str, base: &GSet<u64>, other: &GSet<u64>) {
+    let clone = median(base, other, |set, other| set.merge(other));
+    let copy = median(base, other, |set, other| {
+        set.merge_copy(other);
+    });
+    println!("{name}: merge {clone:?}, merge_copy {copy:?}");
+}
+
+fn main() {
+    let other: GSet<u64> = (0..ELEMENTS).collect();
+    compare("into an empty set", &GSet::new(), &other);
+
+    let half: GSet<u64> = (ELEMENTS / 2..ELEMENTS * 3 / 2).collect();
+    compare(
+        "into a half-overlapping set of the same size",
+        &half,
+        &other,
+    );
+}
@@ -2,14 +2,39 @@ extern crate proc_macro;
 
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
-use syn::{Data, DataStruct, DeriveInput, Fields, parse_macro_input};
+use syn::punctuated::Punctuated;
+use syn::{Data, DataEnum, DataStruct, DeriveInput, Fields, Token, parse_macro_input};
 
 /// The entry point for the `Crdt` derive procedural macro.
 ///
 /// This macro provides an automatic implementation of the `Crdt` trait for structs.
 /// It implements the `merge` method by calling `merge` on each field of the struct
 /// individually. This effectively treats the struct as a "Product CRDT".
-#[proc_macro_derive(Crdt)]
+/// Fields marked `#[crdt(skip)]` (caches, labels, other metadata) are left
+/// untouched by `merge` and do not need to implement `Crdt`. Plain `Ord` fields
+/// can join the merge with `#[crdt(strategy = "max")]` or `#[crdt(strategy = "min")]`.
+///
+/// Enums are treated as "Sum CRDTs": when both sides are the same variant their
+/// fields are merged pairwise, and when they differ the higher-ranked variant wins.
+/// Variants are ranked by declaration order (later wins) unless every variant
+/// carries an explicit `#[crdt(rank = N)]`. This models state machines that only
+/// move forward; the `Default` variant should be the lowest-ranked one.
+///
+/// The generated `Value` is `Self`. A single-field newtype marked
+/// `#[crdt(transparent)]` instead reports its inner field's `Value`, and a
+/// struct marked `#[crdt(value_struct)]` gets a companion `{Name}Value` struct,
+/// as visible as the struct itself, holding each field's `Value` (strategy
+/// fields as-is, skipped fields omitted).
+///
+/// The derive bounds each field whose type mentions a generic parameter (see
+/// below). Where that is wrong, e.g. for recursive types, whose field bounds
+/// would require the impl being defined, `#[crdt(bound = "T: Crdt")]` replaces
+/// all generated bounds with the given predicates; the type's own where-clause
+/// is kept either way.
+///
+/// Structs also get a `Fields` implementation listing their sub-CRDT fields, so
+/// `properties::check_composite` can check each field on its own.
+#[proc_macro_derive(Crdt, attributes(crdt))]
 pub fn derive_crdt(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
@@ -36,19 +61,42 @@ pub fn derive_delta_sync(input: TokenStream) -> TokenStream {
     }
 }
 
+/// The entry point for the `Apply` derive procedural macro.
+///
+/// Generates an `Apply` implementation for structs with named fields by
+/// dispatching to the field an operation names. A companion enum `{Name}Op` is
+/// generated with one variant per field, in PascalCase, carrying that field's
+/// own `Op` and `Context`; the struct's `Context` is `()`. Fields marked
+/// `#[crdt(skip)]` or merged with a `strategy` are not CRDTs and get no variant.
+#[proc_macro_derive(Apply, attributes(crdt))]
+pub fn derive_apply(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match generate_apply_impl(input) {
+        Ok(token_stream) => token_stream.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
 /// Orchestrates the generation of the `Crdt` trait implementation.
 fn generate_crdt_impl(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let name = &input.ident;
-    let generics = &input.generics;
+    let container = ContainerAttrs::parse(&input)?;
+    let generics = match &container.bound {
+        Some(bound) => {
+            let mut generics = input.generics.clone();
+            generics
+                .make_where_clause()
+                .predicates
+                .extend(bound.iter().cloned());
+            generics
+        }
+        None => add_field_bounds(&input)?,
+    };
 
     let merge_body = match &input.data {
-        Data::Struct(data_struct) => generate_merge_body(data_struct),
-        Data::Enum(_) => {
-            return Err(syn::Error::new(
-                name.span(),
-                "Derive(Crdt) is currently only supported for structs. Enums require a custom merge strategy.",
-            ));
-        }
+        Data::Struct(data_struct) => generate_merge_body(data_struct)?,
+        Data::Enum(data_enum) => generate_enum_merge_body(data_enum)?,
         Data::Union(_) => {
             return Err(syn::Error::new(
                 name.span(),
@@ -59,21 +107,206 @@ fn generate_crdt_impl(input: DeriveInput) -> syn::Result<proc_macro2::TokenStrea
 
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
+    let fields_impl = match &input.data {
+        Data::Struct(data_struct) => {
+            let visits = generate_field_visits(data_struct)?;
+            Some(quote! {
+                impl #impl_generics crdt::Fields for #name #ty_generics #where_clause {
+                    fn visit_fields<__CrdtVisitor: crdt::FieldVisitor<Self>>(
+                        visitor: &mut __CrdtVisitor,
+                    ) {
+                        #visits
+                    }
+                }
+            })
+        }
+        _ => None,
+    };
+
+    let mut value_struct = None;
+    let (value_type, value_body) = if container.transparent {
+        let (member, ty) = transparent_field(&input)?;
+        (
+            quote! { <#ty as crdt::Crdt>::Value },
+            quote! { crdt::Crdt::value(&self.#member) },
+        )
+    } else if container.value_struct {
+        let vis = &input.vis;
+        let value_name = format_ident!("{}Value", name);
+        let value_fields = value_struct_fields(&input)?;
+        let field_names: Vec<_> = value_fields.iter().map(|(name, _, _)| name).collect();
+        let field_types = value_fields.iter().map(|(_, ty, _)| ty);
+        let field_values = value_fields.iter().map(|(_, _, value)| value);
+        value_struct = Some(quote! {
+            // Generated value struct, one `Value` per merged field
+            #[derive(Debug, Clone, PartialEq)]
+            #vis struct #value_name #impl_generics #where_clause {
+                #( pub #field_names: #field_types ),*
+            }
+        });
+        (
+            quote! { #value_name #ty_generics },
+            quote! {
+                #value_name {
+                    #( #field_names: #field_values ),*
+                }
+            },
+        )
+    } else {
+        (quote! { Self }, quote! { self.clone() })
+    };
+
     Ok(quote! {
+        #value_struct
+
         impl #impl_generics crdt::Crdt for #name #ty_generics #where_clause {
-            type Value = Self;
+            type Value = #value_type;
 
             fn merge(&mut self, other: &Self) {
                 #merge_body
             }
 
             fn value(&self) -> Self::Value {
-                self.clone()
+                #value_body
             }
         }
+
+        #fields_impl
     })
 }
 
+/// Options parsed from the `#[crdt(...)]` attributes on the type itself.
+struct ContainerAttrs {
+    /// Forward `value()` to the only field (`#[crdt(transparent)]`).
+    transparent: bool,
+    /// Generate a `{Name}Value` struct of field values (`#[crdt(value_struct)]`).
+    value_struct: bool,
+    /// Predicates replacing the generated field bounds (`#[crdt(bound = "...")]`).
+    bound: Option<Vec<syn::WherePredicate>>,
+}
+
+impl ContainerAttrs {
+    fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let mut attrs = ContainerAttrs {
+            transparent: false,
+            value_struct: false,
+            bound: None,
+        };
+        for attr in input.attrs.iter().filter(|a| a.path().is_ident("crdt")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("transparent") {
+                    attrs.transparent = true;
+                    Ok(())
+                } else if meta.path.is_ident("value_struct") {
+                    attrs.value_struct = true;
+                    Ok(())
+                } else if meta.path.is_ident("bound") {
+                    let lit: syn::LitStr = meta.value()?.parse()?;
+                    let predicates = lit.parse_with(
+                        Punctuated::<syn::WherePredicate, Token![,]>::parse_terminated,
+                    )?;
+                    attrs
+                        .bound
+                        .get_or_insert_with(Vec::new)
+                        .extend(predicates);
+                    Ok(())
+                } else {
+                    Err(meta.error(
+                        "unsupported container attribute; expected `transparent`, `value_struct` or `bound`",
+                    ))
+                }
+            })?;
+        }
+        if attrs.transparent && attrs.value_struct {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "`transparent` and `value_struct` cannot be combined: each picks a different `Value`",
+            ));
+        }
+        Ok(attrs)
+    }
+}
+
+/// Returns the names, value types and value expressions of a
+/// `#[crdt(value_struct)]` struct's fields.
+///
+/// CRDT fields contribute their `Value`, strategy fields a clone of themselves,
+/// and skipped fields nothing, since they are not part of the replicated state.
+fn value_struct_fields(
+    input: &DeriveInput,
+) -> syn::Result<
+    Vec<(
+        &syn::Ident,
+        proc_macro2::TokenStream,
+        proc_macro2::TokenStream,
+    )>,
+> {
+    let Data::Struct(DataStruct {
+        fields: Fields::Named(fields),
+        ..
+    }) = &input.data
+    else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "`#[crdt(value_struct)]` requires a struct with named fields",
+        ));
+    };
+
+    let mut value_fields = Vec::new();
+    for field in &fields.named {
+        let attrs = FieldAttrs::parse(field)?;
+        if attrs.skip {
+            continue;
+        }
+        let name = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+        value_fields.push(match attrs.strategy {
+            MergeStrategy::Crdt => (
+                name,
+                quote! { <#ty as crdt::Crdt>::Value },
+                quote! { crdt::Crdt::value(&self.#name) },
+            ),
+            MergeStrategy::Max | MergeStrategy::Min => (
+                name,
+                quote! { #ty },
+                quote! { ::core::clone::Clone::clone(&self.#name) },
+            ),
+        });
+    }
+    Ok(value_fields)
+}
+
+/// Returns the member and type of a `#[crdt(transparent)]` struct's only field.
+///
+/// The field must be merged as a CRDT, since its `Value` becomes the struct's.
+fn transparent_field(input: &DeriveInput) -> syn::Result<(syn::Member, &syn::Type)> {
+    let error = || {
+        syn::Error::new(
+            input.ident.span(),
+            "`#[crdt(transparent)]` requires a struct with exactly one field",
+        )
+    };
+    let Data::Struct(data_struct) = &input.data else {
+        return Err(error());
+    };
+    let mut fields = data_struct.fields.iter();
+    let (Some(field), None) = (fields.next(), fields.next()) else {
+        return Err(error());
+    };
+    let attrs = FieldAttrs::parse(field)?;
+    if attrs.skip || !matches!(attrs.strategy, MergeStrategy::Crdt) {
+        return Err(syn::Error::new_spanned(
+            field,
+            "the field of a `#[crdt(transparent)]` struct must be merged as a CRDT",
+        ));
+    }
+    let member = match &field.ident {
+        Some(name) => syn::Member::Named(name.clone()),
+        None => syn::Member::Unnamed(syn::Index::from(0)),
+    };
+    Ok((member, &field.ty))
+}
+
 /// Orchestrates the generation of the `DeltaSync` trait implementation.
 fn generate_delta_sync_impl(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let name = &input.ident;
@@ -223,31 +456,410 @@ fn generate_delta_sync_impl(input: DeriveInput) -> syn::Result<proc_macro2::Toke
     }
 }
 
+/// Orchestrates the generation of the `Apply` trait implementation and its op enum.
+fn generate_apply_impl(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let vis = &input.vis;
+    let op_name = format_ident!("{}Op", name);
+
+    let fields = match &input.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => {
+            return Err(syn::Error::new(
+                name.span(),
+                "Derive(Apply) is currently only supported for structs with named fields.",
+            ));
+        }
+    };
+
+    let mut variants = Vec::new();
+    let mut field_names = Vec::new();
+    let mut field_types = Vec::new();
+    for field in fields {
+        let attrs = FieldAttrs::parse(field)?;
+        if attrs.skip || !matches!(attrs.strategy, MergeStrategy::Crdt) {
+            continue;
+        }
+        let field_name = field.ident.as_ref().unwrap();
+        variants.push(format_ident!("{}", pascal_case(&field_name.to_string())));
+        field_names.push(field_name);
+        field_types.push(&field.ty);
+    }
+
+    let mut generics = input.generics.clone();
+    let params: Vec<&syn::Ident> = input.generics.type_params().map(|p| &p.ident).collect();
+    for ty in &field_types {
+        if mentions_any(quote! { #ty }, &params) {
+            generics
+                .make_where_clause()
+                .predicates
+                .push(syn::parse_quote! { #ty: crdt::Apply });
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        /// An operation on one field, with the context that field's `apply` expects.
+        #[derive(Debug, Clone)]
+        #vis enum #op_name #impl_generics #where_clause {
+            #( #variants(<#field_types as crdt::Apply>::Op, <#field_types as crdt::Apply>::Context) ),*
+        }
+
+        impl #impl_generics crdt::Apply for #name #ty_generics #where_clause {
+            type Op = #op_name #ty_generics;
+            type Context = ();
+
+            fn apply(&mut self, op: Self::Op, _ctx: Self::Context) {
+                match op {
+                    #( #op_name::#variants(op, ctx) => crdt::Apply::apply(&mut self.#field_names, op, ctx), )*
+                }
+            }
+        }
+    })
+}
+
+/// Converts a `snake_case` field name to a `PascalCase` variant name.
+fn pascal_case(snake: &str) -> String {
+    snake
+        .trim_start_matches("r#")
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// How a single field participates in a derived `merge`.
+enum MergeStrategy {
+    /// Delegate to the field's own `Crdt::merge` (the default).
+    Crdt,
+    /// Keep the greater of the two values (`#[crdt(strategy = "max")]`).
+    Max,
+    /// Keep the lesser of the two values (`#[crdt(strategy = "min")]`).
+    Min,
+}
+
+/// Extends the user's where-clause with the bounds each merged field needs.
+///
+/// Like `#[derive(Clone)]`, only fields whose type mentions a generic parameter
+/// get a predicate: `crdt::Crdt` for fields merged as CRDTs, or `Ord + Clone`
+/// for `max`/`min` strategy fields.
+/// Skipped fields get no bound at all.
+///
+/// The `Crdt` supertraits are required of the type itself, so that skipped or
+/// strategy fields only need whatever the user's own derives ask of them.
+fn add_field_bounds(input: &DeriveInput) -> syn::Result<syn::Generics> {
+    let name = &input.ident;
+    let params: Vec<&syn::Ident> = input.generics.type_params().map(|p| &p.ident).collect();
+    let fields: Vec<&syn::Field> = match &input.data {
+        Data::Struct(data_struct) => data_struct.fields.iter().collect(),
+        Data::Enum(data_enum) => data_enum.variants.iter().flat_map(|v| &v.fields).collect(),
+        Data::Union(_) => Vec::new(),
+    };
+
+    let mut generics = input.generics.clone();
+    if params.is_empty() {
+        return Ok(generics);
+    }
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote! {
+            #name #ty_generics: ::core::clone::Clone
+                + ::core::fmt::Debug
+                + ::core::cmp::PartialEq
+        });
+    for field in fields {
+        let attrs = FieldAttrs::parse(field)?;
+        let ty = &field.ty;
+        if attrs.skip || !mentions_any(quote! { #ty }, &params) {
+            continue;
+        }
+        let predicate: syn::WherePredicate = match attrs.strategy {
+            MergeStrategy::Crdt => syn::parse_quote! { #ty: crdt::Crdt },
+            MergeStrategy::Max | MergeStrategy::Min => {
+                syn::parse_quote! { #ty: ::core::cmp::Ord + ::core::clone::Clone }
+            }
+        };
+        generics.make_where_clause().predicates.push(predicate);
+    }
+    Ok(generics)
+}
+
+/// Returns true if the token stream contains any of the given identifiers.
+fn mentions_any(tokens: proc_macro2::TokenStream, idents: &[&syn::Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(ident) => idents.iter().any(|i| **i == ident),
+        proc_macro2::TokenTree::Group(group) => mentions_any(group.stream(), idents),
+        _ => false,
+    })
+}
+
+/// Options parsed from a field's `#[crdt(...)]` attributes.
+struct FieldAttrs {
+    /// Leave the field untouched during merge (`#[crdt(skip)]`).
+    skip: bool,
+    strategy: MergeStrategy,
+}
+
+impl FieldAttrs {
+    fn parse(field: &syn::Field) -> syn::Result<Self> {
+        let mut attrs = FieldAttrs {
+            skip: false,
+            strategy: MergeStrategy::Crdt,
+        };
+        let mut has_strategy = false;
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("crdt")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    attrs.skip = true;
+                    Ok(())
+                } else if meta.path.is_ident("strategy") {
+                    let lit: syn::LitStr = meta.value()?.parse()?;
+                    attrs.strategy = match lit.value().as_str() {
+                        "max" => MergeStrategy::Max,
+                        "min" => MergeStrategy::Min,
+                        other => {
+                            return Err(syn::Error::new(
+                                lit.span(),
+                                format!(
+                                    "unknown merge strategy `{}`; expected \"max\" or \"min\"",
+                                    other
+                                ),
+                            ));
+                        }
+                    };
+                    has_strategy = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported field attribute; expected `skip` or `strategy`"))
+                }
+            })?;
+        }
+        if attrs.skip && has_strategy {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`skip` and `strategy` cannot be combined: a skipped field is never merged",
+            ));
+        }
+        Ok(attrs)
+    }
+
+    /// Generates the statement merging `rhs` into the place `lhs`, or `None` if skipped.
+    fn merge_stmt(
+        &self,
+        lhs: proc_macro2::TokenStream,
+        rhs: proc_macro2::TokenStream,
+    ) -> Option<proc_macro2::TokenStream> {
+        if self.skip {
+            return None;
+        }
+        Some(match self.strategy {
+            MergeStrategy::Crdt => quote! {
+                crdt::Crdt::merge(&mut #lhs, &#rhs);
+            },
+            MergeStrategy::Max => quote! {
+                if #rhs > #lhs {
+                    #lhs = ::core::clone::Clone::clone(&#rhs);
+                }
+            },
+            MergeStrategy::Min => quote! {
+                if #rhs < #lhs {
+                    #lhs = ::core::clone::Clone::clone(&#rhs);
+                }
+            },
+        })
+    }
+}
+
 /// Generates the code for the `merge` method's body based on the struct fields.
-fn generate_merge_body(data_struct: &DataStruct) -> proc_macro2::TokenStream {
-    match &data_struct.fields {
-        Fields::Named(fields) => {
-            let field_merges = fields.named.iter().map(|f| {
-                let name = &f.ident;
-                quote! {
-                    self.#name.merge(&other.#name);
+///
+/// Fields marked `#[crdt(skip)]` keep `self`'s value and need not implement `Crdt`;
+/// fields with a `strategy` only need to be `Ord + Clone`.
+fn generate_merge_body(data_struct: &DataStruct) -> syn::Result<proc_macro2::TokenStream> {
+    let mut field_merges = Vec::new();
+    for (i, field) in data_struct.fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(name) => syn::Member::Named(name.clone()),
+            None => syn::Member::Unnamed(syn::Index::from(i)),
+        };
+        field_merges.extend(
+            FieldAttrs::parse(field)?.merge_stmt(quote! { self.#member }, quote! { other.#member }),
+        );
+    }
+    Ok(quote! {
+        #( #field_merges )*
+    })
+}
+
+/// Generates the body of `Fields::visit_fields`, one visit per sub-CRDT field.
+///
+/// Skipped fields and fields with a `strategy` are plain values, not CRDTs, so
+/// they are left out.
+fn generate_field_visits(data_struct: &DataStruct) -> syn::Result<proc_macro2::TokenStream> {
+    let mut visits = Vec::new();
+    for (i, field) in data_struct.fields.iter().enumerate() {
+        let attrs = FieldAttrs::parse(field)?;
+        if attrs.skip || !matches!(attrs.strategy, MergeStrategy::Crdt) {
+            continue;
+        }
+        let (member, label) = match &field.ident {
+            Some(name) => (syn::Member::Named(name.clone()), name.to_string()),
+            None => (syn::Member::Unnamed(syn::Index::from(i)), i.to_string()),
+        };
+        let ty = &field.ty;
+        visits.push(quote! {
+            visitor.visit::<#ty>(#label, |state: &Self| &state.#member);
+        });
+    }
+    if visits.is_empty() {
+        return Ok(quote! {
+            let _ = visitor;
+        });
+    }
+    Ok(quote! {
+        #( #visits )*
+    })
+}
+
+/// Generates the `merge` body for an enum treated as a sum of CRDTs.
+///
+/// Differing variants resolve by rank; matching variants merge field by field.
+fn generate_enum_merge_body(data_enum: &DataEnum) -> syn::Result<proc_macro2::TokenStream> {
+    let ranks = variant_ranks(data_enum)?;
+
+    let rank_arms = data_enum
+        .variants
+        .iter()
+        .zip(&ranks)
+        .map(|(variant, rank)| {
+            let ident = &variant.ident;
+            quote! { Self::#ident { .. } => #rank, }
+        });
+
+    let mut merge_arms = Vec::new();
+    for variant in &data_enum.variants {
+        let ident = &variant.ident;
+        // Bind each merged field on both sides; skipped fields are not bound at all.
+        let mut self_pats = Vec::new();
+        let mut other_pats = Vec::new();
+        let mut merges = Vec::new();
+        for (i, field) in variant.fields.iter().enumerate() {
+            let self_name = format_ident!("__self_{}", i);
+            let other_name = format_ident!("__other_{}", i);
+            let merge = FieldAttrs::parse(field)?
+                .merge_stmt(quote! { *#self_name }, quote! { *#other_name });
+            match (&field.ident, &merge) {
+                (Some(name), Some(_)) => {
+                    self_pats.push(quote! { #name: #self_name });
+                    other_pats.push(quote! { #name: #other_name });
+                }
+                (Some(_), None) => {}
+                (None, Some(_)) => {
+                    self_pats.push(quote! { #self_name });
+                    other_pats.push(quote! { #other_name });
+                }
+                (None, None) => {
+                    self_pats.push(quote! { _ });
+                    other_pats.push(quote! { _ });
                 }
-            });
-            quote! {
-                #( #field_merges )*
             }
+            merges.extend(merge);
         }
-        Fields::Unnamed(fields) => {
-            let field_merges = fields.unnamed.iter().enumerate().map(|(i, _)| {
-                let index = syn::Index::from(i);
-                quote! {
-                    self.#index.merge(&other.#index);
+        merge_arms.push(match &variant.fields {
+            Fields::Named(_) => quote! {
+                (Self::#ident { #( #self_pats, )* .. }, Self::#ident { #( #other_pats, )* .. }) => {
+                    #( #merges )*
                 }
-            });
-            quote! {
-                #( #field_merges )*
+            },
+            Fields::Unnamed(_) => quote! {
+                (Self::#ident ( #( #self_pats ),* ), Self::#ident ( #( #other_pats ),* )) => {
+                    #( #merges )*
+                }
+            },
+            Fields::Unit => quote! {
+                (Self::#ident, Self::#ident) => {}
+            },
+        });
+    }
+
+    Ok(quote! {
+        let variant_rank = |state: &Self| -> u32 {
+            match state {
+                #( #rank_arms )*
+            }
+        };
+        let self_rank = variant_rank(self);
+        let other_rank = variant_rank(other);
+        if self_rank != other_rank {
+            // Different variants: the higher-ranked state wins outright.
+            if other_rank > self_rank {
+                *self = ::core::clone::Clone::clone(other);
             }
+            return;
+        }
+        match (self, other) {
+            #( #merge_arms )*
+            _ => unreachable!("variants with equal rank are the same variant"),
+        }
+    })
+}
+
+/// Computes the rank of each enum variant.
+///
+/// Without attributes the declaration order is used. Once any variant carries
+/// `#[crdt(rank = N)]`, every variant must, and no two may share a rank, since
+/// a cross-variant merge between equal ranks would have no deterministic winner.
+fn variant_ranks(data_enum: &DataEnum) -> syn::Result<Vec<u32>> {
+    let mut explicit = Vec::new();
+    for variant in &data_enum.variants {
+        let mut rank = None;
+        for attr in variant.attrs.iter().filter(|a| a.path().is_ident("crdt")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rank") {
+                    let lit: syn::LitInt = meta.value()?.parse()?;
+                    rank = Some(lit.base10_parse::<u32>()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported variant attribute; expected `rank = N`"))
+                }
+            })?;
+        }
+        explicit.push(rank);
+    }
+
+    if explicit.iter().all(Option::is_none) {
+        return Ok((0..data_enum.variants.len() as u32).collect());
+    }
+
+    let mut ranks: Vec<u32> = Vec::new();
+    for (variant, rank) in data_enum.variants.iter().zip(&explicit) {
+        let Some(rank) = *rank else {
+            return Err(syn::Error::new(
+                variant.ident.span(),
+                "missing `#[crdt(rank = N)]`: once any variant declares a rank, every variant must, \
+                 otherwise cross-variant merges would be ambiguous",
+            ));
+        };
+        if let Some(pos) = ranks.iter().position(|&r| r == rank) {
+            let first = &data_enum.variants[pos].ident;
+            return Err(syn::Error::new(
+                variant.ident.span(),
+                format!(
+                    "variants `{}` and `{}` share rank {}, so a merge between them would be ambiguous",
+                    first, variant.ident, rank
+                ),
+            ));
         }
-        Fields::Unit => quote! {},
+        ranks.push(rank);
     }
+    Ok(ranks)
 }
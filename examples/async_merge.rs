@@ -0,0 +1,76 @@
+//! Folding an async stream of remote states into a local CRDT.
+//!
+//! Run with: `cargo run --example async_merge --features futures`
+
+use crdt::{Crdt, GCounter, MergeStreamExt};
+use futures::executor::block_on;
+use futures::stream;
+
+fn main() {
+    println!("--- Async Merge ---");
+
+    let mut local = GCounter::new();
+    local.add(2, "local".to_string());
+
+    // States as they might arrive from peers over the network.
+    let mut peer = GCounter::new();
+    peer.add(3, "peer".to_string());
+    let stale = local.clone();
+    let incoming = stream::iter([peer.clone(), stale, peer]);
+
+    let changed = block_on(local.merge_stream(incoming));
+    println!("{changed} of 3 incoming states changed the counter");
+    println!("local = {}", local.value());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn merge_stream_counts_only_changing_states() {
+        let mut local = GCounter::new();
+        local.add(5, "a".to_string());
+
+        let mut behind = GCounter::new();
+        behind.add(1, "a".to_string());
+        let mut ahead = local.clone();
+        ahead.add(2, "b".to_string());
+
+        let states = [behind, ahead.clone(), ahead.clone()];
+        let changed = block_on(local.merge_stream(stream::iter(states)));
+
+        assert_eq!(changed, 1);
+        assert_eq!(local, ahead);
+    }
+
+    #[test]
+    fn merge_stream_of_empty_stream_is_noop() {
+        let mut local = GCounter::new();
+        local.inc("a".to_string());
+        let before = local.clone();
+
+        let changed = block_on(local.merge_stream(stream::empty()));
+
+        assert_eq!(changed, 0);
+        assert_eq!(local, before);
+    }
+
+    proptest! {
+        #[test]
+        fn merge_stream_matches_sequential_merge(
+            start in any::<GCounter<String>>(),
+            states in proptest::collection::vec(any::<GCounter<String>>(), 0..6),
+        ) {
+            let mut expected = start.clone();
+            for state in &states {
+                expected.merge(state);
+            }
+
+            let mut merged = start;
+            block_on(merged.merge_stream(stream::iter(states)));
+            prop_assert_eq!(merged, expected);
+        }
+    }
+}
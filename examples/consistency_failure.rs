@@ -1,4 +1,4 @@
-use crdt::{Crdt, properties};
+use crdt::{Bottom, Crdt, properties};
 use proptest::prelude::*;
 
 /// A "broken" CRDT that violates the Commutativity property.
@@ -22,6 +22,12 @@ impl Crdt for BrokenCrdt {
     fn value(&self) -> Self::Value {
         self.value
     }
+
+    // The order this type is *meant* to have: a max-register grows over time.
+    // Because it doesn't go through `merge`, it exposes the broken merge.
+    fn leq(&self, other: &Self) -> bool {
+        self.value <= other.value
+    }
 }
 
 // Implement Arbitrary for BrokenCrdt to support property-based testing.
@@ -34,6 +40,38 @@ impl Arbitrary for BrokenCrdt {
     }
 }
 
+/// A min-register whose default (0) is the *top* of its order, not the bottom.
+///
+/// Its merge is a perfectly good semilattice join, but merging a fresh replica
+/// into any state resets it to 0.
+#[derive(Debug, Clone, PartialEq, Default)]
+struct MinWithZeroDefault {
+    value: u32,
+}
+
+impl Crdt for MinWithZeroDefault {
+    type Value = u32;
+
+    fn merge(&mut self, other: &Self) {
+        self.value = self.value.min(other.value);
+    }
+
+    fn value(&self) -> Self::Value {
+        self.value
+    }
+}
+
+impl Arbitrary for MinWithZeroDefault {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (1u32..)
+            .prop_map(|value| MinWithZeroDefault { value })
+            .boxed()
+    }
+}
+
 fn main() {
     println!("--- Consistency Failure Example ---");
     println!(
@@ -76,6 +114,14 @@ fn main() {
     } else {
         println!("\n[!] Unexpectedly passed! (This shouldn't happen for BrokenCrdt)");
     }
+
+    // A valid merge is not enough: the default state must also be the bottom.
+    let mut state = MinWithZeroDefault { value: 7 };
+    state.merge(&MinWithZeroDefault::init());
+    println!(
+        "\nMerging a fresh MinWithZeroDefault into value 7 gives {}, losing the state.",
+        state.value()
+    );
 }
 
 #[cfg(test)]
@@ -87,4 +133,50 @@ mod tests {
     fn test_broken_crdt_fails() {
         properties::check_commutativity::<BrokenCrdt>();
     }
+
+    #[test]
+    #[should_panic(expected = "Monotonicity failed")]
+    fn test_broken_crdt_fails_monotonicity() {
+        properties::check_monotonicity::<BrokenCrdt>();
+    }
+
+    #[test]
+    #[should_panic(expected = "Seed: [7, 7, 7")]
+    fn test_broken_crdt_fails_seeded() {
+        properties::check_eventual_consistency_seeded::<BrokenCrdt>([7; 32]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Commutativity failed")]
+    fn test_broken_crdt_fails_on_fuzz_input() {
+        // Two different little-endian u32s.
+        properties::fuzz_merge_commutativity::<BrokenCrdt>(&[1, 0, 0, 0, 2, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_broken_crdt_reports_all_violations() {
+        let violations = properties::try_check_eventual_consistency::<BrokenCrdt>()
+            .expect_err("BrokenCrdt should violate CRDT properties");
+        let failed: Vec<_> = violations.iter().map(|v| v.property).collect();
+        assert!(failed.contains(&"Commutativity"));
+        assert!(failed.contains(&"Monotonicity"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Bottom neutrality failed")]
+    fn test_non_neutral_default_fails() {
+        properties::check_bottom_is_neutral::<MinWithZeroDefault>();
+    }
+
+    #[test]
+    fn test_violation_falls_back_to_debug_output() {
+        // BrokenCrdt has no `CrdtDebug` summary, so its inputs print with `{:#?}`.
+        let violation = properties::try_check_commutativity::<BrokenCrdt>()
+            .expect_err("BrokenCrdt should violate commutativity");
+        assert!(
+            violation
+                .counterexample
+                .contains("A = BrokenCrdt {\n    value: ")
+        );
+    }
 }
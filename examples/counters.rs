@@ -0,0 +1,776 @@
+//! Counter CRDTs beyond the grow-only `GCounter`.
+//!
+//! Run with: `cargo run --example counters`
+
+use crdt::properties;
+use crdt::{
+    CappedGCounter, Crdt, DeltaCrdt, GCounter, PNCounter, ResettableCounter, ThresholdCounter,
+};
+
+fn main() {
+    println!("--- PN-Counter ---");
+
+    let mut node_a = PNCounter::new();
+    let mut node_b = PNCounter::new();
+
+    // Node A adds three items to a cart, node B removes one concurrently.
+    node_a.add(3, "a".to_string());
+    node_b.dec("b".to_string());
+
+    println!("Node A: {}", node_a.value());
+    println!("Node B: {}", node_b.value());
+
+    node_a.merge(&node_b);
+    node_b.merge(&node_a);
+
+    println!("After sync: A = {}, B = {}", node_a.value(), node_b.value());
+    assert_eq!(node_a.value(), 2);
+    assert_eq!(node_a, node_b);
+
+    println!("\nRunning property tests for PNCounter...");
+    properties::check_delta_sync_properties::<PNCounter<String>>();
+    println!("PNCounter satisfies all CRDT and delta sync properties!");
+
+    println!("\n--- Shipping G-Counter deltas ---");
+
+    let mut sender = GCounter::new();
+    let mut receiver = GCounter::new();
+    for i in 0..10 {
+        sender.add(100, format!("seed-{i}"));
+    }
+    receiver.merge(&sender);
+    sender.reset_delta();
+
+    // Only the entry touched since the last reset travels.
+    sender.inc("seed-3".to_string());
+    let delta = sender.delta();
+    println!("Delta after one increment: {delta:?}");
+    receiver.merge_delta(&delta);
+    sender.reset_delta();
+
+    assert_eq!(receiver, sender);
+    println!(
+        "Receiver converged to {} from a one-entry delta.",
+        receiver.value()
+    );
+
+    println!("\n--- Capped G-Counter ---");
+
+    // Two gateways share a budget of 100 requests.
+    let mut gateway_a = CappedGCounter::new(100);
+    let mut gateway_b = CappedGCounter::new(100);
+    gateway_a.add(70, "a".to_string());
+    gateway_b.add(60, "b".to_string());
+    gateway_a.merge(&gateway_b);
+    println!(
+        "Reported {} of {} requests served",
+        gateway_a.value(),
+        gateway_a.uncapped_value()
+    );
+    assert_eq!(gateway_a.value(), 100);
+
+    println!("\n--- Threshold Counter ---");
+
+    // Roll a feature out once 50 users across both regions have opted in.
+    let mut region_a = ThresholdCounter::new(50);
+    let mut region_b = ThresholdCounter::new(50);
+    region_a.add(30, "a".to_string());
+    region_b.add(25, "b".to_string());
+    assert!(!region_a.reached());
+
+    region_b.merge(&region_a);
+    println!("Opted in: {:?}", region_b.value());
+    assert_eq!(region_b.value(), (55, true));
+
+    println!("\n--- Resettable Counter ---");
+
+    // An error counter that operators clear, while another node keeps counting.
+    let mut node_a = ResettableCounter::new();
+    node_a.add(4, "a".to_string());
+    let mut node_b = node_a.clone();
+
+    node_a.reset();
+    node_b.inc("b".to_string());
+
+    node_a.merge(&node_b);
+    node_b.merge(&node_a);
+    println!("After reset and a concurrent error: {}", node_a.value());
+    assert_eq!(node_a.value(), 1);
+    assert_eq!(node_a, node_b);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crdt::{CounterOverflow, CrdtDebug};
+    use proptest::prelude::*;
+
+    #[test]
+    fn pncounter_eventual_consistency() {
+        properties::check_eventual_consistency::<PNCounter<String>>();
+    }
+
+    #[test]
+    fn pncounter_delta_sync_properties() {
+        properties::check_delta_sync_properties::<PNCounter<String>>();
+    }
+
+    #[test]
+    fn pncounter_can_go_negative() {
+        let mut counter = PNCounter::new();
+        counter.inc("x".to_string());
+        counter.add(-4, "y".to_string());
+        counter.dec("x".to_string());
+        assert_eq!(counter.value(), -4);
+    }
+
+    #[test]
+    fn gcounter_leq_matches_merge() {
+        properties::check_leq_consistency::<GCounter<String>>();
+
+        let mut old = GCounter::new();
+        old.add(2, "a");
+        let mut new = old.clone();
+        new.inc("b");
+        assert!(old.leq(&new));
+        assert!(!new.leq(&old));
+    }
+
+    proptest! {
+        /// Shipping the delta after every batch of local updates converges to the
+        /// same state as merging the sender's full state.
+        #[test]
+        fn gcounter_deltas_match_full_merge(
+            base in any::<GCounter<String>>(),
+            peer in any::<GCounter<String>>(),
+            batches in proptest::collection::vec(
+                proptest::collection::vec(("[a-c]", 1u64..10), 0..4),
+                0..4,
+            ),
+        ) {
+            let mut sender = base.clone();
+            sender.reset_delta();
+            let mut via_deltas = peer.clone();
+            via_deltas.merge(&base);
+
+            for batch in batches {
+                for (replica, amount) in batch {
+                    sender.add(amount, replica);
+                }
+                via_deltas.merge_delta(&sender.delta());
+                sender.reset_delta();
+            }
+
+            let mut via_full = peer;
+            via_full.merge(&sender);
+            prop_assert_eq!(via_deltas, via_full);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn gcounter_serde_recomputes_cache() {
+        let mut counter = GCounter::new();
+        counter.add(5, "a".to_string());
+        counter.add(7, "b".to_string());
+
+        let json = serde_json::to_string(&counter).unwrap();
+        let restored: GCounter<String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, counter);
+        assert_eq!(restored.value(), 12);
+
+        // A hand-edited file cannot desync the cached sum from the counts.
+        let edited: GCounter<String> =
+            serde_json::from_str(r#"{"counts":{"a":1,"b":2},"cached_value":100}"#).unwrap();
+        assert_eq!(edited.value(), 3);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn gcounter_deserialize_rejects_overflowing_total() {
+        let result = serde_json::from_str::<GCounter<String>>(
+            r#"{"counts":{"a":18446744073709551615,"b":1}}"#,
+        );
+        assert!(result.unwrap_err().to_string().contains("overflowed"));
+    }
+
+    #[test]
+    fn gcounter_merge_saturates_and_try_merge_refuses_overflow() {
+        let mut a = GCounter::new();
+        a.add(u64::MAX, "a");
+        let mut b = GCounter::new();
+        b.add(1, "b");
+
+        let mut refused = a.clone();
+        assert_eq!(refused.try_merge(&b), Err(CounterOverflow));
+        assert_eq!(refused, a);
+
+        let merged = a.merged(&b);
+        assert_eq!(merged.contribution(&"b"), 1);
+        assert_eq!(merged.value(), u64::MAX);
+        assert_eq!(b.merged(&a).value(), u64::MAX);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn gcounter_recompute_cache_fixes_injected_value() {
+        // The cache is private, so the only way to inject a wrong value is a
+        // stored payload that claims one; loading recomputes it from `counts`.
+        let mut loaded: GCounter<String> =
+            serde_json::from_str(r#"{"counts":{"a":4},"cached_value":999}"#).unwrap();
+        assert_eq!(loaded.value(), 4);
+
+        loaded.recompute_cache();
+        assert_eq!(loaded.value(), 4);
+    }
+
+    #[test]
+    fn gcounter_recompute_cache_is_idempotent() {
+        let mut counter = GCounter::new();
+        counter.add(3, "a");
+        counter.inc("b");
+        counter.recompute_cache();
+        assert_eq!(counter.value(), 4);
+    }
+
+    #[test]
+    fn gcounter_try_add_rejects_overflow() {
+        let mut counter = GCounter::new();
+        counter.add(u64::MAX - 1, "a");
+        assert_eq!(counter.try_add(1, "a"), Ok(()));
+        assert_eq!(counter.try_add(1, "a"), Err(CounterOverflow));
+
+        // The total overflows even though the new replica's own entry would not.
+        assert_eq!(counter.try_add(1, "b"), Err(CounterOverflow));
+        assert_eq!(counter.value(), u64::MAX);
+        assert!(!crdt::DeltaSync::summary(&counter).contains_key("b"));
+    }
+
+    #[test]
+    fn gcounter_try_apply_rejects_overflow() {
+        use crdt::TryApply;
+
+        let mut counter = GCounter::new();
+        assert_eq!(counter.try_apply(u64::MAX, "a"), Ok(()));
+        assert_eq!(counter.try_apply(1, "b"), Err(CounterOverflow));
+        assert_eq!(counter.value(), u64::MAX);
+    }
+
+    proptest! {
+        #[test]
+        fn gcounter_join_all_is_order_independent(
+            counters in proptest::collection::vec(any::<GCounter<String>>(), 0..5),
+        ) {
+            let forward = crdt::join_all(counters.clone());
+            let backward = crdt::join_all(counters.iter().rev().cloned());
+            prop_assert_eq!(&forward, &backward);
+
+            if let Some(first) = counters.first() {
+                let mut pairwise = first.clone();
+                for counter in &counters[1..] {
+                    pairwise = pairwise.merged(counter);
+                }
+                prop_assert_eq!(forward, Some(pairwise));
+            } else {
+                prop_assert_eq!(forward, None);
+            }
+        }
+
+        #[test]
+        fn gcounter_merge_all_is_order_independent(
+            counters in proptest::collection::vec(any::<GCounter<String>>(), 0..5),
+            order in proptest::collection::vec(any::<u64>(), 5),
+        ) {
+            let mut keyed: Vec<_> = order.into_iter().zip(&counters).collect();
+            keyed.sort_by_key(|(key, _)| *key);
+            let shuffled = crdt::merge_all(keyed.into_iter().map(|(_, counter)| counter));
+
+            let forward = crdt::merge_all(&counters);
+            prop_assert_eq!(&forward, &crdt::merge_all(counters.iter().rev()));
+            prop_assert_eq!(&forward, &shuffled);
+            prop_assert_eq!(forward, crdt::join_all(counters));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn gcounter_canonical_bytes_ignore_insertion_order() {
+        let replicas = ["d", "a", "c", "b", "e", "f"];
+        let mut forward = GCounter::new();
+        let mut backward = GCounter::new();
+        for (amount, replica) in replicas.iter().enumerate() {
+            forward.add(amount as u64 + 1, replica.to_string());
+        }
+        for (amount, replica) in replicas.iter().enumerate().rev() {
+            backward.add(amount as u64 + 1, replica.to_string());
+        }
+
+        assert_eq!(forward.canonical_bytes(), backward.canonical_bytes());
+        assert_eq!(
+            forward.canonical_bytes(),
+            br#"[["a",2],["b",4],["c",3],["d",1],["e",5],["f",6]]"#
+        );
+
+        // IDs that could not be JSON object keys work too.
+        let mut tuples = GCounter::new();
+        tuples.add(3, (1u8, 2u8));
+        assert_eq!(tuples.canonical_bytes(), br#"[[[1,2],3]]"#);
+    }
+
+    proptest! {
+        /// Equal counters hash equal, even when rebuilt in a different order.
+        #[test]
+        fn gcounter_equal_states_hash_equal(
+            a in any::<GCounter<String>>(),
+            b in any::<GCounter<String>>(),
+        ) {
+            let mut rebuilt = GCounter::new();
+            let mut entries: Vec<_> = crdt::DeltaSync::summary(&a).into_iter().collect();
+            entries.reverse();
+            for (replica, count) in entries {
+                rebuilt.add(count, replica);
+            }
+            prop_assert_eq!(&rebuilt, &a);
+            prop_assert_eq!(rebuilt.state_hash(), a.state_hash());
+
+            if a == b {
+                prop_assert_eq!(a.state_hash(), b.state_hash());
+            }
+        }
+    }
+
+    #[test]
+    fn capped_gcounter_eventual_consistency() {
+        properties::check_eventual_consistency::<CappedGCounter<String>>();
+    }
+
+    #[test]
+    fn threshold_counter_eventual_consistency() {
+        properties::check_eventual_consistency::<ThresholdCounter<String>>();
+        properties::check_leq_consistency::<ThresholdCounter<String>>();
+    }
+
+    proptest! {
+        /// Once the flag is set on any replica, every replica that merges with
+        /// it keeps the flag, whatever order the states arrive in.
+        #[test]
+        fn threshold_counter_flag_is_monotonic(
+            (states, shuffled) in proptest::collection::vec(any::<ThresholdCounter<String>>(), 1..5)
+                .prop_flat_map(|states| (Just(states.clone()), Just(states).prop_shuffle())),
+        ) {
+            let mut forward = ThresholdCounter::default();
+            for state in &states {
+                let was_reached = forward.reached();
+                forward.merge(state);
+                prop_assert!(forward.reached() || !was_reached);
+            }
+            let mut reordered = ThresholdCounter::default();
+            for state in &shuffled {
+                reordered.merge(state);
+            }
+            prop_assert_eq!(forward.value(), reordered.value());
+            prop_assert_eq!(
+                forward.reached(),
+                states.iter().any(|state| state.reached()) || forward.value().0 >= 100
+            );
+        }
+    }
+
+    #[test]
+    fn capped_gcounter_replicas_over_cap_converge() {
+        let mut node_a = CappedGCounter::new(10);
+        let mut node_b = CappedGCounter::new(10);
+        node_a.add(8, "a");
+        node_b.add(7, "b");
+        assert_eq!(node_a.value(), 8);
+
+        node_a.merge(&node_b);
+        node_b.merge(&node_a);
+        assert_eq!(node_a, node_b);
+        assert_eq!(node_a.value(), 10);
+        assert_eq!(node_b.value(), 10);
+
+        // Increments past the cap are still counted, just not reported.
+        assert_eq!(node_a.uncapped_value(), 15);
+        node_b.inc("b");
+        node_a.merge(&node_b);
+        assert_eq!(node_a.uncapped_value(), 16);
+        assert_eq!(node_a.value(), 10);
+    }
+
+    proptest! {
+        #[test]
+        fn capped_gcounter_never_exceeds_cap(
+            cap in 0u64..50,
+            adds in proptest::collection::vec(("[a-c]", 0u64..20), 0..8),
+        ) {
+            let mut counter = CappedGCounter::new(cap);
+            for (replica, amount) in adds {
+                counter.add(amount, replica);
+                prop_assert!(counter.value() <= cap);
+                prop_assert_eq!(counter.value(), counter.uncapped_value().min(cap));
+            }
+        }
+    }
+
+    #[test]
+    fn gcounter_merge_report_counts_raised_entries() {
+        let mut local = GCounter::new();
+        local.add(5, "a");
+        local.add(1, "b");
+        let mut remote = GCounter::new();
+        remote.add(3, "a");
+        remote.add(2, "b");
+        remote.inc("c");
+
+        let report = local.merge_report(&remote);
+        assert_eq!(
+            report,
+            crdt::MergeReport {
+                changed: true,
+                updated: 2,
+            }
+        );
+        assert_eq!(local.value(), 8);
+    }
+
+    proptest! {
+        /// Merging a state that is already dominated reports no change.
+        #[test]
+        fn gcounter_merge_report_dominated_is_unchanged(
+            a in any::<GCounter<String>>(),
+            b in any::<GCounter<String>>(),
+        ) {
+            let mut joined = a.merged(&b);
+            let report = joined.merge_report(&a);
+            prop_assert!(!report.changed);
+            prop_assert_eq!(report.updated, 0);
+            prop_assert_eq!(joined.merge_report(&b), crdt::MergeReport::default());
+        }
+    }
+
+    #[test]
+    fn gcounter_strategy_shrinks_to_minimal_counterexample() {
+        use crdt::arbitrary::gcounter_strategy;
+        use proptest::test_runner::{TestError, TestRunner};
+
+        // Deliberately false: most generated counters have several replicas.
+        let mut runner = TestRunner::deterministic();
+        let result = runner.run(&gcounter_strategy(0u8..10, 0..8), |counter| {
+            prop_assert!(counter.contributions().count() < 2);
+            Ok(())
+        });
+
+        let Err(TestError::Fail(_, minimal)) = result else {
+            panic!("the property should fail");
+        };
+        // Shrinking drops the extra replicas and lowers every count to 1.
+        assert_eq!(minimal.contributions().count(), 2);
+        assert!(minimal.contributions().all(|(_, count)| count == 1));
+    }
+
+    #[test]
+    fn gcounter_merging_dominated_state_changes_nothing() {
+        let mut counter = GCounter::new();
+        counter.add(5, "a");
+        counter.add(2, "b");
+        counter.reset_delta();
+        let mut behind = GCounter::new();
+        behind.add(3, "a");
+        let before = counter.clone();
+
+        counter.merge(&behind);
+        assert_eq!(counter, before);
+        assert_eq!(counter.value(), 7);
+        // Nothing was marked dirty, so there is nothing to relay.
+        assert_eq!(counter.delta(), GCounter::new());
+    }
+
+    #[test]
+    fn gcounter_contributions_report_each_replica() {
+        let mut counter = GCounter::new();
+        counter.add(3, "a");
+        counter.add(4, "b");
+        counter.inc("a");
+
+        assert_eq!(counter.contribution(&"a"), 4);
+        assert_eq!(counter.contribution(&"b"), 4);
+        assert_eq!(counter.contribution(&"unknown"), 0);
+
+        let mut contributions: Vec<_> = counter.contributions().collect();
+        contributions.sort();
+        assert_eq!(contributions, vec![(&"a", 4), (&"b", 4)]);
+        assert_eq!(
+            counter.contributions().map(|(_, count)| count).sum::<u64>(),
+            counter.value()
+        );
+    }
+
+    proptest! {
+        /// Merging raw `(replica, count)` pairs matches building a counter from
+        /// them and merging that.
+        #[test]
+        fn gcounter_merge_entries_matches_merge(
+            local in any::<GCounter<String>>(),
+            entries in proptest::collection::hash_map("[a-c]".prop_map(String::from), 0u64..100, 0..4),
+        ) {
+            let mut remote = GCounter::new();
+            for (replica, &count) in &entries {
+                remote.add(count, replica.clone());
+            }
+            let mut expected = local.clone();
+            let expected_report = expected.merge_report(&remote);
+
+            let mut actual = local;
+            let report = actual.merge_entries(entries.iter().map(|(replica, &count)| (replica, count)));
+            prop_assert_eq!(report, expected_report);
+            prop_assert_eq!(actual.value(), expected.value());
+            prop_assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn gcounter_from_iterator_keeps_max_per_replica() {
+        let mut counter: GCounter<&str> = vec![("a", 3), ("b", 2), ("a", 1)].into_iter().collect();
+        assert_eq!(counter.contribution(&"a"), 3);
+        assert_eq!(counter.value(), 5);
+
+        counter.extend([("b", 7), ("c", 1)]);
+        assert_eq!(counter.value(), 11);
+        assert_eq!(
+            counter,
+            [("c", 1), ("b", 7), ("a", 3)].into_iter().collect()
+        );
+    }
+
+    #[test]
+    fn gcounter_partial_cmp_orders_by_dominance() {
+        use std::cmp::Ordering;
+
+        let a: GCounter<&str> = [("a", 2), ("b", 1)].into_iter().collect();
+        // An explicit zero entry compares like a missing one.
+        let mut same = a.clone();
+        same.add(0, "c");
+        assert_eq!(a.partial_cmp(&same), Some(Ordering::Equal));
+        assert_eq!(a, same);
+        assert_eq!(a.state_hash(), same.state_hash());
+
+        let ahead: GCounter<&str> = [("a", 3), ("b", 1)].into_iter().collect();
+        assert!(a < ahead);
+        assert!(ahead > a);
+
+        let concurrent: GCounter<&str> = [("a", 1), ("b", 5)].into_iter().collect();
+        assert_eq!(a.partial_cmp(&concurrent), None);
+        assert!(!(a <= concurrent) && !(a >= concurrent));
+    }
+
+    #[test]
+    fn traced_gcounter_records_last_merge() {
+        use crdt::{ChangeEvent, TracedCrdt};
+
+        let mut local = TracedCrdt::new(GCounter::new());
+        local.inner_mut().add(3, "a");
+        local.inner_mut().add(1, "b");
+        let remote: GCounter<&str> = [("a", 2), ("b", 4), ("c", 1)].into_iter().collect();
+
+        local.merge(&TracedCrdt::new(remote.clone()));
+        let event = |key: &str, old: Option<&str>, new: &str| ChangeEvent {
+            key: key.to_string(),
+            old: old.map(str::to_string),
+            new: Some(new.to_string()),
+        };
+        // "a" was already ahead locally, so only "b" and "c" changed.
+        assert_eq!(
+            local.last_merge_trace(),
+            [event("\"b\"", Some("1"), "4"), event("\"c\"", None, "1")]
+        );
+        assert_eq!(local.value(), 8);
+
+        // Merging the same state again changes nothing.
+        local.merge(&TracedCrdt::new(remote));
+        assert!(local.last_merge_trace().is_empty());
+    }
+
+    #[test]
+    fn traced_gcounter_eventual_consistency() {
+        properties::check_eventual_consistency::<crdt::TracedCrdt<GCounter<String>>>();
+    }
+
+    #[test]
+    fn optional_gcounter_eventual_consistency() {
+        properties::check_eventual_consistency::<Option<GCounter<String>>>();
+        properties::check_leq_consistency::<Option<GCounter<String>>>();
+    }
+
+    proptest! {
+        /// `None` is the bottom: merging it either way round is a no-op.
+        #[test]
+        fn optional_gcounter_none_is_bottom(counter in any::<Option<GCounter<String>>>()) {
+            let mut lazy: Option<GCounter<String>> = None;
+            lazy.merge(&counter);
+            prop_assert_eq!(&lazy, &counter);
+            prop_assert_eq!(counter.merged(&None), counter.clone());
+            prop_assert_eq!(lazy.value(), counter.as_ref().map(Crdt::value));
+        }
+    }
+
+    #[test]
+    fn tuple_of_crdts_eventual_consistency() {
+        properties::check_eventual_consistency::<(GCounter<String>, crdt::GSet<String>)>();
+        properties::check_leq_consistency::<(GCounter<String>, crdt::GSet<String>)>();
+        properties::check_eventual_consistency::<(
+            GCounter<String>,
+            PNCounter<String>,
+            Option<GCounter<String>>,
+        )>();
+    }
+
+    #[test]
+    fn tuple_merges_elementwise() {
+        let mut a = (GCounter::new(), crdt::GSet::new());
+        let mut b = a.clone();
+        a.0.add(2, "a");
+        a.1.insert("x");
+        b.0.inc("b");
+        b.1.insert("y");
+
+        a.merge(&b);
+        let (total, items) = a.value();
+        assert_eq!(total, 3);
+        assert_eq!(items.len(), 2);
+        assert!(b.leq(&a));
+    }
+
+    #[test]
+    fn array_of_gcounters_eventual_consistency() {
+        properties::check_eventual_consistency::<[GCounter<String>; 3]>();
+        properties::check_leq_consistency::<[GCounter<String>; 3]>();
+    }
+
+    #[test]
+    fn array_merges_elementwise() {
+        let mut a: [GCounter<String>; 3] = Default::default();
+        let mut b = a.clone();
+        a[0].add(2, "a".to_string());
+        a[2].inc("a".to_string());
+        b[0].inc("b".to_string());
+        b[1].add(4, "b".to_string());
+
+        a.merge(&b);
+        assert_eq!(a.value(), [3, 4, 1]);
+        assert!(b.leq(&a));
+        assert!(!a.leq(&b));
+    }
+
+    #[test]
+    fn resettable_counter_eventual_consistency() {
+        properties::check_eventual_consistency::<ResettableCounter<String>>();
+    }
+
+    #[test]
+    fn resettable_counter_reset_clears_observed_increments() {
+        let mut counter = ResettableCounter::new();
+        counter.add(3, "a");
+        counter.inc("b");
+        assert_eq!(counter.value(), 4);
+
+        counter.reset();
+        assert_eq!(counter.value(), 0);
+        counter.inc("a");
+        assert_eq!(counter.value(), 1);
+        assert_eq!(counter.context().len(), 2);
+    }
+
+    proptest! {
+        /// An increment concurrent with a reset survives it, whichever way round
+        /// the replicas are merged, and nothing the reset observed comes back.
+        #[test]
+        fn resettable_counter_increment_beats_concurrent_reset(
+            base in any::<ResettableCounter<String>>(),
+            amount in 1u64..10,
+        ) {
+            let mut incrementer = base.clone();
+            incrementer.add(amount, "z".to_string());
+            let mut resetter = base;
+            resetter.reset();
+
+            let ab = incrementer.merged(&resetter);
+            let ba = resetter.merged(&incrementer);
+            prop_assert_eq!(&ab, &ba);
+            prop_assert_eq!(ab.value(), amount);
+        }
+
+        /// Replicas that increment and reset independently converge once they
+        /// exchange state, in any merge order.
+        #[test]
+        fn resettable_counter_replicas_converge(
+            ops in proptest::collection::vec((0usize..3, proptest::option::weighted(0.8, 1u64..10)), 0..20),
+        ) {
+            let names = ["a", "b", "c"];
+            let mut replicas = vec![ResettableCounter::new(); 3];
+            for (i, (replica, amount)) in ops.into_iter().enumerate() {
+                match amount {
+                    Some(amount) => {
+                        replicas[replica].add(amount, names[replica]);
+                    }
+                    None => replicas[replica].reset(),
+                }
+                if i % 4 == 0 {
+                    let from = replicas[replica].clone();
+                    replicas[(replica + 1) % 3].merge(&from);
+                }
+            }
+
+            let forward = crdt::join_all(replicas.clone()).unwrap();
+            let backward = crdt::join_all(replicas.into_iter().rev()).unwrap();
+            prop_assert_eq!(forward.value(), backward.value());
+            prop_assert_eq!(forward, backward);
+        }
+    }
+
+    #[test]
+    fn gcounter_summary_lists_replicas_in_order() {
+        let mut counter = GCounter::new();
+        counter.add(5, "b".to_string());
+        counter.add(3, "a".to_string());
+
+        assert_eq!(
+            counter.summarize(),
+            "GCounter (total 8) {\n    \"a\": 3,\n    \"b\": 5,\n}"
+        );
+        assert_eq!(counter.debug_summary(), counter.summarize());
+        assert_eq!(
+            GCounter::<String>::new().summarize(),
+            "GCounter (total 0) {}"
+        );
+    }
+
+    proptest! {
+        /// Capacity hints only affect allocation, never the counter's state.
+        #[test]
+        fn gcounter_with_capacity_matches_new(
+            replicas in proptest::collection::vec("[a-e]", 0..20),
+            capacity in 0..64usize,
+        ) {
+            let mut plain = GCounter::new();
+            let mut sized = GCounter::with_capacity(capacity);
+            sized.reserve(replicas.len());
+            for replica in replicas {
+                plain.inc(replica.clone());
+                sized.inc(replica);
+            }
+            prop_assert_eq!(sized.value(), plain.value());
+            prop_assert_eq!(sized, plain);
+        }
+    }
+
+    #[test]
+    fn gcounter_display_is_one_line() {
+        let mut counter = GCounter::new();
+        assert_eq!(counter.to_string(), "GCounter(total=0, replicas=0)");
+        counter.add(40, "a");
+        counter.add(2, "b");
+        counter.add(0, "c");
+        assert_eq!(counter.to_string(), "GCounter(total=42, replicas=2)");
+    }
+}
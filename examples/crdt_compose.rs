@@ -1,4 +1,4 @@
-use crdt::{Crdt, properties};
+use crdt::{Crdt, assert_crdt, properties};
 use proptest::prelude::*;
 use std::collections::HashMap;
 
@@ -61,6 +61,10 @@ struct Stats {
     pub errors: GCounter,
 }
 
+// Fails to compile if `Stats` stops being a CRDT, and adds a test running the
+// full set of property checks on it.
+assert_crdt!(Stats);
+
 /// Implement Arbitrary for Stats to support property-based testing.
 impl Arbitrary for Stats {
     type Parameters = ();
@@ -115,4 +119,78 @@ mod tests {
     fn test_stats_eventual_consistency() {
         properties::check_eventual_consistency::<Stats>();
     }
+
+    /// A generic composite, which needs an explicit test name.
+    #[derive(Debug, Clone, PartialEq, Default, Crdt)]
+    struct Tagged<T: Crdt + Default> {
+        inner: T,
+        tags: crdt::GSet<String>,
+    }
+
+    impl Arbitrary for Tagged<GCounter> {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (any::<GCounter>(), any::<crdt::GSet<String>>())
+                .prop_map(|(inner, tags)| Tagged { inner, tags })
+                .boxed()
+        }
+    }
+
+    assert_crdt!(Tagged<GCounter>, tagged_counter_is_crdt);
+
+    #[test]
+    fn test_stats_fields_satisfy_laws() {
+        properties::check_composite::<Stats>();
+    }
+
+    /// A broken CRDT whose merge keeps whichever side was merged in last.
+    #[derive(Debug, Clone, PartialEq, Default)]
+    struct Overwrite(u8);
+
+    impl Crdt for Overwrite {
+        type Value = u8;
+
+        fn merge(&mut self, other: &Self) {
+            self.0 = other.0;
+        }
+
+        fn value(&self) -> Self::Value {
+            self.0
+        }
+    }
+
+    /// A composite mixing a lawful field with the broken one.
+    #[derive(Debug, Clone, PartialEq, Default, Crdt)]
+    struct Mixed {
+        visits: GCounter,
+        last: Overwrite,
+        #[crdt(skip)]
+        label: String,
+    }
+
+    impl Arbitrary for Mixed {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (any::<GCounter>(), any::<u8>())
+                .prop_map(|(visits, last)| Mixed {
+                    visits,
+                    last: Overwrite(last),
+                    label: String::new(),
+                })
+                .boxed()
+        }
+    }
+
+    #[test]
+    fn test_check_composite_names_broken_field() {
+        let err = properties::try_check_composite::<Mixed>().unwrap_err();
+        assert_eq!(err.field, "last");
+        assert_eq!(err.violation.property, "Commutativity");
+        assert!(err.to_string().contains("In field `last`"));
+        assert!(!err.violation.counterexample.contains("visits"));
+    }
 }
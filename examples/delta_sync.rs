@@ -181,3 +181,46 @@ fn test_empty_delta_when_in_sync() {
     let delta = a.delta_from_summary(&b.summary());
     assert_eq!(delta.value(), 0); // nothing missing
 }
+
+/// Checks that `a.merge_diff(b)` holds only what `b` adds and that applying it
+/// matches a full merge.
+#[cfg(test)]
+fn check_merge_diff<T: DeltaSync<Delta = T>>(a: &T, b: &T) -> Result<(), TestCaseError> {
+    let diff = a.merge_diff(b);
+    prop_assert!(diff.leq(b));
+
+    let mut applied = a.clone();
+    applied.merge_delta(&diff);
+    prop_assert_eq!(applied, a.merged(b));
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn test_merge_diff_matches_merge(
+        counters in (any::<GCounter<String>>(), any::<GCounter<String>>()),
+        sets in (any::<GSet<String>>(), any::<GSet<String>>()),
+        clocks in (any::<VectorClock<String>>(), any::<VectorClock<String>>()),
+    ) {
+        check_merge_diff(&counters.0, &counters.1)?;
+        check_merge_diff(&sets.0, &sets.1)?;
+        check_merge_diff(&clocks.0, &clocks.1)?;
+    }
+}
+
+#[test]
+fn test_merge_diff_lists_only_raised_entries() {
+    let mut local = GCounter::new();
+    local.add(10, "x".to_string());
+    local.add(5, "y".to_string());
+    let mut remote = local.clone();
+    remote.add(2, "y".to_string());
+    remote.add(1, "z".to_string());
+
+    let diff = local.merge_diff(&remote);
+    let mut raised: Vec<_> = diff.contributions().collect();
+    raised.sort();
+    assert_eq!(raised, [(&"y".to_string(), 7), (&"z".to_string(), 1)]);
+    assert_eq!(local.value(), 15);
+    assert_eq!(remote.merge_diff(&local).value(), 0);
+}
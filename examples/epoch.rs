@@ -0,0 +1,97 @@
+//! Resetting a CRDT convergently with an epoch counter.
+//!
+//! Run with: `cargo run --example epoch`
+
+use crdt::{Crdt, Epoch, GCounter, properties};
+
+fn main() {
+    println!("--- Epoch ---");
+
+    // Two replicas count page views for the current reporting period.
+    let mut node_a: Epoch<GCounter<String>> = Epoch::default();
+    let mut node_b = node_a.clone();
+    node_a.inner_mut().add(10, "a".to_string());
+    node_b.inner_mut().add(7, "b".to_string());
+    node_a.merge(&node_b);
+    println!("Views this period: {}", node_a.value());
+    assert_eq!(node_a.value(), 17);
+
+    // A new period starts on A, while B keeps counting in the old one.
+    node_a.bump_epoch();
+    node_a.inner_mut().inc("a".to_string());
+    node_b.inner_mut().add(5, "b".to_string());
+
+    // The reset wins on both replicas, whichever way round they merge.
+    let ab = node_a.merged(&node_b);
+    let ba = node_b.merged(&node_a);
+    println!(
+        "After the reset: epoch {}, views {}",
+        ab.epoch(),
+        ab.value()
+    );
+    assert_eq!(ab, ba);
+    assert_eq!(ab.value(), 1);
+
+    println!("\nRunning property tests for Epoch<GCounter<String>>...");
+    properties::check_eventual_consistency::<Epoch<GCounter<String>>>();
+    println!("Epoch satisfies all CRDT properties!");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn epoch_eventual_consistency() {
+        properties::check_eventual_consistency::<Epoch<GCounter<String>>>();
+        properties::check_leq_consistency::<Epoch<GCounter<String>>>();
+    }
+
+    #[test]
+    fn bump_epoch_resets_inner_state() {
+        let mut counter: Epoch<GCounter<&str>> = Epoch::default();
+        counter.inner_mut().add(3, "a");
+        counter.bump_epoch();
+        assert_eq!(counter.epoch(), 1);
+        assert_eq!(counter.value(), 0);
+    }
+
+    proptest! {
+        /// Merging in any order ends in the highest epoch, holding exactly the
+        /// join of the states from that epoch.
+        #[test]
+        fn highest_epoch_wins_in_any_order(
+            states in proptest::collection::vec(any::<Epoch<GCounter<String>>>(), 1..6),
+        ) {
+            let highest = states.iter().map(Epoch::epoch).max().unwrap();
+            let expected = states
+                .iter()
+                .filter(|state| state.epoch() == highest)
+                .fold(GCounter::new(), |acc, state| acc.merged(state.inner()));
+
+            let forward = crdt::join_all(states.clone()).unwrap();
+            let backward = crdt::join_all(states.into_iter().rev()).unwrap();
+            prop_assert_eq!(&forward, &backward);
+            prop_assert_eq!(forward.epoch(), highest);
+            prop_assert_eq!(forward.inner(), &expected);
+        }
+
+        /// A reset beats every update made in the epoch it left.
+        #[test]
+        fn reset_beats_concurrent_updates(
+            base in any::<Epoch<GCounter<String>>>(),
+            updates in proptest::collection::vec(("[a-c]", 1..10u64), 0..5),
+        ) {
+            let mut reset = base.clone();
+            reset.bump_epoch();
+            let mut updated = base;
+            for (replica, amount) in updates {
+                updated.inner_mut().add(amount, replica);
+            }
+
+            prop_assert_eq!(reset.merged(&updated), reset.clone());
+            prop_assert_eq!(updated.merged(&reset), reset);
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! An append-only dependency graph with the `GGraph` CRDT.
+//!
+//! Run with: `cargo run --example graph`
+
+use crdt::{Crdt, GGraph, properties};
+
+fn main() {
+    println!("--- G-Graph ---");
+
+    // Two build servers record task dependencies independently.
+    let mut ci = GGraph::new();
+    ci.add_vertex("compile");
+    ci.add_vertex("test");
+    ci.add_edge("test", "compile");
+
+    let mut release = GGraph::new();
+    release.add_vertex("package");
+    // Depends on a task this replica has not heard of yet.
+    release.add_edge("package", "test");
+    println!(
+        "Before sync, package depends on {:?}",
+        release.neighbors(&"package").collect::<Vec<_>>()
+    );
+
+    ci.merge(&release);
+    release.merge(&ci);
+    assert_eq!(ci, release);
+    println!(
+        "After sync, package depends on {:?}",
+        release.neighbors(&"package").collect::<Vec<_>>()
+    );
+
+    println!("\nRunning property tests for GGraph...");
+    properties::check_eventual_consistency::<GGraph<String>>();
+    properties::check_leq_consistency::<GGraph<String>>();
+    println!("GGraph satisfies all CRDT properties!");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crdt::{Apply, GGraphOp};
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn ggraph_eventual_consistency() {
+        properties::check_eventual_consistency::<GGraph<String>>();
+    }
+
+    #[test]
+    fn ggraph_leq_consistency() {
+        properties::check_leq_consistency::<GGraph<String>>();
+    }
+
+    #[test]
+    fn ggraph_edge_resolves_once_vertices_arrive() {
+        let mut edges_first = GGraph::new();
+        edges_first.add_edge("a", "b");
+        assert_eq!(edges_first.neighbors(&"a").count(), 0);
+        assert!(edges_first.value().1.is_empty());
+
+        let mut vertices = GGraph::new();
+        vertices.add_vertex("a");
+        edges_first.merge(&vertices);
+        // Only one endpoint is known so far.
+        assert_eq!(edges_first.neighbors(&"a").count(), 0);
+
+        vertices.add_vertex("b");
+        edges_first.merge(&vertices);
+        assert_eq!(edges_first.neighbors(&"a").collect::<Vec<_>>(), vec![&"b"]);
+        assert_eq!(edges_first.value().1, HashSet::from([("a", "b")]));
+    }
+
+    #[test]
+    fn ggraph_neighbors_follow_edge_direction() {
+        let mut graph = GGraph::new();
+        graph.apply(GGraphOp::AddVertex(1), ());
+        graph.apply(GGraphOp::AddVertex(2), ());
+        graph.apply(GGraphOp::AddVertex(3), ());
+        graph.apply(GGraphOp::AddEdge(1, 2), ());
+        graph.apply(GGraphOp::AddEdge(1, 3), ());
+        graph.apply(GGraphOp::AddEdge(3, 1), ());
+
+        let mut out: Vec<_> = graph.neighbors(&1).copied().collect();
+        out.sort();
+        assert_eq!(out, vec![2, 3]);
+        assert_eq!(graph.neighbors(&2).count(), 0);
+        assert!(graph.contains_vertex(&3));
+    }
+
+    proptest! {
+        /// Replicas that see the same operations in any order converge.
+        #[test]
+        fn ggraph_ops_in_any_order_converge(
+            ops in proptest::collection::vec(
+                prop_oneof![
+                    "[a-c]".prop_map(GGraphOp::AddVertex),
+                    ("[a-c]", "[a-c]").prop_map(|(from, to)| GGraphOp::AddEdge(from, to)),
+                ],
+                0..10,
+            ),
+        ) {
+            let mut forward = GGraph::new();
+            for op in ops.iter().cloned() {
+                forward.apply(op, ());
+            }
+            let mut backward = GGraph::new();
+            for op in ops.into_iter().rev() {
+                backward.apply(op, ());
+            }
+            prop_assert_eq!(forward, backward);
+        }
+
+        /// Merging a state reports a change exactly when it was not already dominated.
+        #[test]
+        fn ggraph_merge_report_matches_leq(a in any::<GGraph<String>>(), b in any::<GGraph<String>>()) {
+            let mut merged = a.clone();
+            let report = merged.merge_report(&b);
+            prop_assert_eq!(report.changed, !b.leq(&a));
+        }
+    }
+}
@@ -0,0 +1,453 @@
+//! Interval Tree Clocks: forkable identities and compact encodings.
+//!
+//! Run with: `cargo run --example itc`
+
+use crdt::causal::itc::{EventTree, IdTree};
+use crdt::{Apply, Crdt, ItcClock, ItcReplica, ItcStamp, Replica};
+
+fn main() {
+    println!("--- ITC stamps ---");
+
+    let (mut alice, bob) = ItcStamp::new().fork();
+    let (mut bob, mut carol) = bob.fork();
+    alice.event();
+    bob.event();
+    carol.event();
+    carol.event();
+    println!("alice = {}", alice.event.to_ascii());
+    println!("carol = {}", carol.event.to_ascii());
+    assert!(!alice.leq(&carol) && !carol.leq(&alice));
+
+    // Carol retires, handing her identity and history to Bob.
+    let bob = bob.join(carol);
+    let everyone = alice.join(bob);
+    assert_eq!(everyone.id, ItcStamp::new().id);
+    println!("After joining every stamp: {:?}\n", everyone);
+
+    println!("--- ITC binary encoding ---");
+
+    let mut seed = ItcReplica::new();
+    let forked = seed.fork();
+
+    let mut clock_a = ItcClock::default();
+    let mut clock_b = ItcClock::default();
+    clock_a.apply((), seed.id());
+    clock_b.apply((), forked.id());
+    clock_b.apply((), forked.id());
+    clock_a.merge(&clock_b);
+
+    let id_bytes = seed.tree.encode();
+    let event_bytes = clock_a.tree.encode();
+    println!("id    {:?} -> {} byte(s)", seed.tree, id_bytes.len());
+    println!("event {:?} -> {} byte(s)", clock_a.tree, event_bytes.len());
+
+    assert_eq!(IdTree::decode(&id_bytes), Ok(seed.tree));
+    assert_eq!(EventTree::decode(&event_bytes), Ok(clock_a.tree));
+    println!("Both trees round-trip through their binary encoding.");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crdt::causal::itc::{Max, Min, Normalisable, disjoint};
+    use crdt::causal::itc_props;
+    use crdt::{DecodeError, IdOverlap};
+    use proptest::prelude::*;
+
+    /// Normalized id trees, as `encode` expects, up to seven levels deep.
+    fn id_tree() -> impl Strategy<Value = IdTree> {
+        itc_props::raw_id_tree().prop_map(Normalisable::norm)
+    }
+
+    /// Normalized event trees up to seven levels deep, with counters large
+    /// enough to need wide number encodings but whose sums along any path
+    /// still fit in a `u32`.
+    fn event_tree() -> impl Strategy<Value = EventTree> {
+        const WIDE: u32 = u32::MAX / 8;
+        prop_oneof![0u32..8, 0..WIDE]
+            .prop_map(EventTree::leaf)
+            .prop_recursive(6, 64, 2, |inner| {
+                (
+                    prop_oneof![Just(0u32), 0u32..8, 0..WIDE],
+                    inner.clone(),
+                    inner,
+                )
+                    .prop_map(|(n, left, right)| {
+                        EventTree::node(n, Box::new(left), Box::new(right))
+                    })
+            })
+            .prop_map(Normalisable::norm)
+    }
+
+    proptest! {
+        #[test]
+        fn id_tree_encoding_round_trips(tree in id_tree()) {
+            prop_assert_eq!(IdTree::decode(&tree.encode()), Ok(tree));
+        }
+
+        #[test]
+        fn event_tree_encoding_round_trips(tree in event_tree()) {
+            prop_assert_eq!(EventTree::decode(&tree.encode()), Ok(tree));
+        }
+
+        /// Decoding never panics, and whatever it accepts is safe to use.
+        #[test]
+        fn decoding_arbitrary_bytes_never_panics(
+            bytes in proptest::collection::vec(any::<u8>(), 0..16),
+        ) {
+            if let Ok(tree) = IdTree::decode(&bytes) {
+                prop_assert_eq!(tree.clone().norm(), tree.clone());
+                prop_assert_eq!(IdTree::decode(&tree.encode()), Ok(tree));
+            }
+            if let Ok(tree) = EventTree::decode(&bytes) {
+                prop_assert_eq!(tree.clone().norm(), tree.clone());
+                prop_assert!(tree.min() <= tree.max());
+                let clock = ItcClock { tree: tree.clone() };
+                prop_assert_eq!(clock.merged(&ItcClock::default()), clock);
+                prop_assert_eq!(EventTree::decode(&tree.encode()), Ok(tree));
+            }
+        }
+
+        #[test]
+        fn clock_leq_join(a in any::<ItcClock>(), b in any::<ItcClock>()) {
+            let mut joined = a.clone();
+            joined.merge(&b);
+            prop_assert!(a.leq(&joined));
+            prop_assert!(b.leq(&joined));
+            prop_assert!(!a.happened_before(&a));
+            prop_assert!(!(a.happened_before(&b) && a.concurrent(&b)));
+        }
+
+        #[test]
+        fn clock_encoding_round_trips(clock in any::<ItcClock>()) {
+            prop_assert_eq!(EventTree::decode(&clock.tree.encode()), Ok(clock.tree));
+        }
+    }
+
+    #[test]
+    fn clock_eventual_consistency() {
+        crdt::properties::check_eventual_consistency::<ItcClock>();
+    }
+
+    proptest! {
+        /// The provided generators only produce normalized trees.
+        #[test]
+        fn arbitrary_trees_are_normalized(id in any::<IdTree>(), event in any::<EventTree>()) {
+            let clock = ItcClock { tree: event.clone() };
+            prop_assert_eq!(clock.merged(&clock).tree, event);
+            let (left, right) = ItcStamp { id: id.clone(), event: EventTree::zero() }.fork();
+            prop_assert_eq!(left.join(right).id, id);
+        }
+    }
+
+    #[test]
+    fn norm_properties() {
+        itc_props::check_norm_properties();
+    }
+
+    #[test]
+    fn norm_collapses_redundant_nodes() {
+        let ones = IdTree::node(Box::new(IdTree::one()), Box::new(IdTree::one()));
+        assert_eq!(ones.norm(), IdTree::one());
+
+        // (2, 1, (0, 3, 3)) lifts the smaller child's base: (3, 0, 2).
+        let event = EventTree::node(
+            2,
+            Box::new(EventTree::leaf(1)),
+            Box::new(EventTree::node(
+                0,
+                Box::new(EventTree::leaf(3)),
+                Box::new(EventTree::leaf(3)),
+            )),
+        );
+        assert_eq!((event.min(), event.max()), (3, 5));
+        assert_eq!(event.norm().to_ascii(), "(3, 0, 2)");
+    }
+
+    #[test]
+    fn clock_leq_matches_merge() {
+        crdt::properties::check_leq_consistency::<ItcClock>();
+    }
+
+    #[test]
+    fn clock_happened_before_and_concurrent() {
+        let mut a_id = ItcReplica::new();
+        let b_id = a_id.fork();
+
+        let mut a = ItcClock::default();
+        a.apply((), a_id.id());
+        let mut b = a.clone();
+        assert!(!a.happened_before(&b) && !a.concurrent(&b));
+
+        b.apply((), b_id.id());
+        assert!(a.happened_before(&b));
+
+        a.apply((), a_id.id());
+        assert!(a.concurrent(&b));
+    }
+
+    #[test]
+    fn fork_then_join_restores_identity() {
+        let seed = ItcStamp::new();
+        let (left, right) = seed.fork();
+        let (right_a, right_b) = right.fork();
+        assert_eq!(left.join(right_b).join(right_a).id, seed.id);
+    }
+
+    #[test]
+    fn fork_n_then_join_restores_identity() {
+        let mut seed = ItcReplica::new();
+        let original = seed.tree.clone();
+        let replicas = seed.fork_n(8);
+        assert_eq!(seed.tree, IdTree::zero());
+        assert_eq!(replicas.len(), 8);
+        for (i, replica) in replicas.iter().enumerate() {
+            assert_ne!(replica.tree, IdTree::zero());
+            assert!(
+                replicas[i + 1..]
+                    .iter()
+                    .all(|other| other.tree != replica.tree)
+            );
+        }
+
+        let mut joined = seed;
+        for replica in replicas.into_iter().rev() {
+            joined.join(replica);
+        }
+        assert_eq!(joined.tree, original);
+    }
+
+    #[test]
+    fn forked_identities_are_disjoint() {
+        let mut seed = ItcReplica::new();
+        let replicas = seed.fork_n(5);
+        for (i, replica) in replicas.iter().enumerate() {
+            assert!(disjoint(&replica.tree, &seed.tree));
+            assert!(!disjoint(&replica.tree, &replica.tree));
+            for other in &replicas[i + 1..] {
+                assert!(disjoint(&replica.tree, &other.tree));
+            }
+        }
+
+        // A replica overlaps any identity containing it, including the seed
+        // it was forked from.
+        assert!(!disjoint(&replicas[0].tree, &IdTree::one()));
+        assert!(disjoint(&IdTree::zero(), &IdTree::one()));
+    }
+
+    #[test]
+    fn join_checked_refuses_overlapping_identities() {
+        let mut a = ItcReplica::new();
+        let b = a.fork();
+        let copy = a.clone();
+
+        assert_eq!(a.join_checked(copy), Err(IdOverlap));
+        assert_eq!(a.join_checked(ItcReplica::new()), Err(IdOverlap));
+        assert_ne!(a.tree, IdTree::one());
+
+        assert_eq!(a.join_checked(b), Ok(()));
+        assert_eq!(a.tree, IdTree::one());
+    }
+
+    #[test]
+    fn join_checked_normalizes_unvalidated_identities() {
+        // `(0, 0)` owns nothing, but is not the normalized `0` that `sum` expects.
+        let empty = IdTree::node(Box::new(IdTree::zero()), Box::new(IdTree::zero()));
+        let mut seed = ItcReplica::new();
+        assert_eq!(seed.join_checked(ItcReplica { tree: empty }), Ok(()));
+        assert_eq!(seed.tree, IdTree::one());
+
+        // `((1, 1), 0)` is a redundant spelling of `(1, 0)`.
+        let half = IdTree::node(
+            Box::new(IdTree::node(
+                Box::new(IdTree::one()),
+                Box::new(IdTree::one()),
+            )),
+            Box::new(IdTree::zero()),
+        );
+        let mut right = ItcReplica {
+            tree: IdTree::node(Box::new(IdTree::zero()), Box::new(IdTree::one())),
+        };
+        assert_eq!(right.join_checked(ItcReplica { tree: half }), Ok(()));
+        assert_eq!(right.tree, IdTree::one());
+    }
+
+    proptest! {
+        /// `disjoint` agrees with slot-by-slot comparison of the two identities.
+        #[test]
+        fn disjoint_matches_coverage(a in any::<IdTree>(), b in any::<IdTree>()) {
+            let slots = |tree: &IdTree| {
+                (0..64u32).filter(move |&slot| owns(tree, slot, 6)).collect::<Vec<_>>()
+            };
+            let expected = slots(&a).iter().all(|slot| !slots(&b).contains(slot));
+            prop_assert_eq!(disjoint(&a, &b), expected);
+        }
+    }
+
+    proptest! {
+        /// `join_checked` never panics, whatever shape the trees are in, and
+        /// joins exactly when the identities own no common slot.
+        #[test]
+        fn join_checked_is_total(
+            a in itc_props::raw_id_tree(),
+            b in itc_props::raw_id_tree(),
+        ) {
+            let expected = disjoint(&a.clone().norm(), &b.clone().norm());
+            let mut joined = ItcReplica { tree: a };
+            prop_assert_eq!(joined.join_checked(ItcReplica { tree: b }).is_ok(), expected);
+        }
+    }
+
+    /// Returns whether `tree` owns `slot` of the interval cut into `2^depth` slots.
+    fn owns(tree: &IdTree, slot: u32, depth: u32) -> bool {
+        match tree {
+            IdTree::Leaf { i } => *i,
+            IdTree::Node { left, right } => {
+                let half = 1 << (depth - 1);
+                if slot < half {
+                    owns(left, slot, depth - 1)
+                } else {
+                    owns(right, slot - half, depth - 1)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fork_n_handles_uneven_and_empty_splits() {
+        let mut seed = ItcReplica::new();
+        assert!(seed.fork_n(0).is_empty());
+        assert_eq!(seed.tree, IdTree::one());
+
+        let mut joined = ItcReplica {
+            tree: IdTree::zero(),
+        };
+        for replica in seed.fork_n(5) {
+            joined.join(replica);
+        }
+        assert_eq!(joined.tree, IdTree::one());
+    }
+
+    #[test]
+    fn joining_a_peek_leaves_identity_unchanged() {
+        let (mut owner, _) = ItcStamp::new().fork();
+        owner.event();
+
+        let observer = owner.peek();
+        assert_eq!(observer.id, IdTree::zero());
+        assert!(owner.leq(&observer) && observer.leq(&owner));
+
+        let rejoined = owner.clone().join(observer);
+        assert_eq!(rejoined, owner);
+
+        let replica = ItcReplica::new();
+        assert_eq!(replica.peek().0, IdTree::zero());
+        assert_eq!(replica, ItcReplica::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "anonymous ITC stamps cannot record events")]
+    fn peeked_stamp_cannot_record_events() {
+        ItcStamp::new().peek().event();
+    }
+
+    #[test]
+    fn stamp_events_are_causally_ordered() {
+        let (mut a, mut b) = ItcStamp::new().fork();
+        let before = a.clone();
+        a.event();
+        assert!(before.leq(&a));
+        assert!(!a.leq(&before));
+
+        b.event();
+        assert!(!a.leq(&b) && !b.leq(&a));
+
+        let joined = a.clone().join(b.clone());
+        assert!(a.leq(&joined) && b.leq(&joined));
+    }
+
+    #[test]
+    fn stamp_matches_replica_and_clock() {
+        let mut stamp = ItcStamp::new();
+        let (mut replica, mut clock) = stamp.clone().into_parts();
+
+        stamp.event();
+        clock.apply((), replica.id());
+        assert_eq!(ItcStamp::from_parts(replica.clone(), clock.clone()), stamp);
+
+        let (kept, forked) = stamp.fork();
+        let other = replica.fork();
+        assert_eq!(kept.id, replica.tree);
+        assert_eq!(forked.id, other.tree);
+    }
+
+    #[test]
+    fn seed_identity_fits_in_one_byte() {
+        assert_eq!(IdTree::one().encode(), vec![0b0010_0000]);
+        assert_eq!(EventTree::zero().encode(), vec![0b1000_0000]);
+    }
+
+    #[test]
+    fn decode_rejects_malformed_input() {
+        assert_eq!(IdTree::decode(&[]), Err(DecodeError::UnexpectedEnd));
+        assert_eq!(
+            IdTree::decode(&[0b0010_0000, 0]),
+            Err(DecodeError::TrailingBytes)
+        );
+        // A counter prefix of 31 one bits exceeds u32::MAX.
+        assert_eq!(EventTree::decode(&[0xff; 8]), Err(DecodeError::Overflow));
+    }
+
+    #[test]
+    fn decode_rejects_trees_that_are_not_normalized() {
+        // The id `(0, 0)`, which normalizes to `0`.
+        assert_eq!(
+            IdTree::decode(&[0b0100_0000]),
+            Err(DecodeError::NotNormalized)
+        );
+        let twins = EventTree::node(
+            0,
+            Box::new(EventTree::leaf(2)),
+            Box::new(EventTree::leaf(2)),
+        );
+        assert_eq!(
+            EventTree::decode(&twins.encode()),
+            Err(DecodeError::NotNormalized)
+        );
+
+        // Normalized, but its largest counter is u32::MAX + 1.
+        let overflowing = EventTree::node(
+            u32::MAX,
+            Box::new(EventTree::zero()),
+            Box::new(EventTree::leaf(1)),
+        );
+        assert_eq!(
+            EventTree::decode(&overflowing.encode()),
+            Err(DecodeError::Overflow)
+        );
+    }
+
+    #[test]
+    fn trees_render_in_paper_notation() {
+        let event = EventTree::node(
+            2,
+            Box::new(EventTree::zero()),
+            Box::new(EventTree::node(
+                1,
+                Box::new(EventTree::zero()),
+                Box::new(EventTree::leaf(1)),
+            )),
+        );
+        assert_eq!(event.to_ascii(), "(2, 0, (1, 0, 1))");
+
+        let id = IdTree::node(
+            Box::new(IdTree::one()),
+            Box::new(IdTree::node(
+                Box::new(IdTree::zero()),
+                Box::new(IdTree::one()),
+            )),
+        );
+        assert_eq!(id.to_ascii(), "(1, (0, 1))");
+        assert_eq!(IdTree::one().to_ascii(), "1");
+    }
+}
@@ -0,0 +1,102 @@
+//! Writing generic algorithms against the `Semilattice` trait.
+//!
+//! Run with: `cargo run --example lattice`
+
+use crdt::{Crdt, GCounter, LatticeCrdt, Semilattice, properties};
+use proptest::prelude::*;
+
+/// Positive integers ordered by divisibility: the join is the least common
+/// multiple and the bottom is 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Lcm(u64);
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+impl Semilattice for Lcm {
+    fn join(&self, other: &Self) -> Self {
+        Lcm(self.0 / gcd(self.0, other.0) * other.0)
+    }
+
+    fn bottom() -> Self {
+        Lcm(1)
+    }
+}
+
+impl Arbitrary for Lcm {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (1u64..=64).prop_map(Lcm).boxed()
+    }
+}
+
+/// Joins any number of lattice states, starting from the bottom.
+fn join_from_bottom<L: Semilattice>(items: &[L]) -> L {
+    items.iter().fold(L::bottom(), |acc, item| acc.join(item))
+}
+
+fn main() {
+    println!("--- Semilattice ---");
+
+    // A plain lattice type...
+    let lcm = join_from_bottom(&[Lcm(4), Lcm(6), Lcm(10)]);
+    println!("lcm(4, 6, 10) = {}", lcm.0);
+    assert_eq!(lcm, Lcm(60));
+
+    // ...and any CRDT, through the blanket impl.
+    let mut a = GCounter::new();
+    a.add(2, "a");
+    let mut b = GCounter::new();
+    b.add(3, "b");
+    let total = join_from_bottom(&[a, b]);
+    println!("Joined counters total {}", total.value());
+
+    println!("\nRunning property tests for LatticeCrdt<Lcm>...");
+    properties::check_eventual_consistency::<LatticeCrdt<Lcm>>();
+    println!("Lcm satisfies all semilattice laws!");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lattice_crdt_satisfies_join_laws() {
+        // Idempotence, commutativity, associativity and bottom neutrality,
+        // checked through the `Crdt` adapter.
+        properties::check_eventual_consistency::<LatticeCrdt<Lcm>>();
+        properties::check_leq_consistency::<LatticeCrdt<Lcm>>();
+    }
+
+    #[test]
+    fn lattice_crdt_default_is_bottom() {
+        assert_eq!(LatticeCrdt::<Lcm>::default().into_inner(), Lcm::bottom());
+
+        let mut state = LatticeCrdt(Lcm(9));
+        state.merge(&LatticeCrdt(Lcm(6)));
+        assert_eq!(state.value(), Lcm(18));
+    }
+
+    proptest! {
+        /// The blanket impl joins exactly like `Crdt::merged`.
+        #[test]
+        fn crdt_join_matches_merged(
+            a in any::<GCounter<String>>(),
+            b in any::<GCounter<String>>(),
+        ) {
+            prop_assert_eq!(Semilattice::join(&a, &b), a.merged(&b));
+            prop_assert_eq!(a.join(&GCounter::bottom()), a);
+        }
+
+        /// Joining from the bottom does not depend on the order of the items.
+        #[test]
+        fn join_from_bottom_ignores_order(items in proptest::collection::vec(any::<Lcm>(), 0..5)) {
+            let mut reversed = items.clone();
+            reversed.reverse();
+            prop_assert_eq!(join_from_bottom(&items), join_from_bottom(&reversed));
+        }
+    }
+}
@@ -0,0 +1,212 @@
+//! Map CRDTs whose values are themselves CRDTs.
+//!
+//! Run with: `cargo run --example maps`
+
+use crdt::properties;
+use crdt::{Crdt, GCounter, GMap, ORMap};
+
+fn main() {
+    println!("--- G-Map of G-Counters ---");
+
+    // Page views per URL, counted independently on two nodes.
+    let mut node_a: GMap<String, GCounter<String>> = GMap::new();
+    let mut node_b: GMap<String, GCounter<String>> = GMap::new();
+
+    node_a
+        .entry_or_default("/home".to_string())
+        .add(3, "a".to_string());
+    node_b
+        .entry_or_default("/home".to_string())
+        .add(2, "b".to_string());
+    node_b
+        .entry_or_default("/about".to_string())
+        .inc("b".to_string());
+
+    node_a.merge(&node_b);
+    println!("Merged view counts: {:?}", node_a.value());
+    assert_eq!(node_a.get(&"/home".to_string()).unwrap().value(), 5);
+    assert_eq!(node_a.get(&"/about".to_string()).unwrap().value(), 1);
+
+    println!("\nRunning property tests for GMap<String, GCounter<String>>...");
+    properties::check_eventual_consistency::<GMap<String, GCounter<String>>>();
+    println!("GMap satisfies all CRDT properties!");
+
+    println!("\n--- OR-Map of G-Counters ---");
+
+    // A shopping cart: one node drops an item while the other adds to it.
+    let mut phone: ORMap<String, GCounter<String>, String> = ORMap::new();
+    phone.update("apples".to_string(), "phone".to_string(), |count| {
+        count.add(2, "phone".to_string())
+    });
+    let mut laptop = phone.clone();
+
+    laptop.remove(&"apples".to_string(), "laptop".to_string());
+    phone.update("apples".to_string(), "phone".to_string(), |count| {
+        count.inc("phone".to_string())
+    });
+
+    laptop.merge(&phone);
+    phone.merge(&laptop);
+    println!("Merged cart: {:?}", phone.value());
+    assert_eq!(phone, laptop);
+    assert_eq!(phone.get(&"apples".to_string()).unwrap().value(), 3);
+
+    println!("\nRunning property tests for ORMap<String, GCounter<String>, String>...");
+    properties::check_eventual_consistency::<ORMap<String, GCounter<String>, String>>();
+    println!("ORMap satisfies all CRDT properties!");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn gmap_of_gcounters_eventual_consistency() {
+        properties::check_eventual_consistency::<GMap<String, GCounter<String>>>();
+    }
+
+    #[test]
+    fn gmap_of_gcounters_associativity() {
+        properties::check_associativity::<GMap<String, GCounter<String>>>();
+    }
+
+    #[test]
+    fn gmap_merges_shared_keys_recursively() {
+        let mut a: GMap<&str, GCounter<&str>> = GMap::new();
+        a.entry_or_default("k").add(4, "a");
+        let mut b: GMap<&str, GCounter<&str>> = GMap::new();
+        b.entry_or_default("k").add(6, "b");
+
+        a.merge(&b);
+        assert_eq!(a.len(), 1);
+        assert_eq!(a.value()["k"], 10);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn gmap_canonical_bytes_ignore_insertion_order() {
+        use crdt::Apply;
+
+        let mut a: GMap<String, GCounter<String>> = GMap::new();
+        let mut b: GMap<String, GCounter<String>> = GMap::new();
+        for key in ["x", "y", "z"] {
+            for replica in ["r1", "r2", "r3"] {
+                a.apply((key.to_string(), 1), replica.to_string());
+            }
+        }
+        for key in ["z", "y", "x"] {
+            for replica in ["r3", "r2", "r1"] {
+                b.apply((key.to_string(), 1), replica.to_string());
+            }
+        }
+
+        assert_eq!(a, b);
+        assert_eq!(a.canonical_bytes(), b.canonical_bytes());
+
+        // Keys that could not be JSON object keys work too.
+        let mut tuples: GMap<(u8, u8), crdt::GSet<u8>> = GMap::new();
+        tuples.apply(((1, 2), 7), ());
+        assert_eq!(tuples.canonical_bytes(), br#"[[[1,2],[7]]]"#);
+    }
+
+    #[test]
+    fn ormap_of_gcounters_eventual_consistency() {
+        properties::check_eventual_consistency::<ORMap<String, GCounter<String>, String>>();
+    }
+
+    #[test]
+    fn ormap_update_after_observed_remove_starts_from_bottom() {
+        let mut map: ORMap<&str, GCounter<&str>, &str> = ORMap::new();
+        map.update("k", "a", |count| count.add(5, "a"));
+        map.remove(&"k", "a");
+        assert!(!map.contains_key(&"k"));
+
+        map.update("k", "a", |count| count.inc("a"));
+        assert_eq!(map.get(&"k").unwrap().value(), 1);
+        // Superseded and removed versions only leave the replica's context entry.
+        assert_eq!(map.context().len(), 1);
+    }
+
+    #[test]
+    fn ormap_remove_is_recorded_for_its_replica() {
+        let mut map: ORMap<&str, GCounter<&str>, &str> = ORMap::new();
+        map.update("k", "a", |count| count.inc("a"));
+        let before = map.clone();
+
+        assert_eq!(map.remove(&"missing", "b"), ("b", 1));
+        assert_eq!(map.value(), before.value());
+        assert!(before.leq(&map));
+        assert!(!map.leq(&before));
+    }
+
+    proptest! {
+        /// An update concurrent with a remove of the same key survives the merge,
+        /// whichever way round the replicas are merged, and keeps what the
+        /// updater saw.
+        #[test]
+        fn ormap_concurrent_update_beats_remove(
+            base in any::<ORMap<String, GCounter<String>, String>>(),
+            key in "[a-c]",
+            amount in 1u64..10,
+        ) {
+            let mut updater = base.clone();
+            updater.update(key.clone(), "z".to_string(), |count| count.add(amount, "z".to_string()));
+            let mut remover = base.clone();
+            remover.remove(&key, "y".to_string());
+
+            let ab = updater.merged(&remover);
+            let ba = remover.merged(&updater);
+            prop_assert_eq!(&ab, &ba);
+            prop_assert_eq!(ab.get(&key), updater.get(&key));
+        }
+
+        /// A remove that has seen every update of a key wins.
+        #[test]
+        fn ormap_remove_after_update_wins(
+            base in any::<ORMap<String, GCounter<String>, String>>(),
+            key in "[a-c]",
+        ) {
+            let mut updater = base.clone();
+            updater.update(key.clone(), "z".to_string(), |count| count.inc("z".to_string()));
+            let mut remover = updater.clone();
+            remover.remove(&key, "y".to_string());
+
+            let merged = updater.merged(&remover);
+            prop_assert!(!merged.contains_key(&key));
+            prop_assert_eq!(merged, remover.merged(&updater));
+        }
+    }
+
+    #[test]
+    fn btreemap_of_gcounters_eventual_consistency() {
+        use std::collections::BTreeMap;
+
+        properties::check_eventual_consistency::<BTreeMap<String, GCounter<String>>>();
+        properties::check_leq_consistency::<BTreeMap<String, GCounter<String>>>();
+    }
+
+    proptest! {
+        /// A `BTreeMap` of CRDTs merges exactly like a `GMap` with the same entries.
+        #[test]
+        fn btreemap_merges_like_gmap(
+            a in proptest::collection::btree_map("[a-c]", any::<GCounter<String>>(), 0..4),
+            b in proptest::collection::btree_map("[a-c]", any::<GCounter<String>>(), 0..4),
+        ) {
+            let to_gmap = |map: &std::collections::BTreeMap<String, GCounter<String>>| {
+                let mut gmap: GMap<String, GCounter<String>> = GMap::new();
+                for (key, counter) in map {
+                    gmap.entry_or_default(key.clone()).merge(counter);
+                }
+                gmap
+            };
+
+            let merged = a.merged(&b);
+            let expected = to_gmap(&a).merged(&to_gmap(&b));
+            prop_assert_eq!(merged.len(), expected.len());
+            for (key, value) in merged.value() {
+                prop_assert_eq!(Some(value), expected.value().get(&key).copied());
+            }
+        }
+    }
+}
@@ -1,5 +1,5 @@
-use crdt::Crdt;
 use crdt::properties;
+use crdt::{Crdt, Max, Min};
 use proptest::prelude::*;
 
 /// A simple CRDT where the state is a single value,
@@ -45,6 +45,14 @@ fn main() {
     println!("\nRunning automated property tests...");
     properties::check_eventual_consistency::<MyStruct>();
     println!("All CRDT properties (Idempotence, Commutativity, Associativity) hold!");
+
+    // The crate ships this register as `Max`, along with its mirror image `Min`.
+    let mut high = Max::new(10u32);
+    high.merge(&Max::new(20));
+    let mut low = Min::new(10u32);
+    low.merge(&Min::new(20));
+    println!("\nMax = {}, Min = {}", high.value(), low.value());
+    assert_eq!((high.value(), low.value()), (20, 10));
 }
 
 #[test]
@@ -63,3 +71,45 @@ fn test_merge() {
     c.merge(&a);
     assert_eq!(c.value, 30);
 }
+
+#[test]
+fn test_max_and_min_crdt() {
+    properties::check_eventual_consistency::<Max<u32>>();
+    properties::check_eventual_consistency::<Max<i64>>();
+    properties::check_leq_consistency::<Max<i64>>();
+    properties::check_eventual_consistency::<Min<u32>>();
+    properties::check_eventual_consistency::<Min<i64>>();
+    properties::check_leq_consistency::<Min<i64>>();
+}
+
+/// Unbounded types have no bottom, but still merge as CRDTs.
+#[test]
+fn test_max_and_min_crdt_without_bottom() {
+    use crdt::Apply;
+
+    properties::check_idempotence::<Max<String>>();
+    properties::check_commutativity::<Max<String>>();
+    properties::check_associativity::<Max<String>>();
+    properties::check_monotonicity::<Max<String>>();
+    properties::check_leq_consistency::<Max<String>>();
+    properties::check_idempotence::<Min<(u32, u32)>>();
+    properties::check_commutativity::<Min<(u32, u32)>>();
+    properties::check_associativity::<Min<(u32, u32)>>();
+    properties::check_leq_consistency::<Min<(u32, u32)>>();
+
+    let mut name = Max::new("alice".to_string());
+    name.apply("bob".to_string(), ());
+    name.merge(&Max::new("ann".to_string()));
+    assert_eq!(name.value(), "bob");
+}
+
+proptest! {
+    /// Merging keeps the extreme value, and a fresh register is neutral.
+    #[test]
+    fn test_max_and_min_merge(a in any::<i32>(), b in any::<i32>()) {
+        prop_assert_eq!(Max::new(a).merged(&Max::new(b)).value(), a.max(b));
+        prop_assert_eq!(Min::new(a).merged(&Min::new(b)).value(), a.min(b));
+        prop_assert_eq!(Max::default().merged(&Max::new(a)).value(), a);
+        prop_assert_eq!(Min::default().merged(&Min::new(a)).value(), a);
+    }
+}
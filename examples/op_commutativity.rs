@@ -0,0 +1,243 @@
+//! Checking that the operations of an op-based CRDT commute.
+//!
+//! Run with: `cargo run --example op_commutativity`
+
+use crdt::{Apply, Crdt, properties};
+use proptest::prelude::*;
+use std::collections::BTreeMap;
+
+/// An op-based inventory: each operation adjusts the stock of one item.
+///
+/// Adjustments are additions, so replicas that receive the same operations in
+/// different orders end up with the same stock.
+#[derive(Debug, Clone, PartialEq, Default)]
+struct Inventory {
+    stock: BTreeMap<String, i64>,
+}
+
+impl Crdt for Inventory {
+    type Value = BTreeMap<String, i64>;
+
+    // Op-based replicas converge by exchanging operations; merging full states
+    // is only used for the property checks' inputs.
+    fn merge(&mut self, other: &Self) {
+        for (item, &count) in &other.stock {
+            let entry = self.stock.entry(item.clone()).or_insert(0);
+            *entry = (*entry).max(count);
+        }
+    }
+
+    fn value(&self) -> Self::Value {
+        self.stock.clone()
+    }
+}
+
+impl Apply for Inventory {
+    type Op = (String, i8);
+    type Context = ();
+
+    fn apply(&mut self, (item, delta): Self::Op, _ctx: Self::Context) {
+        *self.stock.entry(item).or_insert(0) += i64::from(delta);
+    }
+}
+
+impl Arbitrary for Inventory {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::collection::btree_map("[a-c]".prop_map(String::from), 0i64..10, 0..3)
+            .prop_map(|stock| Inventory { stock })
+            .boxed()
+    }
+}
+
+/// A naive list where each operation inserts a character at an index.
+///
+/// Two concurrent inserts shift each other's positions, so the order in which
+/// they arrive changes the result.
+#[derive(Debug, Clone, PartialEq, Default)]
+struct NaiveList {
+    items: Vec<char>,
+}
+
+impl Crdt for NaiveList {
+    type Value = String;
+
+    fn merge(&mut self, other: &Self) {
+        if other.items.len() > self.items.len() {
+            self.items = other.items.clone();
+        }
+    }
+
+    fn value(&self) -> Self::Value {
+        self.items.iter().collect()
+    }
+}
+
+impl Apply for NaiveList {
+    type Op = (usize, char);
+    type Context = ();
+
+    fn apply(&mut self, (index, item): Self::Op, _ctx: Self::Context) {
+        let index = index.min(self.items.len());
+        self.items.insert(index, item);
+    }
+}
+
+impl Arbitrary for NaiveList {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::collection::vec(proptest::char::range('a', 'c'), 0..4)
+            .prop_map(|items| NaiveList { items })
+            .boxed()
+    }
+}
+
+/// A poll that only exists once its question is known.
+///
+/// There is no sensible empty poll, so it implements `Crdt` and `Apply`
+/// without `Default` (and therefore without `Bottom`).
+#[derive(Debug, Clone, PartialEq)]
+struct Poll {
+    question: String,
+    votes: BTreeMap<char, u64>,
+}
+
+impl Poll {
+    fn new(question: &str) -> Self {
+        Poll {
+            question: question.to_string(),
+            votes: BTreeMap::new(),
+        }
+    }
+}
+
+impl Crdt for Poll {
+    type Value = BTreeMap<char, u64>;
+
+    // Replicas of one poll share its question, so only the votes are merged.
+    fn merge(&mut self, other: &Self) {
+        for (&choice, &count) in &other.votes {
+            let entry = self.votes.entry(choice).or_insert(0);
+            *entry = (*entry).max(count);
+        }
+    }
+
+    fn value(&self) -> Self::Value {
+        self.votes.clone()
+    }
+}
+
+impl Apply for Poll {
+    type Op = char;
+    type Context = ();
+
+    fn apply(&mut self, choice: Self::Op, _ctx: Self::Context) {
+        *self.votes.entry(choice).or_insert(0) += 1;
+    }
+}
+
+impl Arbitrary for Poll {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::collection::btree_map(proptest::char::range('a', 'c'), 0u64..5, 0..3)
+            .prop_map(|votes| Poll {
+                question: "lunch?".to_string(),
+                votes,
+            })
+            .boxed()
+    }
+}
+
+fn main() {
+    println!("--- Op commutativity ---");
+
+    let mut list_a = NaiveList::default();
+    let mut list_b = NaiveList::default();
+    list_a.apply_all([((0, 'x'), ()), ((0, 'y'), ())]);
+    list_b.apply_all([((0, 'y'), ()), ((0, 'x'), ())]);
+    println!(
+        "Same inserts, different order: {:?} vs {:?}",
+        list_a.value(),
+        list_b.value()
+    );
+
+    properties::check_op_commutativity::<Inventory>();
+    println!("Inventory operations commute.");
+
+    let mut poll = Poll::new("lunch?");
+    poll.apply_all([('a', ()), ('b', ()), ('a', ())]);
+    println!("{:?} votes: {:?}", poll.question, poll.value());
+    properties::check_op_commutativity::<Poll>();
+    println!("Poll operations commute, without a Default state.");
+
+    let result = properties::try_check_op_commutativity::<NaiveList>();
+    println!(
+        "NaiveList operations commute: {}",
+        if result.is_ok() { "yes" } else { "no" }
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inventory_ops_commute() {
+        properties::check_op_commutativity::<Inventory>();
+    }
+
+    #[test]
+    fn gset_ops_commute() {
+        properties::check_op_commutativity::<crdt::GSet<String>>();
+    }
+
+    #[test]
+    fn poll_without_bottom_passes_merge_checks() {
+        // Every check except bottom neutrality works without `Default`.
+        properties::check_op_commutativity::<Poll>();
+        properties::check_idempotence::<Poll>();
+        properties::check_commutativity::<Poll>();
+        properties::check_associativity::<Poll>();
+        properties::check_monotonicity::<Poll>();
+        properties::check_leq_consistency::<Poll>();
+    }
+
+    #[test]
+    #[should_panic(expected = "Op commutativity failed")]
+    fn naive_list_ops_do_not_commute() {
+        properties::check_op_commutativity::<NaiveList>();
+    }
+
+    // Wrongly claimed: re-delivering an adjustment applies it twice.
+    impl crdt::IdempotentApply for Inventory {}
+
+    #[test]
+    fn idempotent_ops_pass_apply_checked() {
+        use crdt::{ApplyChecked, GSet, LWWRegister};
+
+        let mut set = GSet::new();
+        set.apply_checked(3, ());
+        assert!(set.contains(&3));
+
+        let mut register = LWWRegister::new();
+        register.apply_checked("a", 1);
+        register.apply_checked("b", 1);
+        assert_eq!(register.get(), Some(&"b"));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "apply is not idempotent")]
+    fn non_idempotent_ops_fail_apply_checked() {
+        use crdt::ApplyChecked;
+
+        let mut inventory = Inventory::default();
+        inventory.apply_checked(("apples".to_string(), 2), ());
+    }
+}
@@ -0,0 +1,622 @@
+//! Register CRDTs: single-value cells that resolve concurrent writes.
+//!
+//! Run with: `cargo run --example registers`
+
+use crdt::properties;
+use crdt::{
+    Crdt, DisableWinsFlag, DotRegister, EnableWinsFlag, FnRegister, LWWRegister, LamportClock,
+    MVRegister,
+};
+
+/// An axis-aligned bounding box, joined by expanding to cover both sides.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct BBox {
+    min: (i32, i32),
+    max: (i32, i32),
+}
+
+fn cover(a: &BBox, b: &BBox) -> BBox {
+    BBox {
+        min: (a.min.0.min(b.min.0), a.min.1.min(b.min.1)),
+        max: (a.max.0.max(b.max.0), a.max.1.max(b.max.1)),
+    }
+}
+
+fn main() {
+    println!("--- LWW-Register ---");
+
+    let mut node_a = LWWRegister::new();
+    let mut node_b = LWWRegister::new();
+
+    node_a.set("draft".to_string(), 1u64);
+    node_b.set("published".to_string(), 2u64);
+
+    node_a.merge(&node_b);
+    println!("After merge, A holds: {:?}", node_a.value());
+    assert_eq!(node_a.value(), Some("published".to_string()));
+
+    // Two writes with the same timestamp: the greater value wins on every replica.
+    let mut left = LWWRegister::new();
+    let mut right = LWWRegister::new();
+    left.set("apple".to_string(), 5u64);
+    right.set("banana".to_string(), 5u64);
+
+    let mut lr = left.clone();
+    lr.merge(&right);
+    let mut rl = right.clone();
+    rl.merge(&left);
+    println!("Tie at ts=5 resolves to {:?} in both orders", lr.value());
+    assert_eq!(lr, rl);
+
+    // A Lamport clock that observes the remote write stamps the next local
+    // write after it, so the local write wins without any wall-clock reads.
+    let clock = LamportClock::new();
+    let mut local = LWWRegister::new();
+    local.set_now("v1".to_string(), &clock);
+    let mut remote = LWWRegister::new();
+    remote.set("remote".to_string(), 10u64);
+    local.merge(&remote);
+    clock.observe(*local.timestamp().unwrap());
+    local.set_now("v2".to_string(), &clock);
+    println!(
+        "After observing ts=10, the next local write is stamped {:?}",
+        local.timestamp()
+    );
+    assert_eq!(local.value(), Some("v2".to_string()));
+
+    println!("\nRunning property tests for LWWRegister...");
+    properties::check_eventual_consistency::<LWWRegister<String, u64>>();
+    println!("LWWRegister satisfies all CRDT properties!");
+
+    println!("\n--- MV-Register ---");
+
+    let mut node_a = MVRegister::new();
+    node_a.set("red".to_string(), "a".to_string());
+    let mut node_b = node_a.clone();
+
+    // Both replicas overwrite "red" concurrently: neither write supersedes the other.
+    node_a.set("green".to_string(), "a".to_string());
+    node_b.set("blue".to_string(), "b".to_string());
+    node_a.merge(&node_b);
+    println!("Concurrent writes are both kept: {:?}", node_a.value());
+    assert_eq!(node_a.value().len(), 2);
+
+    // A later write observes both and resolves the conflict.
+    node_a.set("purple".to_string(), "a".to_string());
+    node_b.merge(&node_a);
+    println!("After resolving, B holds: {:?}", node_b.value());
+    assert!(!node_b.is_conflicted());
+
+    println!("\nRunning property tests for MVRegister...");
+    properties::check_eventual_consistency::<MVRegister<String, String>>();
+    println!("MVRegister satisfies all CRDT properties!");
+
+    println!("\n--- Dot Register ---");
+
+    // No clocks at all: causality alone decides which write is newer.
+    let mut node_a = DotRegister::new();
+    node_a.set("draft".to_string(), "a".to_string());
+    let mut node_b = node_a.clone();
+    node_a.set("edited by a".to_string(), "a".to_string());
+    node_b.set("edited by b".to_string(), "b".to_string());
+
+    node_a.merge(&node_b);
+    node_b.merge(&node_a);
+    println!("Concurrent edits resolve to: {:?}", node_a.value());
+    assert_eq!(node_a.value(), node_b.value());
+    assert!(node_a.is_conflicted());
+
+    println!("\nRunning property tests for DotRegister...");
+    properties::check_eventual_consistency::<DotRegister<String, String>>();
+    println!("DotRegister satisfies all CRDT properties!");
+
+    println!("\n--- Flags ---");
+
+    // A feature rollout that any node can switch on, and a kill switch that any
+    // node can pull.
+    let mut rollout_a = EnableWinsFlag::new();
+    let rollout_b = EnableWinsFlag::new();
+    let mut service_a = DisableWinsFlag::new();
+    let mut service_b = DisableWinsFlag::new();
+    rollout_a.enable();
+    service_b.disable();
+
+    let rollout = rollout_a.merged(&rollout_b);
+    service_a.merge(&service_b);
+    println!(
+        "Rollout enabled: {}, service enabled: {}",
+        rollout.value(),
+        service_a.value()
+    );
+    assert!(rollout.is_enabled());
+    assert!(!service_a.is_enabled());
+
+    println!("\n--- Fn-Register ---");
+
+    // The area two survey drones have covered, each starting from a point.
+    let mut drone_a = FnRegister::new(
+        BBox {
+            min: (0, 0),
+            max: (0, 0),
+        },
+        cover,
+    );
+    let mut drone_b = drone_a.clone();
+    drone_a.set(BBox {
+        min: (-2, 1),
+        max: (3, 4),
+    });
+    drone_b.set(BBox {
+        min: (1, -5),
+        max: (2, 2),
+    });
+
+    drone_a.merge(&drone_b);
+    println!("Covered area: {:?}", drone_a.value());
+    assert_eq!(
+        drone_a.value(),
+        BBox {
+            min: (-2, -5),
+            max: (3, 4)
+        }
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crdt::{Clock, Hlc, HlcTimestamp, SystemClock};
+    use proptest::prelude::*;
+    use std::cell::RefCell;
+
+    type Join = fn(&BBox, &BBox) -> BBox;
+
+    /// Lets the property checks generate bounding-box registers.
+    #[derive(Debug, Clone, PartialEq, Crdt)]
+    #[crdt(transparent)]
+    struct Coverage(FnRegister<BBox, Join>);
+
+    impl Arbitrary for Coverage {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            ((-5..5, -5..5), (0..5, 0..5))
+                .prop_map(|((x, y), (w, h))| {
+                    let bbox = BBox {
+                        min: (x, y),
+                        max: (x + w, y + h),
+                    };
+                    Coverage(FnRegister::new(bbox, cover as Join))
+                })
+                .boxed()
+        }
+    }
+
+    #[test]
+    fn fn_register_bbox_join_is_a_crdt() {
+        properties::check_idempotence::<Coverage>();
+        properties::check_commutativity::<Coverage>();
+        properties::check_associativity::<Coverage>();
+        properties::check_monotonicity::<Coverage>();
+    }
+
+    #[test]
+    fn fn_register_set_expands_box() {
+        let mut area = FnRegister::new(
+            BBox {
+                min: (0, 0),
+                max: (1, 1),
+            },
+            cover,
+        );
+        area.set(BBox {
+            min: (2, 2),
+            max: (3, 3),
+        });
+        assert_eq!(
+            *area.get(),
+            BBox {
+                min: (0, 0),
+                max: (3, 3)
+            }
+        );
+        area.set(BBox {
+            min: (1, 1),
+            max: (2, 2),
+        });
+        assert_eq!(
+            *area.get(),
+            BBox {
+                min: (0, 0),
+                max: (3, 3)
+            }
+        );
+    }
+
+    /// A clock that hands out a fixed script of timestamps, in order.
+    struct ScriptedClock(RefCell<Vec<u64>>);
+
+    impl ScriptedClock {
+        fn new(timestamps: &[u64]) -> Self {
+            let mut script = timestamps.to_vec();
+            script.reverse();
+            ScriptedClock(RefCell::new(script))
+        }
+    }
+
+    impl Clock<u64> for ScriptedClock {
+        fn now(&self) -> u64 {
+            self.0
+                .borrow_mut()
+                .pop()
+                .expect("scripted clock ran out of timestamps")
+        }
+    }
+
+    #[test]
+    fn lww_register_eventual_consistency() {
+        properties::check_eventual_consistency::<LWWRegister<String, u64>>();
+    }
+
+    #[test]
+    fn lww_register_tie_break_is_commutative() {
+        let mut a = LWWRegister::new();
+        a.set("x".to_string(), 7u64);
+        let mut b = LWWRegister::new();
+        b.set("y".to_string(), 7u64);
+
+        let mut ab = a.clone();
+        ab.merge(&b);
+        let mut ba = b.clone();
+        ba.merge(&a);
+
+        assert_eq!(ab, ba);
+        assert_eq!(ab.value(), Some("y".to_string()));
+    }
+
+    #[test]
+    fn lww_register_ignores_stale_writes() {
+        let mut reg = LWWRegister::new();
+        assert_eq!(reg.value(), None);
+        reg.set(10, 3u64);
+        reg.set(99, 2u64);
+        assert_eq!(reg.value(), Some(10));
+        assert_eq!(reg.timestamp(), Some(&3));
+    }
+
+    #[test]
+    fn lww_register_set_now_reads_clock() {
+        // The clock steps backwards, as a skewed wall clock might.
+        let clock = ScriptedClock::new(&[5, 3]);
+        let mut reg = LWWRegister::new();
+        reg.set_now("first".to_string(), &clock);
+        reg.set_now("second".to_string(), &clock);
+
+        assert_eq!(reg.value(), Some("first".to_string()));
+        assert_eq!(reg.timestamp(), Some(&5));
+    }
+
+    #[test]
+    fn lamport_clock_advances_past_observed_timestamps() {
+        let clock = LamportClock::new();
+        assert_eq!(clock.now(), 1);
+        assert_eq!(clock.now(), 2);
+
+        clock.observe(10);
+        assert_eq!(clock.current(), 10);
+        assert_eq!(clock.now(), 11);
+
+        // Observing an older timestamp never moves the clock back.
+        clock.observe(4);
+        assert_eq!(clock.now(), 12);
+    }
+
+    #[test]
+    fn lww_register_with_lamport_clock_overwrites_merged_writes() {
+        let clock = LamportClock::new();
+        let mut reg = LWWRegister::new();
+        let mut remote = LWWRegister::new();
+        remote.set("remote".to_string(), 7u64);
+
+        reg.merge(&remote);
+        clock.observe(7);
+        reg.set_now("local".to_string(), &clock);
+
+        assert_eq!(reg.value(), Some("local".to_string()));
+        assert_eq!(reg.timestamp(), Some(&8));
+    }
+
+    #[test]
+    fn system_clock_reads_time_since_epoch() {
+        // 2020-01-01T00:00:00Z in milliseconds.
+        assert!(SystemClock.now() > 1_577_836_800_000);
+    }
+
+    fn hlc(physical: u64, logical: u32) -> HlcTimestamp {
+        HlcTimestamp { physical, logical }
+    }
+
+    #[test]
+    fn hlc_tick_is_monotonic_when_physical_clock_steps_back() {
+        let mut clock = Hlc::new(ScriptedClock::new(&[100, 100, 90, 120]));
+
+        assert_eq!(clock.tick(), hlc(100, 0));
+        assert_eq!(clock.tick(), hlc(100, 1));
+        // The wall clock went backwards; the logical counter keeps order.
+        assert_eq!(clock.tick(), hlc(100, 2));
+        assert_eq!(clock.tick(), hlc(120, 0));
+    }
+
+    #[test]
+    fn hlc_update_moves_past_remote_timestamps() {
+        let mut clock = Hlc::new(ScriptedClock::new(&[10, 10, 10, 10, 50]));
+        assert_eq!(clock.tick(), hlc(10, 0));
+
+        // A remote replica whose clock runs far ahead.
+        clock.update(hlc(40, 3));
+        assert_eq!(clock.last(), hlc(40, 4));
+        assert_eq!(clock.tick(), hlc(40, 5));
+
+        // Same physical time on both sides: the larger logical counter wins.
+        clock.update(hlc(40, 9));
+        assert_eq!(clock.last(), hlc(40, 10));
+
+        // Local physical time overtakes both: the counter resets.
+        clock.update(hlc(45, 0));
+        assert_eq!(clock.last(), hlc(50, 0));
+    }
+
+    #[test]
+    fn hlc_exhausted_logical_counter_moves_to_next_millisecond() {
+        let mut clock = Hlc::new(ScriptedClock::new(&[10, 10, 10]));
+
+        // A peer hands over a timestamp whose logical counter is maxed out.
+        clock.update(hlc(10, u32::MAX));
+        assert_eq!(clock.last(), hlc(11, 0));
+        assert_eq!(clock.tick(), hlc(11, 1));
+
+        let mut local = Hlc::new(ScriptedClock::new(&[20, 20]));
+        local.update(hlc(20, u32::MAX - 1));
+        assert_eq!(local.last(), hlc(20, u32::MAX));
+        assert_eq!(local.tick(), hlc(21, 0));
+    }
+
+    #[test]
+    fn hlc_timestamps_order_lww_writes_across_skewed_replicas() {
+        // Replica A's wall clock runs 1000ms ahead of B's.
+        let mut a = Hlc::new(ScriptedClock::new(&[2000]));
+        let mut b = Hlc::new(ScriptedClock::new(&[1000, 1001]));
+        let mut reg = LWWRegister::new();
+
+        let ts_a = a.tick();
+        reg.set("from a".to_string(), ts_a);
+
+        // B receives A's write before making its own, so B's write wins even
+        // though B's wall clock says it is earlier.
+        b.update(ts_a);
+        reg.set("from b".to_string(), b.tick());
+        assert_eq!(reg.value(), Some("from b".to_string()));
+        assert_eq!(reg.timestamp(), Some(&hlc(2000, 2)));
+    }
+
+    proptest! {
+        /// Whatever the physical clock reads, ticks and updates never go backwards
+        /// and always land after the remote timestamp.
+        #[test]
+        fn hlc_never_goes_backwards(
+            events in proptest::collection::vec(
+                (
+                    0u64..50,
+                    proptest::option::of((0u64..50, prop_oneof![0u32..4, u32::MAX - 2..=u32::MAX])),
+                ),
+                1..20,
+            ),
+        ) {
+            let readings: Vec<u64> = events.iter().map(|(now, _)| *now).collect();
+            let mut clock = Hlc::new(ScriptedClock::new(&readings));
+            let mut previous = clock.last();
+            for (_, remote) in events {
+                let next = match remote {
+                    Some((physical, logical)) => {
+                        let remote = hlc(physical, logical);
+                        clock.update(remote);
+                        prop_assert!(clock.last() > remote);
+                        clock.last()
+                    }
+                    None => clock.tick(),
+                };
+                prop_assert!(next > previous);
+                previous = next;
+            }
+        }
+    }
+
+    #[test]
+    fn mv_register_eventual_consistency() {
+        properties::check_eventual_consistency::<MVRegister<String, String>>();
+    }
+
+    #[test]
+    fn mv_register_keeps_concurrent_writes() {
+        let mut a = MVRegister::new();
+        let mut b = MVRegister::new();
+        a.set(1, "a".to_string());
+        b.set(2, "b".to_string());
+
+        a.merge(&b);
+        assert_eq!(a.value(), [1, 2].into_iter().collect());
+    }
+
+    #[test]
+    fn lww_register_merge_with_resolves_ties() {
+        let mut conflicts = 0;
+        let mut a = LWWRegister::new();
+        let mut b = LWWRegister::new();
+        a.set("apple", 1);
+        b.set("banana", 1);
+
+        // The default tie-break would pick the greater value, "banana".
+        a.merge_with(&b, |local, remote| {
+            conflicts += 1;
+            std::cmp::min(*local, *remote)
+        });
+        assert_eq!(a.get(), Some(&"apple"));
+
+        // Strictly newer writes and identical values are not conflicts.
+        b.set("cherry", 2);
+        a.merge_with(&b, |_, _| unreachable!());
+        let same = a.clone();
+        a.merge_with(&same, |_, _| unreachable!());
+        assert_eq!(a.get(), Some(&"cherry"));
+        assert_eq!(conflicts, 1);
+    }
+
+    #[test]
+    fn mv_register_merge_with_collapses_concurrent_writes() {
+        let mut conflicts = 0;
+        let mut a = MVRegister::new();
+        let mut b = MVRegister::new();
+        let mut c = MVRegister::new();
+        a.set(1, "a".to_string());
+        b.set(5, "b".to_string());
+        c.set(3, "c".to_string());
+        b.merge(&c);
+        assert!(b.is_conflicted());
+
+        a.merge_with(&b, |x, y| {
+            conflicts += 1;
+            *x.max(y)
+        });
+        assert_eq!(conflicts, 2);
+        assert_eq!(a.value(), [5].into_iter().collect());
+
+        // The resolved write supersedes all the writes it replaced.
+        b.merge(&a);
+        assert_eq!(b.value(), [5].into_iter().collect());
+        c.merge(&a);
+        assert_eq!(c, a);
+    }
+
+    #[test]
+    fn mv_register_drops_dominated_writes() {
+        let mut a = MVRegister::new();
+        a.set(1, "a".to_string());
+        let stale = a.clone();
+        a.set(2, "a".to_string());
+
+        // The stale write happened before the new one, so it cannot come back.
+        a.merge(&stale);
+        assert_eq!(a.value(), [2].into_iter().collect());
+
+        let mut b = stale;
+        b.merge(&a);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn dot_register_eventual_consistency() {
+        properties::check_eventual_consistency::<DotRegister<String, String>>();
+        properties::check_leq_consistency::<DotRegister<String, String>>();
+    }
+
+    #[test]
+    fn dot_register_resolves_concurrent_writes_by_dot() {
+        let mut a = DotRegister::new();
+        let mut b = DotRegister::new();
+        assert_eq!(a.set("first", "a"), ("a", 1));
+        b.merge(&a);
+
+        // Both overwrite "first" concurrently; the greater dot wins.
+        a.set("from a", "a");
+        assert_eq!(b.set("from b", "b"), ("b", 1));
+        a.merge(&b);
+        assert!(a.is_conflicted());
+        assert_eq!(a.values().collect::<Vec<_>>(), [&"from a", &"from b"]);
+        assert_eq!(a.value(), Some("from b"));
+
+        // A later write has seen both, so it replaces them.
+        a.set("resolved", "a");
+        b.merge(&a);
+        assert_eq!(b.value(), Some("resolved"));
+        assert!(!b.is_conflicted());
+    }
+
+    proptest! {
+        /// Replicas that write and sync in any pattern converge once they have
+        /// all exchanged state, with no clock involved.
+        #[test]
+        fn dot_register_replicas_converge(
+            steps in proptest::collection::vec((0usize..3, 0usize..3, any::<bool>()), 0..20),
+        ) {
+            let names = ["a", "b", "c"];
+            let mut replicas = vec![DotRegister::new(); 3];
+            for (i, (replica, from, write)) in steps.into_iter().enumerate() {
+                if write {
+                    replicas[replica].set(i, names[replica]);
+                } else {
+                    let state = replicas[from].clone();
+                    replicas[replica].merge(&state);
+                }
+            }
+
+            let mut forward = DotRegister::new();
+            for replica in &replicas {
+                forward.merge(replica);
+            }
+            let mut backward = DotRegister::new();
+            for replica in replicas.iter().rev() {
+                backward.merge(replica);
+            }
+            prop_assert_eq!(forward.value(), backward.value());
+            prop_assert_eq!(forward, backward);
+        }
+    }
+
+    #[test]
+    fn flags_eventual_consistency() {
+        properties::check_eventual_consistency::<EnableWinsFlag>();
+        properties::check_leq_consistency::<EnableWinsFlag>();
+        properties::check_eventual_consistency::<DisableWinsFlag>();
+        properties::check_leq_consistency::<DisableWinsFlag>();
+    }
+
+    proptest! {
+        /// However many replicas are merged, a single enable (or disable) wins.
+        #[test]
+        fn flags_winning_write_survives_any_merge_order(
+            enabled in proptest::collection::vec(any::<bool>(), 1..6),
+        ) {
+            let enable_wins: Vec<_> = enabled
+                .iter()
+                .map(|&on| {
+                    let mut flag = EnableWinsFlag::new();
+                    if on {
+                        flag.enable();
+                    }
+                    flag
+                })
+                .collect();
+            let disable_wins: Vec<_> = enabled
+                .iter()
+                .map(|&on| {
+                    let mut flag = DisableWinsFlag::new();
+                    if !on {
+                        flag.disable();
+                    }
+                    flag
+                })
+                .collect();
+
+            let forward = crdt::join_all(enable_wins.clone()).unwrap();
+            let backward = crdt::join_all(enable_wins.into_iter().rev()).unwrap();
+            prop_assert_eq!(forward, backward);
+            prop_assert_eq!(forward.value(), enabled.iter().any(|&on| on));
+
+            let forward = crdt::join_all(disable_wins.clone()).unwrap();
+            let backward = crdt::join_all(disable_wins.into_iter().rev()).unwrap();
+            prop_assert_eq!(forward, backward);
+            prop_assert_eq!(forward.value(), enabled.iter().all(|&on| on));
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! Ready-made `Replica` identities for when Interval Tree Clocks are overkill.
+//!
+//! Run with: `cargo run --example replicas --features uuid`
+
+use crdt::{CounterReplica, Replica};
+
+fn main() {
+    println!("--- Counter replicas ---");
+
+    let mut origin = CounterReplica::new();
+    let mut worker = origin.fork();
+    let helper = worker.fork();
+    println!(
+        "origin = {}, worker = {}, helper = {}",
+        origin.id(),
+        worker.id(),
+        helper.id()
+    );
+    assert_eq!([origin.id(), worker.id(), helper.id()], [0, 1, 2]);
+
+    #[cfg(feature = "uuid")]
+    {
+        println!("\n--- UUID replicas ---");
+
+        let mut origin = crdt::UuidReplica::new();
+        let forked = origin.fork();
+        println!("origin = {}", origin.id());
+        println!("forked = {}", forked.id());
+        assert_ne!(origin.id(), forked.id());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn counter_forks_never_collide() {
+        let mut replicas = vec![CounterReplica::new()];
+        for i in 0..1000 {
+            // Fork from different replicas to show they share one sequence.
+            let parent = i % replicas.len();
+            let forked = replicas[parent].fork();
+            replicas.push(forked);
+        }
+
+        let ids: HashSet<u64> = replicas.iter().map(Replica::id).collect();
+        assert_eq!(ids.len(), replicas.len());
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn uuid_forks_have_distinct_ids() {
+        let mut origin = crdt::UuidReplica::new();
+        let id = origin.id();
+        let first = origin.fork();
+        let second = origin.fork();
+
+        assert_ne!(first.id(), second.id());
+        assert_ne!(first.id(), id);
+
+        // Joining discards the other replica's id.
+        origin.join(first);
+        assert_eq!(origin.id(), id);
+    }
+}
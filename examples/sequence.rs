@@ -0,0 +1,281 @@
+//! Collaborative editing with the `Rga` sequence CRDT.
+//!
+//! Run with: `cargo run --example sequence`
+
+use crdt::{Crdt, ElementId, GrowLog, Rga, properties};
+
+/// Types `text` into the sequence after `position`, returning the last id.
+fn type_text(
+    doc: &mut Rga<char, String>,
+    mut position: Option<ElementId<String>>,
+    text: &str,
+    replica: &str,
+) -> Option<ElementId<String>> {
+    for c in text.chars() {
+        position = Some(doc.insert_after(position.as_ref(), c, replica.to_string()));
+    }
+    position
+}
+
+fn render(doc: &Rga<char, String>) -> String {
+    doc.value().into_iter().collect()
+}
+
+fn main() {
+    println!("--- RGA ---");
+
+    let mut alice = Rga::new();
+    let end = type_text(&mut alice, None, "Hello", "alice");
+    let mut bob = alice.clone();
+
+    // Alice appends a name while Bob, concurrently, deletes the "H" and types "J".
+    type_text(&mut alice, end, " world", "alice");
+    let first = bob.ids()[0].clone();
+    bob.remove(&first);
+    type_text(&mut bob, Some(first), "J", "bob");
+
+    println!("Alice: {:?}", render(&alice));
+    println!("Bob:   {:?}", render(&bob));
+
+    alice.merge(&bob);
+    bob.merge(&alice);
+    println!("After sync: {:?}", render(&alice));
+    assert_eq!(render(&alice), "Jello world");
+    assert_eq!(alice, bob);
+
+    println!("\nRunning property tests for Rga...");
+    properties::check_eventual_consistency::<Rga<String, String>>();
+    println!("Rga satisfies all CRDT properties!");
+
+    println!("\n--- GrowLog ---");
+    let mut alice = GrowLog::new();
+    let mut bob = GrowLog::new();
+    alice.append("alice joined", "alice");
+    bob.append("bob joined", "bob");
+    bob.append("bob: hi", "bob");
+    alice.merge(&bob);
+    alice.append("alice: hello", "alice");
+    bob.merge(&alice);
+    println!("Log: {:?}", bob.value());
+    assert_eq!(
+        bob.value(),
+        ["alice joined", "bob joined", "bob: hi", "alice: hello"]
+    );
+
+    println!("\nRunning property tests for GrowLog...");
+    properties::check_eventual_consistency::<GrowLog<String, String>>();
+    println!("GrowLog satisfies all CRDT properties!");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use proptest::sample::Index;
+
+    #[test]
+    fn rga_eventual_consistency() {
+        properties::check_eventual_consistency::<Rga<String, String>>();
+    }
+
+    #[test]
+    fn rga_concurrent_runs_do_not_interleave() {
+        let mut alice = Rga::new();
+        let mut bob = Rga::new();
+        type_text(&mut alice, None, "abc", "alice");
+        type_text(&mut bob, None, "xyz", "bob");
+
+        let ab = alice.merged(&bob);
+        let ba = bob.merged(&alice);
+        assert_eq!(ab, ba);
+        // Equal timestamps: "bob" sorts after "alice", so Bob's run comes first.
+        assert_eq!(render(&ab), "xyzabc");
+    }
+
+    #[test]
+    fn rga_concurrent_inserts_at_same_position_tie_break_by_dot() {
+        let mut base = Rga::new();
+        let anchor = base.insert_after(None, 'a', "c".to_string());
+        let mut left = base.clone();
+        let mut right = base;
+
+        left.insert_after(Some(&anchor), 'L', "b".to_string());
+        right.insert_after(Some(&anchor), 'R', "d".to_string());
+
+        // Same seq on both sides, so the higher replica id is placed first.
+        assert_eq!(render(&left.merged(&right)), "aRL");
+        assert_eq!(render(&right.merged(&left)), "aRL");
+    }
+
+    #[test]
+    fn rga_insert_after_tombstone() {
+        let mut doc = Rga::new();
+        let a = doc.insert_after(None, 'a', "r".to_string());
+        let b = doc.insert_after(Some(&a), 'b', "r".to_string());
+        assert!(doc.remove(&a));
+        assert!(!doc.remove(&a));
+        doc.insert_after(Some(&a), 'c', "r".to_string());
+
+        assert_eq!(render(&doc), "cb");
+        assert_eq!(doc.len(), 2);
+        assert_eq!(doc.get(&a), None);
+        assert_eq!(doc.get(&b), Some(&'b'));
+    }
+
+    #[test]
+    fn growlog_eventual_consistency() {
+        properties::check_eventual_consistency::<GrowLog<String, String>>();
+    }
+
+    #[test]
+    fn growlog_orders_by_timestamp_then_replica() {
+        let mut left = GrowLog::new();
+        let mut right = GrowLog::new();
+        left.append('a', "b");
+        right.append('x', "a");
+        right.append('y', "a");
+
+        // Both first appends have timestamp 1, so replica "a" goes first.
+        assert_eq!(left.merged(&right).value(), ['x', 'a', 'y']);
+        assert_eq!(right.merged(&left).value(), ['x', 'a', 'y']);
+
+        // An append after a merge follows everything its replica had seen.
+        let mut synced = left.merged(&right);
+        let id = synced.append('z', "b");
+        assert_eq!(id.seq, 3);
+        assert_eq!(synced.value(), ['x', 'a', 'y', 'z']);
+        assert_eq!(synced.get(&id), Some(&'z'));
+    }
+
+    /// One local edit: insert at or remove from a position chosen by index.
+    #[derive(Debug, Clone)]
+    struct Edit {
+        replica: usize,
+        position: Index,
+        value: char,
+        is_insert: bool,
+    }
+
+    fn edits() -> impl Strategy<Value = Vec<Edit>> {
+        proptest::collection::vec(
+            (
+                0usize..3,
+                any::<Index>(),
+                proptest::char::range('a', 'e'),
+                any::<bool>(),
+            )
+                .prop_map(|(replica, position, value, is_insert)| Edit {
+                    replica,
+                    position,
+                    value,
+                    is_insert,
+                }),
+            0..30,
+        )
+    }
+
+    /// Applies `edit` to `doc` as a local operation of its replica.
+    fn apply_edit(doc: &mut Rga<char, String>, edit: &Edit) {
+        let ids = doc.ids();
+        if edit.is_insert {
+            let after = match edit.position.index(ids.len() + 1) {
+                0 => None,
+                n => Some(&ids[n - 1]),
+            };
+            doc.insert_after(after, edit.value, edit.replica.to_string());
+        } else if !ids.is_empty() {
+            doc.remove(edit.position.get(&ids));
+        }
+    }
+
+    proptest! {
+        /// Replicas that edit concurrently and gossip occasionally converge to
+        /// the same sequence whichever order their states are finally merged in.
+        #[test]
+        fn rga_replicas_converge(edits in edits(), gossip in any::<u8>()) {
+            let mut replicas = vec![Rga::new(); 3];
+            for (i, edit) in edits.iter().enumerate() {
+                apply_edit(&mut replicas[edit.replica], edit);
+                if (i as u8).wrapping_add(gossip) % 4 == 0 {
+                    let from = replicas[edit.replica].clone();
+                    replicas[(edit.replica + 1) % 3].merge(&from);
+                }
+            }
+
+            let forward = crdt::join_all(replicas.clone()).unwrap();
+            let backward = crdt::join_all(replicas.into_iter().rev()).unwrap();
+            prop_assert_eq!(forward.value(), backward.value());
+            prop_assert_eq!(forward, backward);
+        }
+
+        /// Delivering every intermediate state of every replica, shuffled,
+        /// yields the same document as delivering them in order.
+        #[test]
+        fn rga_shuffled_delivery_converges(
+            edits in edits(),
+            order in proptest::collection::vec(any::<u64>(), 30),
+        ) {
+            let mut replicas = vec![Rga::new(); 3];
+            let mut snapshots = Vec::new();
+            for edit in &edits {
+                apply_edit(&mut replicas[edit.replica], edit);
+                snapshots.push(replicas[edit.replica].clone());
+            }
+
+            let mut in_order = Rga::new();
+            for snapshot in &snapshots {
+                in_order.merge(snapshot);
+            }
+
+            let mut keyed: Vec<_> = order.into_iter().zip(snapshots).collect();
+            keyed.sort_by_key(|(key, _)| *key);
+            let mut shuffled = Rga::new();
+            for (_, snapshot) in &keyed {
+                shuffled.merge(snapshot);
+            }
+
+            prop_assert_eq!(in_order.value(), shuffled.value());
+            prop_assert_eq!(in_order, shuffled);
+        }
+
+        /// A single replica's edits behave like edits to a plain `Vec`.
+        #[test]
+        fn rga_matches_vec_model(edits in edits()) {
+            let mut doc = Rga::new();
+            let mut model = Vec::new();
+            for edit in &edits {
+                let len = model.len();
+                if edit.is_insert {
+                    model.insert(edit.position.index(len + 1), edit.value);
+                } else if len > 0 {
+                    model.remove(edit.position.index(len));
+                }
+                apply_edit(&mut doc, edit);
+            }
+            prop_assert_eq!(doc.value(), model);
+        }
+
+        /// Replicas appending concurrently and gossiping occasionally agree on
+        /// the log's order whichever order their states are finally merged in.
+        #[test]
+        fn growlog_replicas_converge(
+            appends in proptest::collection::vec((0usize..3, proptest::char::range('a', 'e')), 0..30),
+            gossip in any::<u8>(),
+        ) {
+            let mut replicas = vec![GrowLog::new(); 3];
+            for (i, (replica, value)) in appends.iter().enumerate() {
+                replicas[*replica].append(*value, *replica);
+                if (i as u8).wrapping_add(gossip) % 4 == 0 {
+                    let from = replicas[*replica].clone();
+                    replicas[(replica + 1) % 3].merge(&from);
+                }
+            }
+
+            let forward = crdt::join_all(replicas.clone()).unwrap();
+            let backward = crdt::join_all(replicas.into_iter().rev()).unwrap();
+            prop_assert_eq!(forward.len(), appends.len());
+            prop_assert_eq!(forward.value(), backward.value());
+            prop_assert_eq!(forward, backward);
+        }
+    }
+}
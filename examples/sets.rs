@@ -0,0 +1,596 @@
+//! Set CRDTs that support removal.
+//!
+//! Run with: `cargo run --example sets`
+
+use crdt::properties;
+use crdt::{Apply, Crdt, LWWSet, LWWSetOp, ORSet, OrderedGSet, PNSet, PNSetOp, TwoPSet, TwoPSetOp};
+
+fn main() {
+    println!("--- 2P-Set ---");
+
+    let mut node_a = TwoPSet::new();
+    node_a.insert("milk".to_string());
+    node_a.insert("eggs".to_string());
+    let mut node_b = node_a.clone();
+
+    // B removes eggs while A adds bread.
+    node_b.apply(TwoPSetOp::Remove("eggs".to_string()), ());
+    node_a.apply(TwoPSetOp::Add("bread".to_string()), ());
+
+    node_a.merge(&node_b);
+    node_b.merge(&node_a);
+    println!("After sync: {:?}", node_a.value());
+    assert_eq!(node_a, node_b);
+    assert!(!node_a.contains(&"eggs".to_string()));
+
+    // Once removed, an element can never come back.
+    node_a.insert("eggs".to_string());
+    assert!(!node_a.contains(&"eggs".to_string()));
+    println!("Re-adding a removed element has no effect.");
+
+    println!("\nRunning property tests for TwoPSet...");
+    properties::check_delta_sync_properties::<TwoPSet<String>>();
+    println!("TwoPSet satisfies all CRDT and delta sync properties!");
+
+    println!("\n--- OR-Set ---");
+
+    let mut node_a = ORSet::new();
+    node_a.add("milk".to_string(), "a".to_string());
+    let mut node_b = node_a.clone();
+
+    // B removes milk while A concurrently adds it again: the unseen add wins.
+    node_b.remove(&"milk".to_string());
+    node_a.add("milk".to_string(), "a".to_string());
+
+    node_a.merge(&node_b);
+    node_b.merge(&node_a);
+    println!("After concurrent add/remove: {:?}", node_a.value());
+    assert!(node_a.contains(&"milk".to_string()));
+    assert_eq!(node_a, node_b);
+
+    println!("\nRunning property tests for ORSet...");
+    properties::check_eventual_consistency::<ORSet<String, String>>();
+    println!("ORSet satisfies all CRDT properties!");
+
+    println!("\n--- LWW-Element-Set ---");
+
+    let mut node_a = LWWSet::new();
+    node_a.add("milk".to_string(), 1);
+    let mut node_b = node_a.clone();
+
+    // B removes milk at time 2; A adds it back later, at time 3.
+    node_b.apply(LWWSetOp::Remove("milk".to_string()), 2);
+    node_a.apply(LWWSetOp::Add("milk".to_string()), 3);
+
+    node_a.merge(&node_b);
+    node_b.merge(&node_a);
+    println!("After sync: {:?}", node_a.value());
+    assert!(node_a.contains(&"milk".to_string()));
+    assert_eq!(node_a, node_b);
+
+    println!("\nRunning property tests for LWWSet...");
+    properties::check_eventual_consistency::<LWWSet<String, u64>>();
+    println!("LWWSet satisfies all CRDT properties!");
+
+    println!("\n--- PN-Set ---");
+
+    let mut phone = PNSet::new();
+    let mut laptop = PNSet::new();
+
+    // Two apples go in the cart on the phone; the laptop takes one out.
+    phone.add("apple".to_string(), "phone".to_string());
+    phone.add("apple".to_string(), "phone".to_string());
+    laptop.merge(&phone);
+    laptop.apply(PNSetOp::Remove("apple".to_string()), "laptop".to_string());
+
+    phone.merge(&laptop);
+    println!("Apples in cart: {}", phone.count(&"apple".to_string()));
+    assert_eq!(phone.count(&"apple".to_string()), 1);
+    assert!(phone.contains(&"apple".to_string()));
+
+    println!("\nRunning property tests for PNSet...");
+    properties::check_eventual_consistency::<PNSet<String, String>>();
+    println!("PNSet satisfies all CRDT properties!");
+
+    println!("\n--- Ordered G-Set ---");
+
+    let mut log_a = OrderedGSet::new();
+    let mut log_b = OrderedGSet::new();
+
+    // Each replica appends to its own copy of a shared log.
+    log_a.insert("boot".to_string());
+    log_b.insert("connect".to_string());
+    log_a.insert("login".to_string());
+
+    log_a.merge(&log_b);
+    log_b.merge(&log_a);
+    println!("Log order: {:?}", log_a.value_ordered());
+    assert_eq!(log_a.value_ordered(), log_b.value_ordered());
+
+    // Appends after a merge land after everything merged so far.
+    log_b.insert("logout".to_string());
+    assert_eq!(log_b.value_ordered().last().unwrap(), "logout");
+
+    println!("\nRunning property tests for OrderedGSet...");
+    properties::check_eventual_consistency::<OrderedGSet<String>>();
+    println!("OrderedGSet satisfies all CRDT properties!");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crdt::CrdtDebug;
+    use proptest::prelude::*;
+
+    #[test]
+    fn twopset_eventual_consistency() {
+        properties::check_eventual_consistency::<TwoPSet<String>>();
+    }
+
+    #[test]
+    fn twopset_remove_requires_presence() {
+        let mut set = TwoPSet::new();
+        assert!(!set.remove(1));
+        set.insert(1);
+        assert!(set.remove(1));
+        assert!(!set.contains(&1));
+        assert!(set.value().is_empty());
+    }
+
+    #[test]
+    fn orset_eventual_consistency() {
+        properties::check_eventual_consistency::<ORSet<String, String>>();
+    }
+
+    #[test]
+    fn orset_readd_after_remove() {
+        let mut set = ORSet::new();
+        let first = set.add(1, "a");
+        set.remove(&1);
+        assert!(!set.contains(&1));
+        let second = set.add(1, "a");
+        assert_ne!(first, second);
+        assert!(set.contains(&1));
+    }
+
+    proptest! {
+        /// An add concurrent with a remove of the same element survives the merge,
+        /// whichever way round the replicas are merged.
+        #[test]
+        fn orset_add_wins(base in any::<ORSet<String, String>>(), item in "[a-e]") {
+            let mut adder = base.clone();
+            adder.add(item.clone(), "z".to_string());
+            let mut remover = base;
+            remover.remove(&item);
+
+            let mut ab = adder.clone();
+            ab.merge(&remover);
+            let mut ba = remover.clone();
+            ba.merge(&adder);
+
+            prop_assert!(ab.contains(&item));
+            prop_assert_eq!(ab, ba);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn gset_serde_round_trip() {
+        let mut set = crdt::GSet::new();
+        set.insert("apple".to_string());
+        set.insert("pear".to_string());
+
+        let json = serde_json::to_string(&set).unwrap();
+        let restored: crdt::GSet<String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, set);
+    }
+
+    #[test]
+    fn gset_value_ref_borrows_the_set() {
+        let set: crdt::GSet<u32> = [1, 2, 3].into_iter().collect();
+        let borrowed = set.value_ref().expect("GSet stores its value");
+        assert_eq!(*borrowed, set.value());
+        // Every call hands out the same set: the one inside `set`, not a copy.
+        assert!(std::ptr::eq(borrowed, set.value_ref().unwrap()));
+
+        // Types whose value is computed fall back to `value`.
+        assert_eq!(TwoPSet::<u32>::new().value_ref(), None);
+    }
+
+    #[test]
+    fn gset_from_iterator_and_iter() {
+        let set: crdt::GSet<u32> = [1, 2, 3, 2].into_iter().collect();
+        assert_eq!(set.len(), 3);
+        assert_eq!(set.iter().sum::<u32>(), 6);
+        assert_eq!((&set).into_iter().count(), 3);
+
+        let mut owned: Vec<u32> = set.into_iter().collect();
+        owned.sort();
+        assert_eq!(owned, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn twopset_try_apply_rejects_invalid_ops() {
+        use crdt::{TryApply, TwoPSetError};
+
+        let mut set = TwoPSet::new();
+        assert_eq!(
+            set.try_apply(TwoPSetOp::Remove("x"), ()),
+            Err(TwoPSetError::NotPresent)
+        );
+        assert_eq!(set.try_apply(TwoPSetOp::Add("x"), ()), Ok(()));
+        assert_eq!(set.try_apply(TwoPSetOp::Remove("x"), ()), Ok(()));
+        assert_eq!(
+            set.try_apply(TwoPSetOp::Add("x"), ()),
+            Err(TwoPSetError::AlreadyRemoved)
+        );
+        assert!(!set.contains(&"x"));
+    }
+
+    proptest! {
+        #[test]
+        fn gset_apply_all_matches_individual_applies(
+            ops in proptest::collection::vec("[a-e]", 0..20),
+        ) {
+            let mut batched = crdt::GSet::new();
+            batched.apply_all(ops.iter().cloned().map(|op| (op, ())));
+
+            let mut one_by_one = crdt::GSet::new();
+            for op in ops {
+                one_by_one.apply(op, ());
+            }
+            prop_assert_eq!(batched, one_by_one);
+        }
+    }
+
+    proptest! {
+        /// Copying elements in gives the same set and report as the cloning path.
+        #[test]
+        fn gset_merge_copy_matches_merge(
+            a in proptest::collection::hash_set(0..20u64, 0..10),
+            b in proptest::collection::hash_set(0..20u64, 0..10),
+        ) {
+            let mut cloned: crdt::GSet<u64> = a.iter().copied().collect();
+            let mut copied = cloned.clone();
+            let other: crdt::GSet<u64> = b.into_iter().collect();
+
+            let cloned_report = cloned.merge_report(&other);
+            let copied_report = copied.merge_copy(&other);
+            prop_assert_eq!(cloned_report, copied_report);
+            prop_assert_eq!(&copied, &cloned);
+            prop_assert_eq!(copied, a.into_iter().chain(other).collect::<crdt::GSet<u64>>());
+        }
+    }
+
+    proptest! {
+        /// Equal sets hash equal, regardless of insertion order.
+        #[test]
+        fn gset_equal_states_hash_equal(items in proptest::collection::vec("[a-e]", 0..10)) {
+            let forward: crdt::GSet<String> = items.iter().cloned().collect();
+            let backward: crdt::GSet<String> = items.iter().rev().cloned().collect();
+            prop_assert_eq!(&forward, &backward);
+            prop_assert_eq!(forward.state_hash(), backward.state_hash());
+        }
+    }
+
+    #[test]
+    fn lwwset_eventual_consistency() {
+        properties::check_eventual_consistency::<LWWSet<String, u64>>();
+    }
+
+    #[test]
+    fn lwwset_concurrent_add_and_remove_tie_keeps_element() {
+        let mut node_a = LWWSet::new();
+        let mut node_b = LWWSet::new();
+        node_a.add("x", 5);
+        node_b.add("x", 1);
+        node_b.remove("x", 5);
+
+        node_a.merge(&node_b);
+        node_b.merge(&node_a);
+        assert_eq!(node_a, node_b);
+        assert!(node_a.contains(&"x"));
+
+        // A strictly later remove does take effect, and a later add revives it.
+        node_a.remove("x", 6);
+        assert!(!node_a.contains(&"x"));
+        node_a.add("x", 7);
+        assert_eq!(node_a.value(), std::collections::HashSet::from(["x"]));
+    }
+
+    #[test]
+    fn lwwset_now_methods_stamp_from_clock() {
+        let clock = crdt::LamportClock::new();
+        let mut set = LWWSet::new();
+        set.add_now("x", &clock);
+        set.remove_now("x", &clock);
+        assert!(!set.contains(&"x"));
+
+        // The remove was stamped 2, so a concurrent add at 1 stays removed.
+        let mut other = LWWSet::new();
+        other.add("x", 1);
+        set.merge(&other);
+        assert!(!set.contains(&"x"));
+
+        set.add_now("x", &clock);
+        assert!(set.contains(&"x"));
+    }
+
+    proptest! {
+        /// Replicas that see the same operations in any order converge.
+        #[test]
+        fn lwwset_ops_in_any_order_converge(
+            ops in proptest::collection::vec((any::<bool>(), "[a-c]", 0u64..4), 0..10),
+        ) {
+            let ops: Vec<_> = ops
+                .into_iter()
+                .map(|(add, value, ts)| {
+                    let op = if add { LWWSetOp::Add(value) } else { LWWSetOp::Remove(value) };
+                    (op, ts)
+                })
+                .collect();
+
+            let mut forward = LWWSet::new();
+            forward.apply_all(ops.iter().cloned());
+            let mut backward = LWWSet::new();
+            backward.apply_all(ops.into_iter().rev());
+            prop_assert_eq!(forward, backward);
+        }
+    }
+
+    #[test]
+    fn pnset_eventual_consistency() {
+        properties::check_eventual_consistency::<PNSet<String, String>>();
+    }
+
+    #[test]
+    fn pnset_element_can_be_readded_after_removal() {
+        let mut set = PNSet::new();
+        set.add("x", "a");
+        set.remove("x", "a");
+        assert!(!set.contains(&"x"));
+
+        set.add("x", "b");
+        assert!(set.contains(&"x"));
+        assert_eq!(set.value(), std::collections::HashSet::from(["x"]));
+    }
+
+    proptest! {
+        /// Replicas that each apply their own ops converge once they exchange state.
+        #[test]
+        fn pnset_replicas_converge(
+            ops in proptest::collection::vec((any::<bool>(), "[a-c]", 0usize..3), 0..12),
+        ) {
+            let mut replicas = vec![PNSet::new(); 3];
+            for (add, value, replica) in ops {
+                let op = if add { PNSetOp::Add(value) } else { PNSetOp::Remove(value) };
+                replicas[replica].apply(op, replica);
+            }
+
+            let forward = crdt::join_all(replicas.clone()).unwrap();
+            let backward = crdt::join_all(replicas.into_iter().rev()).unwrap();
+            prop_assert_eq!(&forward, &backward);
+            prop_assert_eq!(forward.value(), backward.value());
+        }
+    }
+
+    #[test]
+    fn orset_removals_keep_context_bounded() {
+        let mut node_a = ORSet::new();
+        let mut node_b = ORSet::new();
+        for i in 0..500 {
+            let item = i % 7;
+            node_a.add(item, "a");
+            node_b.add(item, "b");
+            node_b.merge(&node_a);
+            node_b.remove(&item);
+            node_a.merge(&node_b);
+        }
+
+        node_b.merge(&node_a);
+        assert_eq!(node_a, node_b);
+        assert!(node_a.value().is_empty());
+        // A thousand removed dots compact to one context entry per replica.
+        assert_eq!(node_a.context().len(), 2);
+    }
+
+    #[test]
+    fn causal_context_compacts_once_gaps_fill() {
+        let mut context = crdt::CausalContext::new();
+        context.insert(("a", 3));
+        context.insert(("a", 2));
+        assert!(context.contains(&("a", 3)));
+        assert!(!context.contains(&("a", 1)));
+        assert_eq!(context.len(), 2);
+
+        context.insert(("a", 1));
+        assert_eq!(context.len(), 1);
+        assert_eq!(context.next_dot("a"), ("a", 4));
+
+        let mut other = crdt::CausalContext::new();
+        other.insert(("b", 2));
+        context.merge(&other);
+        assert!(context.contains(&("b", 2)));
+        assert!(!context.contains(&("b", 1)));
+        assert_eq!(context.len(), 2);
+    }
+
+    proptest! {
+        /// Replicas that apply their own histories and then exchange state agree,
+        /// with no more context entries than replicas.
+        #[test]
+        fn orset_replicas_converge_with_bounded_context(
+            ops in proptest::collection::vec((0usize..3, "[a-c]", any::<bool>()), 0..40),
+        ) {
+            let names = ["a", "b", "c"];
+            let mut replicas = vec![ORSet::new(); 3];
+            for (i, (replica, item, is_add)) in ops.into_iter().enumerate() {
+                if is_add {
+                    replicas[replica].add(item, names[replica]);
+                } else {
+                    replicas[replica].remove(&item);
+                }
+                // Gossip to a neighbour every few steps.
+                if i % 3 == 0 {
+                    let from = replicas[replica].clone();
+                    replicas[(replica + 1) % 3].merge(&from);
+                }
+            }
+
+            let forward = crdt::join_all(replicas.clone()).unwrap();
+            let backward = crdt::join_all(replicas.into_iter().rev()).unwrap();
+            prop_assert_eq!(&forward, &backward);
+            prop_assert!(forward.context().len() <= 3);
+        }
+    }
+
+    #[test]
+    fn gset_merge_report_counts_new_elements() {
+        let mut local: crdt::GSet<u32> = [1, 2].into_iter().collect();
+        let remote: crdt::GSet<u32> = [2, 3, 4].into_iter().collect();
+
+        let report = local.merge_report(&remote);
+        assert!(report.changed);
+        assert_eq!(report.updated, 2);
+
+        // The remote state is now dominated, so merging it again is a no-op.
+        let report = local.merge_report(&remote);
+        assert!(!report.changed);
+        assert_eq!(report.updated, 0);
+    }
+
+    #[test]
+    fn gset_summary_lists_elements_in_order() {
+        let set: crdt::GSet<u32> = [3, 1, 2].into_iter().collect();
+
+        assert_eq!(
+            set.summarize(),
+            "GSet (3 elements) {\n    1,\n    2,\n    3,\n}"
+        );
+        assert_eq!(set.debug_summary(), set.summarize());
+    }
+
+    #[test]
+    fn gset_filter_leaves_set_unchanged() {
+        let set: crdt::GSet<u32> = (1..=6).collect();
+        let before = set.clone();
+
+        let mut even: Vec<_> = set.filter(|n| n % 2 == 0).copied().collect();
+        even.sort();
+        assert_eq!(even, vec![2, 4, 6]);
+        assert_eq!(set.filter(|n| *n > 10).count(), 0);
+
+        assert_eq!(set, before);
+        assert_eq!(set.len(), 6);
+    }
+
+    /// Counts allocations made by the current thread, so tests running in
+    /// parallel do not disturb each other's counts.
+    struct CountingAllocator;
+
+    thread_local! {
+        static ALLOCATIONS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    }
+
+    unsafe impl std::alloc::GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: std::alloc::Layout) -> *mut u8 {
+            ALLOCATIONS.with(|count| count.set(count.get() + 1));
+            unsafe { std::alloc::System.alloc(layout) }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: std::alloc::Layout) {
+            unsafe { std::alloc::System.dealloc(ptr, layout) }
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    fn allocations() -> usize {
+        ALLOCATIONS.with(|count| count.get())
+    }
+
+    #[test]
+    fn gset_merging_a_subset_does_not_allocate() {
+        let set: crdt::GSet<String> = (0..100).map(|i| format!("element-{i}")).collect();
+        let subset: crdt::GSet<String> = (0..50).map(|i| format!("element-{i}")).collect();
+        let mut merged = set.clone();
+
+        let before = allocations();
+        merged.merge(&subset);
+        assert_eq!(allocations(), before);
+        assert_eq!(merged, set);
+    }
+
+    #[test]
+    fn ordered_gset_eventual_consistency() {
+        properties::check_eventual_consistency::<OrderedGSet<String>>();
+    }
+
+    #[test]
+    fn ordered_gset_keeps_earliest_timestamp() {
+        let mut node_a = OrderedGSet::new();
+        let mut node_b = OrderedGSet::new();
+        node_a.insert_at("x", 5);
+        node_b.insert_at("x", 2);
+        node_b.insert_at("y", 3);
+
+        node_a.merge(&node_b);
+        assert_eq!(node_a.timestamp(&"x"), Some(2));
+        assert_eq!(node_a.value_ordered(), vec!["x", "y"]);
+
+        // Re-inserting a present element does not move it.
+        assert_eq!(node_a.insert("x"), 2);
+        assert_eq!(node_a.insert("z"), 6);
+        assert_eq!(node_a.value_ordered(), vec!["x", "y", "z"]);
+    }
+
+    proptest! {
+        /// Two replicas inserting independently agree on the order after syncing.
+        #[test]
+        fn ordered_gset_replicas_converge_to_same_order(
+            ops_a in proptest::collection::vec("[a-e]", 0..8),
+            ops_b in proptest::collection::vec("[a-e]", 0..8),
+            base in any::<OrderedGSet<String>>(),
+        ) {
+            let mut node_a = base.clone();
+            let mut node_b = base;
+            for value in ops_a {
+                node_a.insert(value);
+            }
+            for value in ops_b {
+                node_b.insert(value);
+            }
+
+            node_a.merge(&node_b);
+            node_b.merge(&node_a);
+            prop_assert_eq!(&node_a, &node_b);
+            prop_assert_eq!(node_a.value_ordered(), node_b.value_ordered());
+        }
+    }
+
+    proptest! {
+        /// Capacity hints only affect allocation, never the set's contents.
+        #[test]
+        fn gset_with_capacity_matches_new(
+            items in proptest::collection::vec("[a-e]", 0..20),
+            capacity in 0..64usize,
+        ) {
+            let mut plain = crdt::GSet::new();
+            let mut sized = crdt::GSet::with_capacity(capacity);
+            sized.reserve(items.len());
+            for item in items {
+                plain.insert(item.clone());
+                sized.insert(item);
+            }
+            prop_assert_eq!(sized, plain);
+        }
+    }
+
+    #[test]
+    fn gset_display_sorts_elements() {
+        let set: crdt::GSet<&str> = ["pear", "apple", "fig"].into_iter().collect();
+        assert_eq!(set.to_string(), "GSet{apple, fig, pear}");
+        assert_eq!(crdt::GSet::<u32>::new().to_string(), "GSet{}");
+    }
+}
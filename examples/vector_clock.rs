@@ -0,0 +1,472 @@
+//! Tracking causality with vector clocks.
+//!
+//! Run with: `cargo run --example vector_clock`
+
+use crdt::properties;
+use crdt::{Crdt, VectorClock};
+
+fn main() {
+    println!("--- Vector Clock ---");
+
+    let mut alice = VectorClock::new();
+    alice.inc("alice".to_string());
+
+    // Bob has seen Alice's event and then does his own work.
+    let mut bob = alice.clone();
+    bob.inc("bob".to_string());
+
+    println!("alice = {:?}", alice.value());
+    println!("bob   = {:?}", bob.value());
+    assert!(alice.happened_before(&bob));
+    assert!(alice.leq(&bob));
+
+    // Meanwhile Alice does more work without hearing from Bob.
+    alice.inc("alice".to_string());
+    assert!(alice.concurrent(&bob));
+    println!("After another local event, alice and bob are concurrent.");
+
+    println!("\nRunning property tests for VectorClock...");
+    properties::check_eventual_consistency::<VectorClock<String>>();
+    properties::check_leq_consistency::<VectorClock<String>>();
+    println!("VectorClock satisfies all CRDT properties!");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crdt::CrdtDebug;
+    use proptest::prelude::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn vector_clock_eventual_consistency_with_more_cases() {
+        let config = proptest::test_runner::Config::with_cases(1024);
+        properties::check_eventual_consistency_with::<VectorClock<String>>(config);
+    }
+
+    #[test]
+    fn vector_clock_leq_matches_merge() {
+        properties::check_leq_consistency::<VectorClock<String>>();
+    }
+
+    #[test]
+    fn vector_clock_leq_is_causal_order() {
+        let mut a = VectorClock::new();
+        a.inc("a");
+        let mut b = a.clone();
+        b.inc("b");
+        let mut c = a.clone();
+        c.inc("c");
+
+        assert!(a.leq(&a));
+        assert!(a.leq(&b));
+        assert!(!b.leq(&a));
+        assert!(!b.leq(&c) && !c.leq(&b));
+    }
+
+    #[test]
+    fn vector_clock_inc_returns_new_time() {
+        let mut clock = VectorClock::new();
+        assert_eq!(clock.inc("a"), 1);
+        assert_eq!(clock.inc("a"), 2);
+        assert_eq!(clock.inc("b"), 1);
+        assert_eq!(clock.get(&"a"), 2);
+    }
+
+    #[test]
+    fn vector_clock_size_helpers() {
+        let mut clock = VectorClock::new();
+        assert!(clock.is_empty());
+        assert!(!clock.knows(&"a"));
+
+        clock.inc("a");
+        clock.inc("a");
+        clock.inc("b");
+        assert_eq!(clock.len(), 2);
+        assert!(!clock.is_empty());
+        assert!(clock.knows(&"a"));
+        assert!(!clock.knows(&"c"));
+
+        let mut replicas: Vec<_> = clock.replicas().copied().collect();
+        replicas.sort();
+        assert_eq!(replicas, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn vector_clock_dominates_and_diff() {
+        let mut a = VectorClock::new();
+        a.inc("a");
+        let mut b = a.clone();
+        b.inc("b");
+        b.inc("b");
+
+        assert!(b.dominates(&a));
+        assert!(!a.dominates(&b));
+        assert!(a.dominates(&a));
+        assert_eq!(b.diff(&a), HashMap::from([("b", (2, 0))]));
+        assert!(a.diff(&a).is_empty());
+
+        // Concurrent clocks dominate neither way, and disagree on both replicas.
+        a.inc("a");
+        assert!(!a.dominates(&b) && !b.dominates(&a));
+        assert_eq!(a.diff(&b), HashMap::from([("a", (2, 1)), ("b", (0, 2))]));
+    }
+
+    proptest! {
+        #[test]
+        fn prune_preserves_comparisons(
+            a in any::<VectorClock<String>>(),
+            b in any::<VectorClock<String>>(),
+        ) {
+            let mut pruned = a.clone();
+            pruned.prune();
+            prop_assert_eq!(pruned.partial_cmp(&b), a.partial_cmp(&b));
+            prop_assert_eq!(&pruned, &a);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn pruned_clock_equals_unpruned_twin() {
+        use std::cmp::Ordering;
+
+        // Explicit zeros can arrive from a peer's serialized clock.
+        let unpruned: VectorClock<String> =
+            serde_json::from_str(r#"{"clocks":{"a":2,"b":0}}"#).unwrap();
+        let mut pruned = unpruned.clone();
+        pruned.prune();
+
+        assert_eq!(pruned.value().len(), 1);
+        assert_eq!(pruned.get(&"b".to_string()), 0);
+        assert_eq!(pruned.partial_cmp(&unpruned), Some(Ordering::Equal));
+        assert_eq!(pruned, unpruned);
+
+        let mut merged = VectorClock::new();
+        merged.merge(&unpruned);
+        assert_eq!(merged.value().len(), 1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn vector_clock_canonical_bytes_skip_zeros() {
+        let mut clock = VectorClock::new();
+        clock.inc("b".to_string());
+        clock.inc("a".to_string());
+
+        let with_zero: VectorClock<String> =
+            serde_json::from_str(r#"{"clocks":{"c":0,"b":1,"a":1}}"#).unwrap();
+        assert_eq!(clock.canonical_bytes(), with_zero.canonical_bytes());
+        assert_eq!(clock.canonical_bytes(), br#"[["a",1],["b",1]]"#);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn vector_clock_canonical_bytes_accept_tuple_ids() {
+        let mut clock = VectorClock::new();
+        clock.inc((2, "b"));
+        clock.inc((1, "a"));
+        assert_eq!(clock.canonical_bytes(), br#"[[[1,"a"],1],[[2,"b"],1]]"#);
+    }
+
+    proptest! {
+        /// Merging in either order gives equal clocks, which must hash equal.
+        #[test]
+        fn vector_clock_equal_states_hash_equal(
+            a in any::<VectorClock<String>>(),
+            b in any::<VectorClock<String>>(),
+        ) {
+            let ab = a.merged(&b);
+            let ba = b.merged(&a);
+            prop_assert_eq!(&ab, &ba);
+            prop_assert_eq!(ab.state_hash(), ba.state_hash());
+        }
+    }
+
+    #[test]
+    fn vector_clock_merge_report_dominated_is_unchanged() {
+        let mut old = VectorClock::new();
+        old.inc("a");
+        let mut new = old.clone();
+        new.inc("a");
+        new.inc("b");
+
+        let report = new.merge_report(&old);
+        assert!(!report.changed);
+        assert_eq!(report.updated, 0);
+
+        let report = old.merge_report(&new);
+        assert!(report.changed);
+        assert_eq!(report.updated, 2);
+        assert_eq!(old, new);
+    }
+
+    #[test]
+    fn vector_clock_summary_lists_replicas_in_order() {
+        let mut clock = VectorClock::new();
+        clock.inc("b");
+        clock.inc("b");
+        clock.inc("a");
+
+        assert_eq!(
+            clock.summarize(),
+            "VectorClock {\n    \"a\": 1,\n    \"b\": 2,\n}"
+        );
+        assert_eq!(clock.debug_summary(), clock.summarize());
+    }
+
+    #[test]
+    fn vector_clock_delta_since_keeps_only_newer_entries() {
+        let mut local = VectorClock::new();
+        local.inc("a");
+        local.inc("a");
+        local.inc("b");
+        let mut remote = VectorClock::new();
+        remote.inc("a");
+        remote.inc("b");
+        remote.inc("c");
+
+        let delta = local.delta_since(&remote);
+        assert_eq!(delta.len(), 1);
+        assert_eq!(delta.get(&"a"), 2);
+        assert!(remote.delta_since(&remote).is_empty());
+    }
+
+    proptest! {
+        /// Merging the delta into the remote yields the join of both clocks.
+        #[test]
+        fn vector_clock_delta_since_brings_remote_to_join(
+            local in any::<VectorClock<String>>(),
+            remote in any::<VectorClock<String>>(),
+        ) {
+            let delta = local.delta_since(&remote);
+            prop_assert!(delta.leq(&local));
+
+            let mut synced = remote.clone();
+            synced.merge(&delta);
+            prop_assert_eq!(synced, local.merged(&remote));
+        }
+    }
+
+    #[test]
+    fn stable_frontier_is_elementwise_minimum() {
+        let clock = |entries: &[(&'static str, u64)]| {
+            let mut clock = VectorClock::new();
+            for &(replica, count) in entries {
+                for _ in 0..count {
+                    clock.inc(replica);
+                }
+            }
+            clock
+        };
+        let a = clock(&[("a", 3), ("b", 1), ("c", 2)]);
+        let b = clock(&[("a", 2), ("b", 2), ("c", 2)]);
+        let c = clock(&[("a", 4), ("b", 1)]);
+
+        let frontier = crdt::stable_frontier(&[a.clone(), b.clone(), c.clone()]);
+        assert_eq!(frontier, clock(&[("a", 2), ("b", 1)]));
+        assert!(!frontier.knows(&"c"));
+        for replica in [&a, &b, &c] {
+            assert!(frontier.leq(replica));
+        }
+        assert!(crdt::stable_frontier::<&str>(&[]).is_empty());
+    }
+
+    #[test]
+    fn oplog_gc_drops_only_stable_ops() {
+        let mut log = crdt::OpLog::new();
+        let mut a = VectorClock::new();
+        let mut b = VectorClock::new();
+
+        a.inc("a");
+        log.push(a.clone(), "a1");
+        b.merge(&a);
+        b.inc("b");
+        log.push(b.clone(), "b1");
+        // Concurrent with b1: a has not seen it.
+        a.inc("a");
+        log.push(a.clone(), "a2");
+
+        // Both replicas have seen a1; b1 and a2 are each missing from one side.
+        let stable = crdt::stable_frontier(&[a.clone(), b.clone()]);
+        log.gc(&stable);
+        let retained: Vec<_> = log.iter().map(|(_, op)| *op).collect();
+        assert_eq!(retained, ["b1", "a2"]);
+
+        // After a full sync everything is stable.
+        a.merge(&b);
+        b.merge(&a);
+        log.gc(&crdt::stable_frontier(&[a, b]));
+        assert!(log.is_empty());
+    }
+
+    /// Issues ops from a script of `(replica, sees_all_so_far)` steps, returning
+    /// each op's name and clock in issue order.
+    fn causal_history<'a>(steps: &[(&'a str, bool)]) -> Vec<(VectorClock<&'a str>, String)> {
+        let mut clocks: HashMap<&str, VectorClock<&str>> = HashMap::new();
+        let mut everything = VectorClock::new();
+        let mut history = Vec::new();
+        for (i, &(replica, sees_all)) in steps.iter().enumerate() {
+            let clock = clocks.entry(replica).or_default();
+            if sees_all {
+                clock.merge(&everything);
+            }
+            clock.inc(replica);
+            everything.merge(clock);
+            history.push((clock.clone(), format!("{replica}{i}")));
+        }
+        history
+    }
+
+    #[test]
+    fn causal_buffer_releases_reversed_ops_in_order() {
+        let history = causal_history(&[("a", true), ("a", true), ("b", true), ("a", true)]);
+        let mut buffer = crdt::CausalBuffer::new();
+
+        let mut released = Vec::new();
+        for (clock, op) in history.iter().rev().cloned() {
+            released.extend(buffer.push(clock, op));
+        }
+        assert_eq!(released, ["a0", "a1", "b2", "a3"]);
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.delivered(), &history[3].0);
+    }
+
+    #[test]
+    fn causal_buffer_holds_ops_until_dependencies_arrive() {
+        // b2 depends on a0 and a1; c3 is concurrent with both of them.
+        let history = causal_history(&[("a", true), ("a", true), ("b", true), ("c", false)]);
+        let mut buffer = crdt::CausalBuffer::new();
+        let [a0, a1, b2, c3] = history.try_into().unwrap();
+
+        assert!(buffer.push(b2.0, b2.1).is_empty());
+        assert!(buffer.push(a1.0, a1.1).is_empty());
+        assert_eq!(buffer.len(), 2);
+        assert_eq!(buffer.push(c3.0, c3.1), ["c3"]);
+        assert_eq!(buffer.push(a0.0.clone(), a0.1.clone()), ["a0", "a1", "b2"]);
+
+        // A redelivered op is a duplicate.
+        assert!(buffer.push(a0.0, a0.1).is_empty());
+        assert!(buffer.is_empty());
+    }
+
+    proptest! {
+        /// Whatever order a causal history arrives in, every op is released
+        /// exactly once and after everything it causally depends on.
+        #[test]
+        fn causal_buffer_respects_causality(
+            (steps, order) in proptest::collection::vec(("[a-c]", any::<bool>()), 1..12)
+                .prop_flat_map(|steps| {
+                    let order: Vec<usize> = (0..steps.len()).collect();
+                    (Just(steps), Just(order).prop_shuffle())
+                }),
+        ) {
+            let steps: Vec<(&str, bool)> = steps
+                .iter()
+                .map(|(replica, sees_all)| (replica.as_str(), *sees_all))
+                .collect();
+            let history = causal_history(&steps);
+            let arrivals: Vec<_> = order.iter().map(|&i| history[i].clone()).collect();
+
+            let mut buffer = crdt::CausalBuffer::new();
+            let mut released = Vec::new();
+            for (clock, op) in arrivals.iter().cloned() {
+                released.extend(buffer.push(clock, op));
+            }
+            prop_assert!(buffer.is_empty());
+            prop_assert_eq!(released.len(), arrivals.len());
+
+            let clock_of = |op: &String| &arrivals.iter().find(|(_, name)| name == op).unwrap().0;
+            for (i, first) in released.iter().enumerate() {
+                for then in &released[i + 1..] {
+                    prop_assert!(!clock_of(then).happened_before(clock_of(first)));
+                }
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn vector_clock_compact_encoding_round_trips(
+            clock in crdt::arbitrary::vector_clock_strategy(any::<u64>(), 0..20),
+            dense in crdt::arbitrary::vector_clock_strategy(0u64..300, 0..200),
+        ) {
+            for clock in [clock, dense] {
+                let decoded = VectorClock::decode_compact(&clock.encode_compact()).unwrap();
+                prop_assert_eq!(decoded, clock);
+            }
+        }
+    }
+
+    #[test]
+    fn vector_clock_compact_encoding_beats_fixed_width() {
+        let mut clock = VectorClock::new();
+        for replica in 0..200u64 {
+            for _ in 0..replica % 5 + 1 {
+                clock.inc(replica * 3);
+            }
+        }
+        // A naive encoding writes each entry as two fixed-width u64s.
+        let naive = clock.len() * 2 * size_of::<u64>();
+        let compact = clock.encode_compact();
+        assert_eq!(compact.len(), 2 + 200 * 2);
+        assert!(compact.len() * 4 < naive);
+    }
+
+    #[test]
+    fn vector_clock_decode_compact_rejects_bad_input() {
+        use crdt::CompactDecodeError;
+
+        let mut clock = VectorClock::new();
+        clock.inc(7u64);
+        let mut bytes = clock.encode_compact();
+        assert_eq!(
+            VectorClock::decode_compact(&bytes[..2]),
+            Err(CompactDecodeError::UnexpectedEnd)
+        );
+        bytes.push(0);
+        assert_eq!(
+            VectorClock::decode_compact(&bytes),
+            Err(CompactDecodeError::TrailingBytes)
+        );
+        // Two entries for the same replica.
+        assert_eq!(
+            VectorClock::decode_compact(&[2, 7, 1, 0, 1]),
+            Err(CompactDecodeError::NotCanonical)
+        );
+        assert_eq!(
+            VectorClock::decode_compact(&[
+                1, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f, 1
+            ]),
+            Err(CompactDecodeError::Overflow)
+        );
+    }
+
+    proptest! {
+        /// Capacity hints only affect allocation, never the clock's entries.
+        #[test]
+        fn with_capacity_matches_new(
+            replicas in proptest::collection::vec("[a-e]", 0..20),
+            capacity in 0..64usize,
+        ) {
+            let mut plain = VectorClock::new();
+            let mut sized = VectorClock::with_capacity(capacity);
+            sized.reserve(replicas.len());
+            for replica in replicas {
+                prop_assert_eq!(sized.inc(replica.clone()), plain.inc(replica));
+            }
+            prop_assert_eq!(sized, plain);
+        }
+    }
+
+    #[test]
+    fn vector_clock_display_sorts_replicas() {
+        let mut clock = VectorClock::new();
+        assert_eq!(clock.to_string(), "VectorClock{}");
+        for _ in 0..5 {
+            clock.inc("b");
+        }
+        clock.inc("a");
+        clock.inc("a");
+        assert_eq!(clock.to_string(), "VectorClock{a:2, b:5}");
+    }
+}
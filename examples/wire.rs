@@ -0,0 +1,133 @@
+//! Sending CRDT states between replicas in the compact binary wire format.
+//!
+//! Run with: `cargo run --example wire --features bincode`
+
+use crdt::{Crdt, GCounter, Wire};
+
+fn main() {
+    println!("--- Wire Format ---");
+
+    let mut counter = GCounter::new();
+    counter.add(40, "alice".to_string());
+    counter.add(2, "bob".to_string());
+
+    let bytes = counter.to_wire();
+    let json = serde_json::to_vec(&counter).unwrap();
+    println!("bincode: {} bytes, JSON: {} bytes", bytes.len(), json.len());
+
+    // The receiving replica decodes the state and merges it.
+    let mut remote = GCounter::new();
+    remote.inc("carol".to_string());
+    remote.merge(&GCounter::from_wire(&bytes).unwrap());
+    println!("remote = {}", remote.value());
+    assert_eq!(remote.value(), 43);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crdt::{EstimateSize, GSet, ItcClock, ItcStamp, VectorClock, WireError};
+    use proptest::prelude::*;
+
+    fn assert_round_trips<T: Wire + PartialEq + std::fmt::Debug>(value: &T) {
+        assert_eq!(&T::from_wire(&value.to_wire()).unwrap(), value);
+    }
+
+    /// Checks that the estimate is exact for bincode's fixed-width `legacy`
+    /// layout and an upper bound on the varint wire encoding.
+    fn check_estimate<T>(state: &T) -> Result<(), TestCaseError>
+    where
+        T: EstimateSize + Wire + serde::Serialize,
+    {
+        let fixed = bincode::serde::encode_to_vec(state, bincode::config::legacy()).unwrap();
+        prop_assert_eq!(state.estimated_size(), fixed.len());
+        prop_assert!(state.to_wire().len() <= state.estimated_size());
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn estimated_size_bounds_wire_length(
+            counter in any::<GCounter<String>>(),
+            set in any::<GSet<String>>(),
+            clock in any::<VectorClock<String>>(),
+        ) {
+            check_estimate(&counter)?;
+            check_estimate(&set)?;
+            check_estimate(&clock)?;
+        }
+
+        #[test]
+        fn gcounter_round_trips(counter in any::<GCounter<String>>()) {
+            let decoded = GCounter::from_wire(&counter.to_wire()).unwrap();
+            prop_assert_eq!(decoded.value(), counter.value());
+            prop_assert_eq!(decoded, counter);
+        }
+
+        #[test]
+        fn gset_round_trips(set in any::<GSet<String>>()) {
+            prop_assert_eq!(GSet::from_wire(&set.to_wire()).unwrap(), set);
+        }
+
+        #[test]
+        fn vector_clock_round_trips(clock in any::<VectorClock<String>>()) {
+            prop_assert_eq!(VectorClock::from_wire(&clock.to_wire()).unwrap(), clock);
+        }
+
+        #[test]
+        fn itc_clock_round_trips(clock in any::<ItcClock>()) {
+            prop_assert_eq!(ItcClock::from_wire(&clock.to_wire()).unwrap(), clock);
+        }
+    }
+
+    #[test]
+    fn gcounter_with_overflowing_total_is_malformed() {
+        // Merging saturates the total, but the counts still sum past u64::MAX.
+        let mut counter = GCounter::new();
+        counter.add(u64::MAX, "a".to_string());
+        counter.merge(&[("b".to_string(), 1)].into_iter().collect());
+        let result = GCounter::<String>::from_wire(&counter.to_wire());
+        assert!(matches!(result, Err(WireError::Malformed(_))));
+    }
+
+    #[test]
+    fn itc_stamps_and_identities_round_trip() {
+        let (mut a, b) = ItcStamp::new().fork();
+        a.event();
+        assert_round_trips(&a);
+        assert_round_trips(&b.peek());
+
+        let (replica, _) = a.into_parts();
+        assert_round_trips(&replica);
+    }
+
+    #[test]
+    fn wire_is_smaller_than_json() {
+        let mut counter = GCounter::new();
+        let mut clock = VectorClock::new();
+        let mut set = GSet::new();
+        for i in 0..20 {
+            counter.add(1000 + i, format!("replica-{i}"));
+            clock.inc(format!("replica-{i}"));
+            set.insert(format!("item-{i}"));
+        }
+
+        assert!(counter.to_wire().len() < serde_json::to_vec(&counter).unwrap().len());
+        assert!(clock.to_wire().len() < serde_json::to_vec(&clock).unwrap().len());
+        assert!(set.to_wire().len() < serde_json::to_vec(&set).unwrap().len());
+    }
+
+    #[test]
+    fn from_wire_rejects_bad_input() {
+        let mut bytes = GSet::from_iter(["a".to_string()]).to_wire();
+        bytes.push(0);
+        assert!(matches!(
+            GSet::<String>::from_wire(&bytes),
+            Err(WireError::TrailingBytes)
+        ));
+        assert!(matches!(
+            GSet::<String>::from_wire(&bytes[..1]),
+            Err(WireError::Malformed(_))
+        ));
+    }
+}
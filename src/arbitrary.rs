@@ -0,0 +1,77 @@
+//! Reusable proptest strategies for the built-in CRDTs.
+//!
+//! Each strategy is built from proptest's collection strategies, so a failing
+//! case shrinks by dropping entries and lowering counts towards 1 (or 0 for
+//! clocks). Counterexamples reported by [`crate::properties`] then show only
+//! the replicas or elements needed to reproduce the failure.
+
+use crate::{GCounter, GSet, VectorClock};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::hash::Hash;
+use proptest::collection::{SizeRange, hash_map, hash_set, vec};
+use proptest::prelude::*;
+
+/// Generates a [`GCounter`] with up to `replicas` entries, each counting 1–99.
+pub fn gcounter_strategy<I>(
+    replica: impl Strategy<Value = I> + 'static,
+    replicas: impl Into<SizeRange>,
+) -> BoxedStrategy<GCounter<I>>
+where
+    I: Hash + Eq + Clone + Debug + 'static,
+{
+    hash_map(replica, 1u64..100, replicas)
+        .prop_map(|counts| {
+            let mut counter = GCounter::new();
+            for (replica, amount) in counts {
+                counter.add(amount, replica);
+            }
+            counter
+        })
+        .boxed()
+}
+
+/// Generates a [`GSet`] with up to `len` elements.
+pub fn gset_strategy<T>(
+    element: impl Strategy<Value = T> + 'static,
+    len: impl Into<SizeRange>,
+) -> BoxedStrategy<GSet<T>>
+where
+    T: Hash + Eq + Clone + Debug + 'static,
+{
+    hash_set(element, len)
+        .prop_map(|set| set.into_iter().collect())
+        .boxed()
+}
+
+/// Generates a [`VectorClock`] with up to `replicas` entries, each at 0–49.
+pub fn vector_clock_strategy<I>(
+    replica: impl Strategy<Value = I> + 'static,
+    replicas: impl Into<SizeRange>,
+) -> BoxedStrategy<VectorClock<I>>
+where
+    I: Hash + Eq + Clone + Debug + 'static,
+{
+    hash_map(replica, 0u64..50, replicas)
+        .prop_map(|clocks| {
+            let mut clock = VectorClock::new();
+            for (replica, count) in clocks {
+                for _ in 0..count {
+                    clock.inc(replica.clone());
+                }
+            }
+            clock
+        })
+        .boxed()
+}
+
+/// Generates one or two replica names for states that mint unique ids, such
+/// as [`Rga`](crate::Rga) or [`ORSet`](crate::ORSet).
+///
+/// Names are drawn from a large space: independently generated states must
+/// not mint the same id for different operations, which real replicas with
+/// unique ids never do.
+pub fn replica_names_strategy() -> BoxedStrategy<Vec<String>> {
+    vec("[a-z]{8}", 1..3).boxed()
+}
@@ -0,0 +1,36 @@
+use crate::Crdt;
+use core::future::Future;
+use core::pin::pin;
+use futures_util::stream::{Stream, StreamExt};
+
+/// Extension trait for merging states as they arrive on an async [`Stream`].
+///
+/// Implemented for every [`Crdt`]. Requires the `futures` feature.
+pub trait MergeStreamExt: Crdt {
+    /// Merges each state from `stream` into `self` as it arrives, resolving
+    /// once the stream ends.
+    ///
+    /// The future resolves to the number of states that changed `self`; states
+    /// already dominated by the local one (see [`Crdt::leq`]) are skipped, like
+    /// a no-op [`MergeReport`](crate::MergeReport).
+    fn merge_stream<S>(&mut self, stream: S) -> impl Future<Output = usize>
+    where
+        S: Stream<Item = Self>;
+}
+
+impl<T: Crdt> MergeStreamExt for T {
+    async fn merge_stream<S>(&mut self, stream: S) -> usize
+    where
+        S: Stream<Item = Self>,
+    {
+        let mut stream = pin!(stream);
+        let mut changed = 0;
+        while let Some(state) = stream.next().await {
+            if !state.leq(self) {
+                self.merge(&state);
+                changed += 1;
+            }
+        }
+        changed
+    }
+}
@@ -0,0 +1,122 @@
+use super::vector::VectorClock;
+use crate::Crdt;
+use crate::collections::Key;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// Buffers ops that arrive out of causal order and releases them once every op
+/// they depend on has been delivered.
+///
+/// Each op is tagged with the vector clock of its origin, taken right after the
+/// origin ticked its own entry for it. An op is deliverable when its clock is
+/// exactly one event ahead of the [`delivered`](CausalBuffer::delivered)
+/// clock: the origin's next event, with every dependency already in. Released
+/// ops can be fed straight to [`Apply`](crate::Apply) in the order returned.
+/// Ops whose clock is already covered by the delivered clock are duplicates
+/// and are dropped.
+///
+/// # Type Parameters
+/// * `I`: The type of the Replica ID. Must be `Hash`, `Eq`, `Clone`, and `Debug`.
+/// * `Op`: The type of the buffered operations.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CausalBuffer<I, Op>
+where
+    I: Key + Clone,
+{
+    /// Everything released so far.
+    delivered: VectorClock<I>,
+    /// Ops waiting on a dependency, in arrival order.
+    pending: Vec<(VectorClock<I>, Op)>,
+}
+
+impl<I: Key + Clone, Op> Default for CausalBuffer<I, Op> {
+    fn default() -> Self {
+        Self {
+            delivered: VectorClock::default(),
+            pending: Vec::new(),
+        }
+    }
+}
+
+impl<I, Op> CausalBuffer<I, Op>
+where
+    I: Key + Clone + Debug,
+{
+    /// Creates an empty buffer that has delivered nothing yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty buffer that has already delivered everything up to
+    /// `delivered`, e.g. a replica resuming from a snapshot.
+    pub fn with_delivered(delivered: VectorClock<I>) -> Self {
+        Self {
+            delivered,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Accepts an op issued at `clock` and returns every op that is now
+    /// deliverable, in causal order.
+    ///
+    /// The result is empty if the op is still waiting on a dependency or is a
+    /// duplicate. Otherwise it starts with this op and continues with any
+    /// buffered ops it unblocked, oldest arrival first among concurrent ones.
+    pub fn push(&mut self, clock: VectorClock<I>, op: Op) -> Vec<Op> {
+        if clock.leq(&self.delivered) {
+            return Vec::new();
+        }
+        self.pending.push((clock, op));
+
+        let mut released = Vec::new();
+        while let Some(index) = self
+            .pending
+            .iter()
+            .position(|(clock, _)| self.is_next(clock))
+        {
+            let (clock, op) = self.pending.remove(index);
+            self.delivered.merge(&clock);
+            released.push(op);
+        }
+        if !released.is_empty() {
+            let delivered = &self.delivered;
+            self.pending.retain(|(clock, _)| !clock.leq(delivered));
+        }
+        released
+    }
+
+    /// Returns the clock of everything delivered so far.
+    pub fn delivered(&self) -> &VectorClock<I> {
+        &self.delivered
+    }
+
+    /// Iterates over the ops still waiting on a dependency, in arrival order.
+    pub fn pending(&self) -> impl Iterator<Item = (&VectorClock<I>, &Op)> {
+        self.pending.iter().map(|(clock, op)| (clock, op))
+    }
+
+    /// Returns the number of ops waiting on a dependency.
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Returns true if no ops are waiting.
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Returns true if `clock` is exactly one event ahead of the delivered clock.
+    fn is_next(&self, clock: &VectorClock<I>) -> bool {
+        let mut ahead = 0;
+        for replica in clock.replicas() {
+            ahead += clock
+                .get(replica)
+                .saturating_sub(self.delivered.get(replica));
+            if ahead > 1 {
+                return false;
+            }
+        }
+        ahead == 1
+    }
+}
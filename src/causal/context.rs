@@ -0,0 +1,113 @@
+use crate::collections::{Key, Map, Set};
+
+/// A causal context: the set of dots `(replica, sequence)` a replica has observed.
+///
+/// Dots are stored compactly as the highest contiguous sequence number seen
+/// from each replica, so that dots `1..=n` from a replica cost a single entry.
+/// Dots that arrive ahead of a gap are kept individually in a "dot cloud" until
+/// the gap is filled, at which point they are folded into the compact part.
+///
+/// # Type Parameters
+/// * `I`: The type of the Replica ID. Must be `Hash`, `Eq`, `Clone`, and `Debug`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CausalContext<I>
+where
+    I: Key,
+{
+    /// Highest contiguous sequence number seen from each replica.
+    compact: Map<I, u64>,
+    /// Observed dots that are not yet contiguous with `compact`.
+    cloud: Set<(I, u64)>,
+}
+
+impl<I: Key> Default for CausalContext<I> {
+    fn default() -> Self {
+        Self {
+            compact: Map::new(),
+            cloud: Set::new(),
+        }
+    }
+}
+
+impl<I> CausalContext<I>
+where
+    I: Key + Clone,
+{
+    /// Creates a new, empty causal context.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if the dot has been observed.
+    pub fn contains(&self, dot: &(I, u64)) -> bool {
+        let (replica, seq) = dot;
+        *seq <= self.compact.get(replica).copied().unwrap_or(0) || self.cloud.contains(dot)
+    }
+
+    /// Records a single observed dot.
+    pub fn insert(&mut self, dot: (I, u64)) {
+        if !self.contains(&dot) {
+            self.cloud.insert(dot);
+            self.compact();
+        }
+    }
+
+    /// Mints and records the next dot for the given replica.
+    ///
+    /// A replica only mints its own dots, so they are always contiguous in its
+    /// own context and the new dot is one past the compact entry.
+    pub fn next_dot(&mut self, replica: I) -> (I, u64) {
+        let seq = self.compact.get(&replica).copied().unwrap_or(0) + 1;
+        self.compact.insert(replica.clone(), seq);
+        self.compact();
+        (replica, seq)
+    }
+
+    /// Adds every dot observed by `other` to this context.
+    pub fn merge(&mut self, other: &Self) {
+        for (replica, &seq) in &other.compact {
+            let entry = self.compact.entry(replica.clone()).or_insert(0);
+            *entry = (*entry).max(seq);
+        }
+        for dot in &other.cloud {
+            if !self.contains(dot) {
+                self.cloud.insert(dot.clone());
+            }
+        }
+        self.compact();
+    }
+
+    /// Returns the number of stored entries: one per replica plus one per
+    /// dot that could not be compacted.
+    pub fn len(&self) -> usize {
+        self.compact.len() + self.cloud.len()
+    }
+
+    /// Returns true if no dots have been observed.
+    pub fn is_empty(&self) -> bool {
+        self.compact.is_empty() && self.cloud.is_empty()
+    }
+
+    /// Folds cloud dots that have become contiguous into `compact`, and drops
+    /// cloud dots that `compact` already covers.
+    fn compact(&mut self) {
+        loop {
+            let mut progressed = false;
+            let compact = &mut self.compact;
+            self.cloud.retain(|(replica, seq)| {
+                let max = compact.get(replica).copied().unwrap_or(0);
+                if *seq == max + 1 {
+                    compact.insert(replica.clone(), *seq);
+                    progressed = true;
+                    false
+                } else {
+                    *seq > max
+                }
+            });
+            if !progressed {
+                break;
+            }
+        }
+    }
+}
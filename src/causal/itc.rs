@@ -2,7 +2,9 @@ use super::Causal;
 use crate::{Apply, Crdt, DeltaSync, Replica};
 use alloc::borrow::Cow;
 use alloc::boxed::Box;
-use core::cmp;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::{cmp, fmt};
 
 #[cfg(feature = "proptest")]
 use proptest::prelude::*;
@@ -114,8 +116,91 @@ impl ItcReplica {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Returns an anonymous identity that owns none of the ID space.
+    ///
+    /// Unlike [`Replica::fork`], this leaves `self` untouched, so read-only
+    /// observers can be handed out without shrinking this replica's identity.
+    pub fn peek(&self) -> ItcId {
+        ItcId(IdTree::zero())
+    }
+
+    /// Splits this replica's whole identity into `n` disjoint replicas, e.g.
+    /// to bootstrap an `n`-node cluster from one seed.
+    ///
+    /// The split is balanced: each half of the identity goes to half of the
+    /// replicas, so every returned id sits about `log2(n)` levels deep and later
+    /// events stay cheap. Joining all of them recombines the original identity.
+    /// `self` is left anonymous, owning none of the ID space; with `n == 0`
+    /// nothing is handed out and `self` is unchanged.
+    pub fn fork_n(&mut self, n: usize) -> Vec<ItcReplica> {
+        let mut replicas = Vec::with_capacity(n);
+        if n > 0 {
+            let tree = core::mem::replace(&mut self.tree, IdTree::zero());
+            tree.split_into(n, &mut replicas);
+        }
+        replicas
+    }
+
+    /// Joins `other` into this replica, refusing if their identities overlap.
+    ///
+    /// Two replicas that own a common part of the ID space would record events
+    /// in the same place, so the clock could no longer tell their events apart.
+    /// That only happens when a cluster is misconfigured, e.g. a replica's id
+    /// was copied instead of forked. [`Replica::join`] assumes it never does;
+    /// this checks first, and leaves `self` unchanged on error.
+    ///
+    /// Both identities are normalized first, so trees from an unvalidated
+    /// source, such as a deserialized `tree` field, cannot make the join panic.
+    pub fn join_checked(&mut self, other: Self) -> Result<(), IdOverlap> {
+        let mine = self.tree.clone().norm();
+        let theirs = other.tree.norm();
+        if !disjoint(&mine, &theirs) {
+            return Err(IdOverlap);
+        }
+        self.tree = mine.sum(&theirs);
+        Ok(())
+    }
+}
+
+/// Returns true if the two id trees own no common part of the ID space.
+///
+/// Identities handed out by [`Replica::fork`] are always disjoint, and only
+/// disjoint identities can be joined.
+pub fn disjoint(a: &IdTree, b: &IdTree) -> bool {
+    match (a, b) {
+        (IdTree::Leaf { i: false }, _) | (_, IdTree::Leaf { i: false }) => true,
+        (IdTree::Leaf { i: true }, IdTree::Leaf { i: true }) => false,
+        (IdTree::Leaf { i: true }, IdTree::Node { left, right })
+        | (IdTree::Node { left, right }, IdTree::Leaf { i: true }) => {
+            disjoint(&IdTree::one(), left) && disjoint(&IdTree::one(), right)
+        }
+        (
+            IdTree::Node {
+                left: l1,
+                right: r1,
+            },
+            IdTree::Node {
+                left: l2,
+                right: r2,
+            },
+        ) => disjoint(l1, l2) && disjoint(r1, r2),
+    }
+}
+
+/// Error returned by [`ItcReplica::join_checked`] when the two identities
+/// overlap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdOverlap;
+
+impl fmt::Display for IdOverlap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ITC identities overlap")
+    }
 }
 
+impl core::error::Error for IdOverlap {}
+
 impl Replica for ItcReplica {
     type Id = ItcId;
 
@@ -188,6 +273,26 @@ impl Crdt for ItcClock {
     fn value(&self) -> Self::Value {
         self.tree.clone()
     }
+
+    fn value_ref(&self) -> Option<&Self::Value> {
+        Some(&self.tree)
+    }
+
+    fn leq(&self, other: &Self) -> bool {
+        self.tree.leq(&other.tree)
+    }
+}
+
+impl ItcClock {
+    /// Returns true if this clock is strictly causally before the other.
+    pub fn happened_before(&self, other: &Self) -> bool {
+        self.tree.leq(&other.tree) && !other.tree.leq(&self.tree)
+    }
+
+    /// Returns true if this clock is concurrent to the other.
+    pub fn concurrent(&self, other: &Self) -> bool {
+        !self.tree.leq(&other.tree) && !other.tree.leq(&self.tree)
+    }
 }
 
 impl Apply for ItcClock {
@@ -195,31 +300,121 @@ impl Apply for ItcClock {
     type Context = ItcId; // Requires the Identity to fill/grow
 
     fn apply(&mut self, _op: Self::Op, ctx: Self::Context) {
-        let id = ctx.0;
+        self.tree = self.tree.event(&ctx.0);
+    }
+}
 
-        // 1. Fill
-        let filled_cow = self.tree.fill(&id);
+/// A complete ITC stamp: an identity paired with the events it has seen.
+///
+/// This is the canonical ITC interface from the paper. Stamps are created by
+/// `fork`ing an existing stamp, record local events with `event`, and are
+/// retired by `join`ing them back into another stamp. [`ItcReplica`] and
+/// [`ItcClock`] are the two halves of a stamp, for use with the [`Replica`]
+/// and [`Apply`] traits.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItcStamp {
+    pub id: IdTree,
+    pub event: EventTree,
+}
 
-        // 2. Check if fill changed anything
-        if filled_cow.as_ref() != &self.tree {
-            self.tree = filled_cow.into_owned();
-        } else {
-            // 3. Grow if fill didn't change anything
-            let (grown_tree, _) = self.tree.grow(&id);
-            self.tree = grown_tree;
+impl Default for ItcStamp {
+    fn default() -> Self {
+        ItcStamp {
+            id: IdTree::one(),
+            event: EventTree::zero(),
+        }
+    }
+}
+
+impl ItcStamp {
+    /// Creates the "Seed" stamp, which owns the entire ID space and has seen no events.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a stamp from a replica's identity and its clock.
+    pub fn from_parts(replica: ItcReplica, clock: ItcClock) -> Self {
+        ItcStamp {
+            id: replica.tree,
+            event: clock.tree,
         }
     }
+
+    /// Splits the stamp back into its identity and clock halves.
+    pub fn into_parts(self) -> (ItcReplica, ItcClock) {
+        (ItcReplica { tree: self.id }, ItcClock { tree: self.event })
+    }
+
+    /// Splits the identity in two. Both stamps keep the full event history.
+    pub fn fork(&self) -> (ItcStamp, ItcStamp) {
+        let (left, right) = self.id.split();
+        (
+            ItcStamp {
+                id: left,
+                event: self.event.clone(),
+            },
+            ItcStamp {
+                id: right,
+                event: self.event.clone(),
+            },
+        )
+    }
+
+    /// Returns an anonymous copy of this stamp: the same events, but no identity.
+    ///
+    /// Joining a peeked stamp back into another leaves that stamp's identity unchanged.
+    pub fn peek(&self) -> ItcStamp {
+        ItcStamp {
+            id: IdTree::zero(),
+            event: self.event.clone(),
+        }
+    }
+
+    /// Records a new local event, filling the event tree where the identity
+    /// allows and growing it otherwise.
+    ///
+    /// # Panics
+    /// Panics if the stamp is anonymous (see [`ItcStamp::peek`]), since it has
+    /// no part of the ID space in which to record the event.
+    pub fn event(&mut self) {
+        assert!(
+            self.id != IdTree::zero(),
+            "anonymous ITC stamps cannot record events"
+        );
+        self.event = self.event.event(&self.id);
+    }
+
+    /// Merges two stamps, summing their identities and joining their events.
+    pub fn join(self, other: ItcStamp) -> ItcStamp {
+        ItcStamp {
+            id: self.id.sum(&other.id),
+            event: self.event.join(&other.event),
+        }
+    }
+
+    /// Returns true if every event seen by `self` has also been seen by `other`.
+    pub fn leq(&self, other: &ItcStamp) -> bool {
+        self.event.leq(&other.event)
+    }
 }
 
 // --- IMPLEMENTATION LOGIC ---
 
-trait Min<T> {
+/// The smallest event count anywhere in a tree.
+pub trait Min<T> {
     fn min(&self) -> T;
 }
-trait Max<T> {
+/// The largest event count anywhere in a tree.
+pub trait Max<T> {
     fn max(&self) -> T;
 }
-trait Normalisable {
+/// Rewrites a tree into its canonical form, which every operation here returns.
+///
+/// An id tree collapses `(0, 0)` and `(1, 1)` into a leaf. An event tree
+/// collapses nodes whose children are equal leaves and lifts the smaller
+/// child's base into the parent, so equal clocks compare equal.
+pub trait Normalisable {
     fn norm(self) -> Self;
 }
 
@@ -379,6 +574,56 @@ impl EventTree {
         }
     }
 
+    /// Returns true if every event recorded in `self` is also recorded in `other`.
+    ///
+    /// Nodes are compared against leaves by lifting their children, the same way
+    /// `join` aligns trees of different shapes.
+    pub fn leq(&self, other: &EventTree) -> bool {
+        match (self, other) {
+            (EventTree::Leaf { n: n1 }, EventTree::Leaf { n: n2 })
+            | (EventTree::Leaf { n: n1 }, EventTree::Node { n: n2, .. }) => n1 <= n2,
+            (
+                EventTree::Node {
+                    n: n1,
+                    left: left1,
+                    right: right1,
+                },
+                EventTree::Leaf { n: n2 },
+            ) => {
+                n1 <= n2
+                    && left1.clone().lift(*n1).leq(other)
+                    && right1.clone().lift(*n1).leq(other)
+            }
+            (
+                EventTree::Node {
+                    n: n1,
+                    left: left1,
+                    right: right1,
+                },
+                EventTree::Node {
+                    n: n2,
+                    left: left2,
+                    right: right2,
+                },
+            ) => {
+                n1 <= n2
+                    && left1.clone().lift(*n1).leq(&left2.clone().lift(*n2))
+                    && right1.clone().lift(*n1).leq(&right2.clone().lift(*n2))
+            }
+        }
+    }
+
+    /// Records an event for `id`: fill if that increases the tree, grow otherwise.
+    fn event(&self, id: &IdTree) -> EventTree {
+        let filled = self.fill(id);
+        if filled.as_ref() != self {
+            filled.into_owned()
+        } else {
+            let (grown, _) = self.grow(id);
+            grown
+        }
+    }
+
     fn join(&self, other: &EventTree) -> EventTree {
         match (self, other) {
             (EventTree::Leaf { n: n1 }, EventTree::Leaf { n: n2 }) => {
@@ -565,6 +810,18 @@ impl IdTree {
         }
     }
 
+    /// Splits this tree into `n` disjoint parts, pushed onto `out` as replicas,
+    /// giving each half of the tree to half of the parts.
+    fn split_into(self, n: usize, out: &mut Vec<ItcReplica>) {
+        if n == 1 {
+            out.push(ItcReplica { tree: self });
+            return;
+        }
+        let (left, right) = self.split();
+        left.split_into(n.div_ceil(2), out);
+        right.split_into(n / 2, out);
+    }
+
     fn sum(&self, other: &Self) -> Self {
         if *self == IdTree::zero() {
             return other.clone();
@@ -593,15 +850,421 @@ impl IdTree {
     }
 }
 
+// --- BINARY ENCODING ---
+//
+// The bit-packed encoding from the ITC paper (Almeida, Baquero & Fonte, 2008).
+// Bits are written most-significant first and the final byte is zero-padded.
+
+/// Error returned when decoding a malformed ITC tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The input ended in the middle of a tree.
+    UnexpectedEnd,
+    /// Whole bytes were left over after the tree was decoded.
+    TrailingBytes,
+    /// An event counter does not fit in a `u32`.
+    Overflow,
+    /// The tree is well formed but not normalized, so it is not the encoding
+    /// [`IdTree::encode`] or [`EventTree::encode`] would produce.
+    NotNormalized,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::UnexpectedEnd => write!(f, "unexpected end of ITC encoding"),
+            DecodeError::TrailingBytes => write!(f, "trailing bytes after ITC encoding"),
+            DecodeError::Overflow => write!(f, "ITC event counter overflows u32"),
+            DecodeError::NotNormalized => write!(f, "ITC tree is not normalized"),
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    bits: usize,
+}
+
+impl BitWriter {
+    /// Appends the low `width` bits of `value`.
+    fn push(&mut self, value: u64, width: u32) {
+        for i in (0..width).rev() {
+            if self.bits % 8 == 0 {
+                self.bytes.push(0);
+            }
+            if (value >> i) & 1 == 1 {
+                let last = self.bytes.len() - 1;
+                self.bytes[last] |= 0x80 >> (self.bits % 8);
+            }
+            self.bits += 1;
+        }
+    }
+
+    /// Appends `n` as a prefix-coded integer: each leading one bit moves to the
+    /// next, one-bit-wider range, starting from ranges of 2 bits.
+    fn push_number(&mut self, n: u32) {
+        let mut n = u64::from(n);
+        let mut width = 2;
+        while n >= 1 << width {
+            self.push(1, 1);
+            n -= 1 << width;
+            width += 1;
+        }
+        self.push(0, 1);
+        self.push(n, width);
+    }
+}
+
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        BitReader { bytes, pos: 0 }
+    }
+
+    fn read(&mut self, width: u32) -> Result<u64, DecodeError> {
+        let mut value = 0;
+        for _ in 0..width {
+            let byte = self
+                .bytes
+                .get(self.pos / 8)
+                .ok_or(DecodeError::UnexpectedEnd)?;
+            let bit = (byte >> (7 - self.pos % 8)) & 1;
+            value = (value << 1) | u64::from(bit);
+            self.pos += 1;
+        }
+        Ok(value)
+    }
+
+    fn read_number(&mut self) -> Result<u32, DecodeError> {
+        let mut base = 0u64;
+        let mut width = 2;
+        while self.read(1)? == 1 {
+            base += 1 << width;
+            width += 1;
+            if width > 32 {
+                return Err(DecodeError::Overflow);
+            }
+        }
+        let n = base + self.read(width)?;
+        u32::try_from(n).map_err(|_| DecodeError::Overflow)
+    }
+
+    /// Checks that only padding bits remain.
+    fn finish(self) -> Result<(), DecodeError> {
+        if self.pos.div_ceil(8) == self.bytes.len() {
+            Ok(())
+        } else {
+            Err(DecodeError::TrailingBytes)
+        }
+    }
+}
+
+impl IdTree {
+    /// Encodes the tree in the compact binary format from the ITC paper.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut writer = BitWriter::default();
+        self.encode_into(&mut writer);
+        writer.bytes
+    }
+
+    /// Decodes a tree produced by [`IdTree::encode`].
+    ///
+    /// Every operation assumes normalized trees, so a tree that is not
+    /// normalized is rejected rather than returned.
+    pub fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = BitReader::new(bytes);
+        let tree = Self::decode_from(&mut reader)?;
+        reader.finish()?;
+        if !tree.is_normalized() {
+            return Err(DecodeError::NotNormalized);
+        }
+        Ok(tree)
+    }
+
+    /// Returns true if no node has two equal leaves as children.
+    fn is_normalized(&self) -> bool {
+        match self {
+            IdTree::Leaf { .. } => true,
+            IdTree::Node { left, right } => {
+                !matches!((&**left, &**right), (IdTree::Leaf { i: a }, IdTree::Leaf { i: b }) if a == b)
+                    && left.is_normalized()
+                    && right.is_normalized()
+            }
+        }
+    }
+
+    fn encode_into(&self, w: &mut BitWriter) {
+        match self {
+            IdTree::Leaf { i } => {
+                w.push(0, 2);
+                w.push(u64::from(*i), 1);
+            }
+            IdTree::Node { left, right } => {
+                if **left == IdTree::zero() {
+                    w.push(1, 2);
+                    right.encode_into(w);
+                } else if **right == IdTree::zero() {
+                    w.push(2, 2);
+                    left.encode_into(w);
+                } else {
+                    w.push(3, 2);
+                    left.encode_into(w);
+                    right.encode_into(w);
+                }
+            }
+        }
+    }
+
+    fn decode_from(r: &mut BitReader) -> Result<Self, DecodeError> {
+        let tree = match r.read(2)? {
+            0 => IdTree::Leaf { i: r.read(1)? == 1 },
+            1 => IdTree::node(Box::new(IdTree::zero()), Box::new(Self::decode_from(r)?)),
+            2 => IdTree::node(Box::new(Self::decode_from(r)?), Box::new(IdTree::zero())),
+            _ => {
+                let left = Self::decode_from(r)?;
+                let right = Self::decode_from(r)?;
+                IdTree::node(Box::new(left), Box::new(right))
+            }
+        };
+        Ok(tree)
+    }
+}
+
+impl EventTree {
+    /// Encodes the tree in the compact binary format from the ITC paper.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut writer = BitWriter::default();
+        self.encode_into(&mut writer);
+        writer.bytes
+    }
+
+    /// Decodes a tree produced by [`EventTree::encode`].
+    ///
+    /// Every operation assumes normalized trees, so a tree that is not
+    /// normalized is rejected rather than returned, as is one whose largest
+    /// counter, summed along its path, does not fit in a `u32`.
+    pub fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = BitReader::new(bytes);
+        let tree = Self::decode_from(&mut reader)?;
+        reader.finish()?;
+        if !tree.is_normalized() {
+            return Err(DecodeError::NotNormalized);
+        }
+        tree.checked_max().ok_or(DecodeError::Overflow)?;
+        Ok(tree)
+    }
+
+    /// Returns true if no node has two equal leaves as children, and one child
+    /// of every node has a base of zero.
+    fn is_normalized(&self) -> bool {
+        match self {
+            EventTree::Leaf { .. } => true,
+            EventTree::Node { left, right, .. } => {
+                !matches!((&**left, &**right), (EventTree::Leaf { n: a }, EventTree::Leaf { n: b }) if a == b)
+                    && cmp::min(left.n(), right.n()) == 0
+                    && left.is_normalized()
+                    && right.is_normalized()
+            }
+        }
+    }
+
+    /// Like [`Max::max`], but `None` instead of overflowing.
+    fn checked_max(&self) -> Option<u32> {
+        match self {
+            EventTree::Leaf { n } => Some(*n),
+            EventTree::Node { n, left, right } => {
+                n.checked_add(cmp::max(left.checked_max()?, right.checked_max()?))
+            }
+        }
+    }
+
+    fn encode_into(&self, w: &mut BitWriter) {
+        match self {
+            EventTree::Leaf { n } => {
+                w.push(1, 1);
+                w.push_number(*n);
+            }
+            EventTree::Node { n, left, right } => {
+                let left_zero = **left == EventTree::zero();
+                let right_zero = **right == EventTree::zero();
+                w.push(0, 1);
+                if *n == 0 {
+                    if left_zero {
+                        w.push(0, 2);
+                        right.encode_into(w);
+                    } else if right_zero {
+                        w.push(1, 2);
+                        left.encode_into(w);
+                    } else {
+                        w.push(2, 2);
+                        left.encode_into(w);
+                        right.encode_into(w);
+                    }
+                } else {
+                    w.push(3, 2);
+                    if left_zero {
+                        w.push(0, 2);
+                        w.push_number(*n);
+                        right.encode_into(w);
+                    } else if right_zero {
+                        w.push(1, 2);
+                        w.push_number(*n);
+                        left.encode_into(w);
+                    } else {
+                        w.push(1, 1);
+                        w.push_number(*n);
+                        left.encode_into(w);
+                        right.encode_into(w);
+                    }
+                }
+            }
+        }
+    }
+
+    fn decode_from(r: &mut BitReader) -> Result<Self, DecodeError> {
+        if r.read(1)? == 1 {
+            return Ok(EventTree::leaf(r.read_number()?));
+        }
+        let zero = || Box::new(EventTree::zero());
+        let tree = match r.read(2)? {
+            0 => EventTree::node(0, zero(), Box::new(Self::decode_from(r)?)),
+            1 => EventTree::node(0, Box::new(Self::decode_from(r)?), zero()),
+            2 => {
+                let left = Self::decode_from(r)?;
+                let right = Self::decode_from(r)?;
+                EventTree::node(0, Box::new(left), Box::new(right))
+            }
+            _ => {
+                if r.read(1)? == 0 {
+                    let right_only = r.read(1)? == 0;
+                    let n = r.read_number()?;
+                    let child = Box::new(Self::decode_from(r)?);
+                    if right_only {
+                        EventTree::node(n, zero(), child)
+                    } else {
+                        EventTree::node(n, child, zero())
+                    }
+                } else {
+                    let n = r.read_number()?;
+                    let left = Self::decode_from(r)?;
+                    let right = Self::decode_from(r)?;
+                    EventTree::node(n, Box::new(left), Box::new(right))
+                }
+            }
+        };
+        Ok(tree)
+    }
+}
+
+// --- TEXT RENDERING ---
+
+impl IdTree {
+    /// Renders the tree in the notation of the ITC paper, e.g. `(1, (0, 1))`.
+    ///
+    /// Leaves are `0` or `1` and nodes are `(left, right)`.
+    pub fn to_ascii(&self) -> String {
+        let mut out = String::new();
+        self.write_ascii(&mut out);
+        out
+    }
+
+    fn write_ascii(&self, out: &mut String) {
+        match self {
+            IdTree::Leaf { i } => out.push(if *i { '1' } else { '0' }),
+            IdTree::Node { left, right } => {
+                out.push('(');
+                left.write_ascii(out);
+                out.push_str(", ");
+                right.write_ascii(out);
+                out.push(')');
+            }
+        }
+    }
+}
+
+impl EventTree {
+    /// Renders the tree in the notation of the ITC paper, e.g. `(2, 0, (1, 0, 1))`.
+    ///
+    /// Leaves are their counter and nodes are `(n, left, right)`, where `n` is
+    /// the base counter shared by both subtrees.
+    pub fn to_ascii(&self) -> String {
+        let mut out = String::new();
+        self.write_ascii(&mut out);
+        out
+    }
+
+    fn write_ascii(&self, out: &mut String) {
+        match self {
+            EventTree::Leaf { n } => out.push_str(&n.to_string()),
+            EventTree::Node { n, left, right } => {
+                out.push('(');
+                out.push_str(&n.to_string());
+                out.push_str(", ");
+                left.write_ascii(out);
+                out.push_str(", ");
+                right.write_ascii(out);
+                out.push(')');
+            }
+        }
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for IdTree {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        // Up to four levels deep, normalized so that equal ids compare equal.
+        any::<bool>()
+            .prop_map(|i| IdTree::Leaf { i })
+            .prop_recursive(4, 16, 2, |inner| {
+                (inner.clone(), inner)
+                    .prop_map(|(left, right)| IdTree::node(Box::new(left), Box::new(right)))
+            })
+            .prop_map(Normalisable::norm)
+            .boxed()
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for EventTree {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        // Up to four levels deep with small counters, normalized like every
+        // tree `join` and `event` produce.
+        (0u32..8)
+            .prop_map(EventTree::leaf)
+            .prop_recursive(4, 16, 2, |inner| {
+                (0u32..4, inner.clone(), inner).prop_map(|(n, left, right)| {
+                    EventTree::node(n, Box::new(left), Box::new(right))
+                })
+            })
+            .prop_map(Normalisable::norm)
+            .boxed()
+    }
+}
+
 #[cfg(feature = "proptest")]
 impl Arbitrary for ItcClock {
     type Parameters = ();
     type Strategy = BoxedStrategy<Self>;
 
     fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
-        // Generate clocks by simulating fork/tick sequences on 1-3 replicas.
-        // Each replica does 0-4 ticks, then we merge a random subset.
-        (1usize..=3, proptest::collection::vec(0u8..5, 1..=3))
+        // Half the clocks come from simulating fork/tick sequences on 1-3
+        // replicas: each replica does 0-4 ticks, then we merge a random subset.
+        // The other half are arbitrary normalized event trees, which reach
+        // shapes the simulation does not.
+        let simulated = (1usize..=3, proptest::collection::vec(0u8..5, 1..=3))
             .prop_flat_map(|(num_replicas, tick_counts)| {
                 let tick_counts: Vec<u8> = tick_counts.into_iter().take(num_replicas).collect();
                 Just(tick_counts)
@@ -637,7 +1300,11 @@ impl Arbitrary for ItcClock {
                     result.merge(c);
                 }
                 result
-            })
-            .boxed()
+            });
+        prop_oneof![
+            simulated,
+            any::<EventTree>().prop_map(|tree| ItcClock { tree }),
+        ]
+        .boxed()
     }
 }
@@ -0,0 +1,145 @@
+//! Property checks for the normalization of ITC trees.
+//!
+//! Every operation in [`itc`](super::itc) assumes its inputs are normalized
+//! and returns normalized trees, so that equal clocks and ids compare equal. A
+//! normalization that changed what a tree means would silently corrupt every
+//! later merge, so these checks run `norm` on arbitrary, not yet normalized
+//! trees and compare the meaning before and after.
+
+use super::itc::{EventTree, IdTree, Max, Min, Normalisable};
+use crate::crdt::checks::{create_seeded_runner, handle_test_result, random_seed};
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+use proptest::prelude::*;
+use proptest::test_runner::{Config, TestCaseError};
+
+/// Generates id trees up to six levels deep, normalized or not.
+pub fn raw_id_tree() -> impl Strategy<Value = IdTree> {
+    any::<bool>()
+        .prop_map(|i| IdTree::Leaf { i })
+        .prop_recursive(6, 64, 2, |inner| {
+            (inner.clone(), inner)
+                .prop_map(|(left, right)| IdTree::node(Box::new(left), Box::new(right)))
+        })
+}
+
+/// Generates event trees up to six levels deep with small counters,
+/// normalized or not.
+pub fn raw_event_tree() -> impl Strategy<Value = EventTree> {
+    (0u32..8)
+        .prop_map(EventTree::leaf)
+        .prop_recursive(6, 64, 2, |inner| {
+            (0u32..8, inner.clone(), inner)
+                .prop_map(|(n, left, right)| EventTree::node(n, Box::new(left), Box::new(right)))
+        })
+}
+
+/// Checks that normalizing an id tree twice is the same as normalizing it once.
+pub fn check_id_norm_idempotent() {
+    let seed = random_seed();
+    let mut runner = create_seeded_runner(Config::default(), seed);
+    let result = runner.run(&raw_id_tree(), |tree| {
+        let once = tree.norm();
+        let twice = once.clone().norm();
+        if once != twice {
+            return Err(TestCaseError::fail(format!(
+                "Id norm idempotence failed: norm(x) = {} but norm(norm(x)) = {}",
+                once.to_ascii(),
+                twice.to_ascii()
+            )));
+        }
+        Ok(())
+    });
+    handle_test_result(result, "x", seed);
+}
+
+/// Checks that normalizing an event tree twice is the same as normalizing it once.
+pub fn check_event_norm_idempotent() {
+    let seed = random_seed();
+    let mut runner = create_seeded_runner(Config::default(), seed);
+    let result = runner.run(&raw_event_tree(), |tree| {
+        let once = tree.norm();
+        let twice = once.clone().norm();
+        if once != twice {
+            return Err(TestCaseError::fail(format!(
+                "Event norm idempotence failed: norm(x) = {} but norm(norm(x)) = {}",
+                once.to_ascii(),
+                twice.to_ascii()
+            )));
+        }
+        Ok(())
+    });
+    handle_test_result(result, "x", seed);
+}
+
+/// Checks that normalizing an id tree never changes which part of the
+/// interval it owns.
+pub fn check_id_norm_preserves_coverage() {
+    let seed = random_seed();
+    let mut runner = create_seeded_runner(Config::default(), seed);
+    let result = runner.run(&raw_id_tree(), |tree| {
+        let depth = id_depth(&tree);
+        let normalized = tree.clone().norm();
+        if id_coverage(&tree, depth) != id_coverage(&normalized, depth) {
+            return Err(TestCaseError::fail(format!(
+                "Id norm coverage failed: {} normalized to {}",
+                tree.to_ascii(),
+                normalized.to_ascii()
+            )));
+        }
+        Ok(())
+    });
+    handle_test_result(result, "x", seed);
+}
+
+/// Checks that normalizing an event tree never changes its smallest or
+/// largest event count.
+pub fn check_event_norm_preserves_bounds() {
+    let seed = random_seed();
+    let mut runner = create_seeded_runner(Config::default(), seed);
+    let result = runner.run(&raw_event_tree(), |tree| {
+        let normalized = tree.clone().norm();
+        let before = (tree.min(), tree.max());
+        let after = (normalized.min(), normalized.max());
+        if before != after {
+            return Err(TestCaseError::fail(format!(
+                "Event norm bounds failed: {} has (min, max) = {before:?} but its \
+                normalization {} has {after:?}",
+                tree.to_ascii(),
+                normalized.to_ascii()
+            )));
+        }
+        Ok(())
+    });
+    handle_test_result(result, "x", seed);
+}
+
+/// Runs every normalization check.
+pub fn check_norm_properties() {
+    check_id_norm_idempotent();
+    check_event_norm_idempotent();
+    check_id_norm_preserves_coverage();
+    check_event_norm_preserves_bounds();
+}
+
+fn id_depth(tree: &IdTree) -> u32 {
+    match tree {
+        IdTree::Leaf { .. } => 0,
+        IdTree::Node { left, right } => 1 + id_depth(left).max(id_depth(right)),
+    }
+}
+
+/// Splits the interval into `2^depth` equal slots and returns whether the tree
+/// owns each one. `depth` must be at least the depth of the tree.
+fn id_coverage(tree: &IdTree, depth: u32) -> Vec<bool> {
+    match tree {
+        IdTree::Leaf { i } => vec![*i; 1 << depth],
+        IdTree::Node { left, right } => {
+            let mut slots = id_coverage(left, depth - 1);
+            slots.extend(id_coverage(right, depth - 1));
+            slots
+        }
+    }
+}
@@ -1,5 +1,9 @@
+pub mod buffer;
+pub mod context;
 pub mod itc;
-#[cfg(feature = "std")]
+#[cfg(feature = "proptest")]
+pub mod itc_props;
+pub mod oplog;
 pub mod vector;
 
 use crate::DeltaSync;
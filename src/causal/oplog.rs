@@ -0,0 +1,71 @@
+use super::vector::VectorClock;
+use crate::Crdt;
+use crate::collections::Key;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// A log of operations, each tagged with the vector clock of its origin.
+///
+/// Op-based deployments keep ops around until every replica has received them.
+/// Once an op's clock is at or below the causal stability frontier (see
+/// [`stable_frontier`](super::vector::stable_frontier)), it will never be needed
+/// again and [`OpLog::gc`] can drop it.
+///
+/// # Type Parameters
+/// * `I`: The type of the Replica ID. Must be `Hash`, `Eq`, `Clone`, and `Debug`.
+/// * `Op`: The type of the logged operations.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpLog<I, Op>
+where
+    I: Key + Clone,
+{
+    /// Ops in the order they were appended, with the clock they were issued at.
+    entries: Vec<(VectorClock<I>, Op)>,
+}
+
+impl<I: Key + Clone, Op> Default for OpLog<I, Op> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<I, Op> OpLog<I, Op>
+where
+    I: Key + Clone + Debug,
+{
+    /// Creates a new, empty op log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends an op issued at the given clock.
+    pub fn push(&mut self, clock: VectorClock<I>, op: Op) {
+        self.entries.push((clock, op));
+    }
+
+    /// Drops every op whose clock is dominated by the `stable` frontier, i.e.
+    /// every op that all replicas are known to have seen.
+    ///
+    /// Ops concurrent with or after the frontier are kept, in order.
+    pub fn gc(&mut self, stable: &VectorClock<I>) {
+        self.entries.retain(|(clock, _)| !clock.leq(stable));
+    }
+
+    /// Iterates over the retained ops and their clocks, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = (&VectorClock<I>, &Op)> {
+        self.entries.iter().map(|(clock, op)| (clock, op))
+    }
+
+    /// Returns the number of retained ops.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if no ops are retained.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
@@ -1,8 +1,15 @@
 use super::Causal;
-use crate::{Apply, Crdt, DeltaSync};
-use std::cmp::Ordering;
-use std::collections::HashMap;
-use std::hash::Hash;
+use crate::collections::{CapacityHint, Key, Map};
+use crate::crdt::{summarize_entries, write_sorted_entries};
+use crate::state_hash::hash_sorted;
+use crate::{Apply, Crdt, CrdtDebug, DeltaSync, MergeReport, TraceEntries};
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::Hash;
 
 #[cfg(feature = "proptest")]
 use proptest::prelude::*;
@@ -18,24 +25,22 @@ use proptest::prelude::*;
 #[derive(Debug, Clone)]
 pub struct VectorClock<I>
 where
-    I: Hash + Eq,
+    I: Key,
 {
-    clocks: HashMap<I, u64>,
+    clocks: Map<I, u64>,
 }
 
-impl<I: Hash + Eq> Default for VectorClock<I> {
+impl<I: Key> Default for VectorClock<I> {
     fn default() -> Self {
-        Self {
-            clocks: HashMap::new(),
-        }
+        Self { clocks: Map::new() }
     }
 }
 
-impl<I: Hash + Eq + Clone> PartialEq for VectorClock<I> {
+impl<I: Key + Clone> PartialEq for VectorClock<I> {
     fn eq(&self, other: &Self) -> bool {
         // Two vector clocks are equal if they have the same entries.
         // Missing entries are treated as 0.
-        // However, HashMap::eq only checks strictly.
+        // However, Map::eq only checks strictly.
         // We should normalize or check carefully.
         // For simplicity in a CRDT context where we assume strictly growing counters,
         // we usually just delegate to HashMap eq if we assume 0s are pruned or explicit.
@@ -51,29 +56,85 @@ impl<I: Hash + Eq + Clone> PartialEq for VectorClock<I> {
     }
 }
 
-impl<I: Hash + Eq + Clone> Eq for VectorClock<I> {}
+impl<I: Key + Clone> Eq for VectorClock<I> {}
 
 impl<I> Crdt for VectorClock<I>
 where
-    I: Hash + Eq + Clone + std::fmt::Debug,
+    I: Key + Clone + core::fmt::Debug,
 {
-    type Value = HashMap<I, u64>;
+    type Value = Map<I, u64>;
 
     fn merge(&mut self, other: &Self) {
-        for (replica, &count) in &other.clocks {
-            let entry = self.clocks.entry(replica.clone()).or_insert(0);
-            *entry = (*entry).max(count);
-        }
+        self.merge_report(other);
     }
 
     fn value(&self) -> Self::Value {
         self.clocks.clone()
     }
+
+    fn value_ref(&self) -> Option<&Self::Value> {
+        Some(&self.clocks)
+    }
+
+    fn leq(&self, other: &Self) -> bool {
+        matches!(
+            self.partial_cmp(other),
+            Some(Ordering::Less | Ordering::Equal)
+        )
+    }
+
+    fn debug_summary(&self) -> String {
+        self.summarize()
+    }
+}
+
+impl<I> CrdtDebug for VectorClock<I>
+where
+    I: Key + core::fmt::Debug,
+{
+    fn summarize(&self) -> String {
+        summarize_entries(
+            "VectorClock",
+            self.clocks
+                .iter()
+                .map(|(replica, time)| format!("{replica:?}: {time}")),
+        )
+    }
+}
+
+/// Formats as `VectorClock{a:2, b:5}`, sorted by replica and skipping zero
+/// entries, so clocks that are `==` print the same.
+impl<I> fmt::Display for VectorClock<I>
+where
+    I: Key + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_sorted_entries(
+            f,
+            "VectorClock",
+            self.clocks
+                .iter()
+                .filter(|(_, time)| **time != 0)
+                .map(|(replica, time)| format!("{replica}:{time}")),
+        )
+    }
+}
+
+impl<I> TraceEntries for VectorClock<I>
+where
+    I: Key + core::fmt::Debug,
+{
+    fn trace_entries(&self) -> BTreeMap<String, String> {
+        self.clocks
+            .iter()
+            .map(|(replica, time)| (format!("{replica:?}"), format!("{time}")))
+            .collect()
+    }
 }
 
 impl<I> Apply for VectorClock<I>
 where
-    I: Hash + Eq + Clone + std::fmt::Debug,
+    I: Key + Clone + core::fmt::Debug,
 {
     type Op = (); // A tick is just an event
     type Context = I; // Who is ticking?
@@ -85,16 +146,47 @@ where
 
 impl<I> VectorClock<I>
 where
-    I: Hash + Eq + Clone,
+    I: Key + Clone,
 {
     /// Creates a new, empty Vector Clock.
     pub fn new() -> Self {
         Self::default()
     }
 
-    /// Increments the clock for the given replica.
-    pub fn inc(&mut self, replica: I) {
-        *self.clocks.entry(replica).or_insert(0) += 1;
+    /// Creates a new, empty Vector Clock with room for `capacity` replicas
+    /// before it has to grow.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            clocks: Map::with_capacity_hint(capacity),
+        }
+    }
+
+    /// Makes room for at least `additional` more replicas.
+    pub fn reserve(&mut self, additional: usize) {
+        self.clocks.reserve_hint(additional);
+    }
+
+    /// Increments the clock for the given replica and returns its new logical
+    /// time, ready to stamp the event being recorded.
+    pub fn inc(&mut self, replica: I) -> u64 {
+        let time = self.clocks.entry(replica).or_insert(0);
+        *time += 1;
+        *time
+    }
+
+    /// Merges another clock into this one, reporting how many replica entries
+    /// were raised.
+    pub fn merge_report(&mut self, other: &Self) -> MergeReport {
+        let mut updated = 0;
+        for (replica, &count) in &other.clocks {
+            let entry = self.clocks.entry(replica.clone()).or_insert(0);
+            if count > *entry {
+                *entry = count;
+                updated += 1;
+            }
+        }
+        self.prune();
+        MergeReport::from_updated(updated)
     }
 
     /// Returns the logical time for a specific replica.
@@ -102,6 +194,34 @@ where
         *self.clocks.get(replica).unwrap_or(&0)
     }
 
+    /// Returns the number of replicas with an entry in this clock.
+    pub fn len(&self) -> usize {
+        self.clocks.len()
+    }
+
+    /// Returns true if the clock has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.clocks.is_empty()
+    }
+
+    /// Returns true if the clock has an entry for the given replica.
+    pub fn knows(&self, replica: &I) -> bool {
+        self.clocks.contains_key(replica)
+    }
+
+    /// Iterates over the replicas with an entry in this clock.
+    pub fn replicas(&self) -> impl Iterator<Item = &I> {
+        self.clocks.keys()
+    }
+
+    /// Removes all entries whose count is 0.
+    ///
+    /// Missing entries already read as 0 through [`VectorClock::get`], so pruning
+    /// never changes how this clock compares to another.
+    pub fn prune(&mut self) {
+        self.clocks.retain(|_, count| *count != 0);
+    }
+
     /// Returns true if this vector clock is strictly causally before the other.
     pub fn happened_before(&self, other: &Self) -> bool {
         self.partial_cmp(other) == Some(Ordering::Less)
@@ -111,12 +231,223 @@ where
     pub fn concurrent(&self, other: &Self) -> bool {
         self.partial_cmp(other).is_none()
     }
+
+    /// Returns true if this vector clock has seen everything the other has
+    /// (`self >= other`). Equal clocks dominate each other.
+    pub fn dominates(&self, other: &Self) -> bool {
+        matches!(
+            self.partial_cmp(other),
+            Some(Ordering::Greater | Ordering::Equal)
+        )
+    }
+
+    /// Returns the entries where this clock is ahead of `remote`, at this
+    /// clock's count.
+    ///
+    /// Merging the result into `remote` brings it up to the join of the two
+    /// clocks, so this is what an anti-entropy round needs to send.
+    pub fn delta_since(&self, remote: &Self) -> VectorClock<I> {
+        let clocks = self
+            .clocks
+            .iter()
+            .filter(|(replica, count)| **count > remote.get(replica))
+            .map(|(replica, &count)| (replica.clone(), count))
+            .collect();
+        VectorClock { clocks }
+    }
+
+    /// Returns every replica whose count differs between the two clocks, mapped
+    /// to `(self_count, other_count)`. Missing entries count as 0.
+    pub fn diff(&self, other: &Self) -> Map<I, (u64, u64)> {
+        self.clocks
+            .keys()
+            .chain(other.clocks.keys())
+            .filter_map(|replica| {
+                let counts = (self.get(replica), other.get(replica));
+                (counts.0 != counts.1).then(|| (replica.clone(), counts))
+            })
+            .collect()
+    }
+}
+
+/// Returns the causal stability frontier of a known-membership cluster: the
+/// element-wise minimum of every replica's clock.
+///
+/// Each entry of the result counts the updates from that replica which every
+/// clock in `clocks` has seen, so anything at or below the frontier is known
+/// everywhere and can be pruned from an op log. A replica missing from any
+/// clock has a frontier of 0. With no clocks, the frontier is empty.
+pub fn stable_frontier<I>(clocks: &[VectorClock<I>]) -> VectorClock<I>
+where
+    I: Key + Clone,
+{
+    let Some((first, rest)) = clocks.split_first() else {
+        return VectorClock::new();
+    };
+    let clocks = first
+        .clocks
+        .iter()
+        .map(|(replica, &count)| {
+            let min = rest
+                .iter()
+                .map(|clock| clock.get(replica))
+                .fold(count, u64::min);
+            (replica.clone(), min)
+        })
+        .filter(|(_, count)| *count != 0)
+        .collect();
+    VectorClock { clocks }
+}
+
+impl<I: Key + Hash + Ord> VectorClock<I> {
+    /// Returns a hash of the clock's state that is stable across processes,
+    /// platforms and Rust versions.
+    ///
+    /// Zero entries are skipped, so clocks that are `==` always hash equal.
+    pub fn state_hash(&self) -> u64 {
+        hash_sorted(self.clocks.iter().filter(|(_, count)| **count != 0))
+    }
+}
+
+/// Error returned by [`VectorClock::decode_compact`] for malformed input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompactDecodeError {
+    /// The input ended in the middle of an entry.
+    UnexpectedEnd,
+    /// Bytes were left over after the last entry.
+    TrailingBytes,
+    /// A varint or replica ID does not fit in a `u64`.
+    Overflow,
+    /// A replica ID was repeated or a count was 0, which the encoder never writes.
+    NotCanonical,
+}
+
+impl fmt::Display for CompactDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompactDecodeError::UnexpectedEnd => {
+                write!(f, "unexpected end of vector clock encoding")
+            }
+            CompactDecodeError::TrailingBytes => {
+                write!(f, "trailing bytes after vector clock encoding")
+            }
+            CompactDecodeError::Overflow => write!(f, "vector clock varint overflows u64"),
+            CompactDecodeError::NotCanonical => write!(f, "vector clock encoding is not canonical"),
+        }
+    }
+}
+
+impl core::error::Error for CompactDecodeError {}
+
+/// Appends `value` as an unsigned LEB128 varint.
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Reads an unsigned LEB128 varint from the front of `bytes`.
+fn read_varint(bytes: &mut &[u8]) -> Result<u64, CompactDecodeError> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let (&byte, rest) = bytes
+            .split_first()
+            .ok_or(CompactDecodeError::UnexpectedEnd)?;
+        *bytes = rest;
+        let low = u64::from(byte & 0x7f);
+        if low << shift >> shift != low {
+            return Err(CompactDecodeError::Overflow);
+        }
+        value |= low << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(CompactDecodeError::Overflow)
+}
+
+impl VectorClock<u64> {
+    /// Encodes the clock compactly, for clusters with many replicas and small
+    /// counts.
+    ///
+    /// Entries are sorted by replica ID and written as varints: the number of
+    /// entries, then for each entry the gap from the previous replica ID and
+    /// the count. Dense IDs and small counts take one byte each. Zero entries
+    /// are left out, so clocks that are `==` encode identically.
+    pub fn encode_compact(&self) -> Vec<u8> {
+        let mut entries: Vec<(u64, u64)> = self
+            .clocks
+            .iter()
+            .filter(|(_, count)| **count != 0)
+            .map(|(&replica, &count)| (replica, count))
+            .collect();
+        entries.sort_unstable();
+
+        let mut out = Vec::new();
+        write_varint(&mut out, entries.len() as u64);
+        let mut previous = 0;
+        for (replica, count) in entries {
+            write_varint(&mut out, replica - previous);
+            write_varint(&mut out, count);
+            previous = replica;
+        }
+        out
+    }
+
+    /// Decodes a clock produced by [`VectorClock::encode_compact`].
+    pub fn decode_compact(mut bytes: &[u8]) -> Result<Self, CompactDecodeError> {
+        let len = read_varint(&mut bytes)?;
+        let mut clock = VectorClock::new();
+        let mut replica = 0u64;
+        for i in 0..len {
+            let gap = read_varint(&mut bytes)?;
+            if i > 0 && gap == 0 {
+                return Err(CompactDecodeError::NotCanonical);
+            }
+            replica = replica
+                .checked_add(gap)
+                .ok_or(CompactDecodeError::Overflow)?;
+            let count = read_varint(&mut bytes)?;
+            if count == 0 {
+                return Err(CompactDecodeError::NotCanonical);
+            }
+            clock.clocks.insert(replica, count);
+        }
+        if !bytes.is_empty() {
+            return Err(CompactDecodeError::TrailingBytes);
+        }
+        Ok(clock)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<I: Key + Ord + serde::Serialize> VectorClock<I> {
+    /// Serializes the clock as a JSON array of `[replica, time]` pairs sorted by
+    /// replica ID, so equal clocks always produce identical bytes (e.g. for
+    /// content addressing).
+    ///
+    /// Zero entries are left out, matching how `==` treats them. Pairs rather
+    /// than an object, so that IDs which are not strings, such as tuples, work.
+    ///
+    /// # Panics
+    /// Panics only if a replica ID's `Serialize` impl reports an error.
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        let mut sorted: Vec<(&I, &u64)> = self
+            .clocks
+            .iter()
+            .filter(|(_, count)| **count != 0)
+            .collect();
+        sorted.sort();
+        serde_json::to_vec(&sorted).expect("replica IDs must serialize as JSON")
+    }
 }
 
 // PartialOrd implementation for Causality
 impl<I> PartialOrd for VectorClock<I>
 where
-    I: Hash + Eq + Clone,
+    I: Key + Clone,
 {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         let mut self_is_bigger = false;
@@ -156,9 +487,9 @@ where
 
 impl<I> DeltaSync for VectorClock<I>
 where
-    I: Hash + Eq + Clone + std::fmt::Debug,
+    I: Key + Clone + core::fmt::Debug,
 {
-    type Summary = HashMap<I, u64>;
+    type Summary = Map<I, u64>;
     type Delta = Self;
 
     fn summary(&self) -> Self::Summary {
@@ -179,11 +510,15 @@ where
     fn merge_delta(&mut self, delta: &Self) {
         self.merge(delta);
     }
+
+    fn merge_diff(&self, other: &Self) -> Self {
+        other.delta_since(self)
+    }
 }
 
 impl<I> Causal for VectorClock<I>
 where
-    I: Hash + Eq + Clone + std::fmt::Debug,
+    I: Key + Clone + core::fmt::Debug,
 {
     type Dot = (I, u64);
 }
@@ -194,16 +529,6 @@ impl Arbitrary for VectorClock<String> {
     type Strategy = BoxedStrategy<Self>;
 
     fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
-        proptest::collection::hash_map("[a-c]".prop_map(String::from), 0u64..50, 0..4)
-            .prop_map(|clocks| {
-                let mut vc = VectorClock::new();
-                for (replica, count) in clocks {
-                    for _ in 0..count {
-                        vc.inc(replica.clone());
-                    }
-                }
-                vc
-            })
-            .boxed()
+        crate::arbitrary::vector_clock_strategy("[a-c]".prop_map(String::from), 0..4)
     }
 }
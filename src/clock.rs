@@ -0,0 +1,182 @@
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// A source of timestamps for last-writer-wins types.
+///
+/// [`LWWRegister::set_now`](crate::LWWRegister::set_now) and the `*_now`
+/// methods on [`LWWSet`](crate::LWWSet) read the timestamp from a clock instead
+/// of taking one from the caller.
+///
+/// # Choosing a clock
+/// Last-writer-wins is only as good as its timestamps. A wall clock
+/// ([`SystemClock`]) can run behind on one replica, so that replica's writes
+/// silently lose to older writes from a replica whose clock runs ahead, and a
+/// clock stepped backwards can even lose to its own earlier writes. A
+/// [`LamportClock`] that observes every timestamp it receives never goes
+/// backwards relative to anything it has seen, so a write always wins over the
+/// writes its replica had already merged. Prefer it unless timestamps must be
+/// meaningful as real time.
+///
+/// # Type Parameters
+/// * `TS`: The type of the timestamps produced. Must be `Ord` to be used by LWW types.
+pub trait Clock<TS> {
+    /// Returns the timestamp for a write happening now.
+    fn now(&self) -> TS;
+}
+
+/// A [`Clock`] reading the system wall clock, in milliseconds since the Unix epoch.
+///
+/// Subject to clock skew between replicas; see [`Clock`] for why a
+/// [`LamportClock`] is usually the safer choice.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock<u64> for SystemClock {
+    /// # Panics
+    /// Panics if the system clock is set before the Unix epoch.
+    fn now(&self) -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("system clock is set before the Unix epoch")
+            .as_millis() as u64
+    }
+}
+
+/// A logical [`Clock`] that advances by one on every read.
+///
+/// Call [`LamportClock::observe`] with the timestamps of remote writes as they
+/// are merged, so the next local write is ordered after everything this
+/// replica has seen. Two replicas can still hand out the same timestamp; the
+/// LWW types break such ties deterministically.
+#[cfg(target_has_atomic = "64")]
+#[derive(Debug, Default)]
+pub struct LamportClock {
+    /// The last timestamp handed out or observed.
+    time: AtomicU64,
+}
+
+#[cfg(target_has_atomic = "64")]
+impl LamportClock {
+    /// Creates a clock whose first read returns 1.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Advances the clock so that its next read is later than `ts`.
+    pub fn observe(&self, ts: u64) {
+        self.time.fetch_max(ts, Ordering::Relaxed);
+    }
+
+    /// Returns the last timestamp handed out or observed, without advancing.
+    pub fn current(&self) -> u64 {
+        self.time.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl Clock<u64> for LamportClock {
+    fn now(&self) -> u64 {
+        self.time.fetch_add(1, Ordering::Relaxed) + 1
+    }
+}
+
+/// A timestamp from a [`Hlc`]: wall-clock milliseconds plus a logical counter.
+///
+/// Ordered by physical time, then by the logical counter, so it can be used
+/// directly as the timestamp of an [`LWWRegister`](crate::LWWRegister) or
+/// [`LWWSet`](crate::LWWSet).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct HlcTimestamp {
+    /// The largest physical time seen, in milliseconds since the Unix epoch.
+    pub physical: u64,
+    /// Orders events that share the same physical time.
+    pub logical: u32,
+}
+
+impl HlcTimestamp {
+    /// Returns the smallest timestamp after this one: the next logical tick, or
+    /// the next millisecond once the logical counter is exhausted.
+    fn successor(self) -> Self {
+        match self.logical.checked_add(1) {
+            Some(logical) => Self {
+                physical: self.physical,
+                logical,
+            },
+            None => Self {
+                physical: self.physical.saturating_add(1),
+                logical: 0,
+            },
+        }
+    }
+}
+
+/// A Hybrid Logical Clock (HLC).
+///
+/// Combines a physical clock with a logical counter. Its timestamps stay close
+/// to wall-clock time, but like a [`LamportClock`] they never go backwards and
+/// always order a write after every remote timestamp passed to
+/// [`Hlc::update`], even when the physical clock is skewed or stepped back.
+///
+/// # Type Parameters
+/// * `C`: The physical time source, e.g. [`SystemClock`].
+#[derive(Debug, Clone, Default)]
+pub struct Hlc<C> {
+    physical: C,
+    /// The last timestamp handed out or received.
+    last: HlcTimestamp,
+}
+
+impl<C: Clock<u64>> Hlc<C> {
+    /// Creates a clock reading physical time from `physical`.
+    pub fn new(physical: C) -> Self {
+        Self {
+            physical,
+            last: HlcTimestamp::default(),
+        }
+    }
+
+    /// Returns a timestamp for a local event, later than every timestamp this
+    /// clock has handed out or received.
+    pub fn tick(&mut self) -> HlcTimestamp {
+        let now = self.physical.now();
+        if now > self.last.physical {
+            self.last = HlcTimestamp {
+                physical: now,
+                logical: 0,
+            };
+        } else {
+            self.last = self.last.successor();
+        }
+        self.last
+    }
+
+    /// Advances the clock past a timestamp received from another replica.
+    ///
+    /// Takes the max of the local, remote and current physical times, and bumps
+    /// the logical counter past whichever of the local and remote timestamps
+    /// share that physical time.
+    ///
+    /// A remote timestamp whose logical counter is already `u32::MAX` moves
+    /// the clock on to the next millisecond rather than overflowing it.
+    pub fn update(&mut self, remote: HlcTimestamp) {
+        let now = self.physical.now();
+        let physical = now.max(self.last.physical).max(remote.physical);
+        self.last = match (physical == self.last.physical, physical == remote.physical) {
+            (true, true) => self.last.max(remote).successor(),
+            (true, false) => self.last.successor(),
+            (false, true) => remote.successor(),
+            (false, false) => HlcTimestamp {
+                physical,
+                logical: 0,
+            },
+        };
+    }
+
+    /// Returns the last timestamp handed out or received, without advancing.
+    pub fn last(&self) -> HlcTimestamp {
+        self.last
+    }
+}
@@ -0,0 +1,74 @@
+//! The map and set types backing `GCounter`, `GSet` and `VectorClock`.
+//!
+//! With the `std` feature these are `HashMap` and `HashSet`. Without it they
+//! fall back to `BTreeMap` and `BTreeSet` from `alloc`, so keys must be `Ord`
+//! instead of `Hash`. [`Key`] names whichever bound is in effect.
+
+#[cfg(not(feature = "std"))]
+pub use alloc::collections::{BTreeMap as Map, BTreeSet as Set, btree_set as set};
+#[cfg(feature = "std")]
+pub use std::collections::{HashMap as Map, HashSet as Set, hash_set as set};
+
+/// The bound on keys and elements of [`Map`] and [`Set`]: `Hash + Eq` with the
+/// `std` feature, `Ord` without it.
+#[cfg(feature = "std")]
+pub trait Key: core::hash::Hash + Eq {}
+#[cfg(feature = "std")]
+impl<T: core::hash::Hash + Eq + ?Sized> Key for T {}
+
+/// The bound on keys and elements of [`Map`] and [`Set`]: `Hash + Eq` with the
+/// `std` feature, `Ord` without it.
+#[cfg(not(feature = "std"))]
+pub trait Key: Ord {}
+#[cfg(not(feature = "std"))]
+impl<T: Ord + ?Sized> Key for T {}
+
+/// Capacity hints for [`Map`] and [`Set`], forwarded to `HashMap`/`HashSet`.
+/// The `BTreeMap`/`BTreeSet` fallback has no notion of capacity and ignores them.
+pub(crate) trait CapacityHint {
+    /// Creates an empty collection with room for at least `capacity` entries.
+    fn with_capacity_hint(capacity: usize) -> Self;
+
+    /// Makes room for at least `additional` more entries.
+    fn reserve_hint(&mut self, additional: usize);
+}
+
+#[cfg(feature = "std")]
+impl<K: Key, V> CapacityHint for Map<K, V> {
+    fn with_capacity_hint(capacity: usize) -> Self {
+        Map::with_capacity(capacity)
+    }
+
+    fn reserve_hint(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Key> CapacityHint for Set<T> {
+    fn with_capacity_hint(capacity: usize) -> Self {
+        Set::with_capacity(capacity)
+    }
+
+    fn reserve_hint(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl<K: Key, V> CapacityHint for Map<K, V> {
+    fn with_capacity_hint(_capacity: usize) -> Self {
+        Map::new()
+    }
+
+    fn reserve_hint(&mut self, _additional: usize) {}
+}
+
+#[cfg(not(feature = "std"))]
+impl<T: Key> CapacityHint for Set<T> {
+    fn with_capacity_hint(_capacity: usize) -> Self {
+        Set::new()
+    }
+
+    fn reserve_hint(&mut self, _additional: usize) {}
+}
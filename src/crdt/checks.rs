@@ -1,54 +1,205 @@
-use super::Crdt;
+use super::{Bottom, Crdt, FieldVisitor, Fields};
+use crate::Apply;
 use core::fmt::Debug;
+use core::marker::PhantomData;
 use pretty_assertions::Comparison;
 use proptest::prelude::*;
-use proptest::test_runner::{Config, TestCaseError, TestError, TestRunner};
+use proptest::strategy::ValueTree;
+use proptest::test_runner::{Config, RngAlgorithm, TestCaseError, TestError, TestRng, TestRunner};
+use std::fmt;
 
-/// Helper to handle test results and provide clean error messages.
-pub fn handle_test_result<T: Debug>(result: Result<(), TestError<T>>, input_labels: &str) {
+/// A violated CRDT property, returned by the `try_check_*` functions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrdtViolation {
+    /// The name of the property that failed (e.g. `"Commutativity"`).
+    pub property: &'static str,
+    /// The failure reason and the minimal inputs that triggered it, formatted for display.
+    pub counterexample: String,
+    /// The RNG seed of the failing run; pass it to [`check_eventual_consistency_seeded`]
+    /// to reproduce the failure.
+    pub seed: [u8; 32],
+}
+
+impl fmt::Display for CrdtViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "\n\n--- CRDT PROPERTY FAILURE ---\n\
+            {}\n\
+            Seed: {:?}\n\
+            -----------------------------\n",
+            self.counterexample, self.seed
+        )
+    }
+}
+
+impl std::error::Error for CrdtViolation {}
+
+/// Converts a proptest result into a [`CrdtViolation`] for the named property.
+pub fn into_violation<T: Debug>(
+    result: Result<(), TestError<T>>,
+    property: &'static str,
+    input_labels: &str,
+    seed: [u8; 32],
+) -> Result<(), CrdtViolation> {
+    into_violation_with(result, property, input_labels, seed, |inputs| {
+        format!("{:#?}", inputs)
+    })
+}
+
+/// Like [`into_violation`], but renders the failing inputs with `format_inputs`.
+fn into_violation_with<T>(
+    result: Result<(), TestError<T>>,
+    property: &'static str,
+    input_labels: &str,
+    seed: [u8; 32],
+    format_inputs: impl FnOnce(&T) -> String,
+) -> Result<(), CrdtViolation>
+where
+    T: Debug,
+{
     match result {
-        Ok(_) => (),
-        Err(TestError::Fail(reason, counterexample)) => {
-            panic!(
-                "\n\n--- CRDT PROPERTY FAILURE ---\n\
-                {}\n\n\
-                Input values ({}):\n{:#?}\n\
-                -----------------------------\n",
-                reason, input_labels, counterexample
-            );
-        }
-        Err(err) => panic!("\nCRDT Property Check Error: {:?}\n", err),
+        Ok(_) => Ok(()),
+        Err(TestError::Fail(reason, counterexample)) => Err(CrdtViolation {
+            property,
+            counterexample: format!(
+                "{}\n\n\
+                Input values ({}):\n{}\n",
+                reason,
+                input_labels,
+                format_inputs(&counterexample)
+            ),
+            seed,
+        }),
+        Err(err) => Err(CrdtViolation {
+            property,
+            counterexample: format!("CRDT Property Check Error: {:?}\n", err),
+            seed,
+        }),
+    }
+}
+
+/// Renders each state with [`Crdt::debug_summary`], one labelled block per state.
+fn summarize_states<T: Crdt>(states: &[(&str, &T)]) -> String {
+    states
+        .iter()
+        .map(|(label, state)| format!("{} = {}", label, state.debug_summary()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A pre-rendered summary, so [`Comparison`] diffs it line by line as-is.
+struct Summary(String);
+
+impl Summary {
+    fn of<T: Crdt>(state: &T) -> Self {
+        Summary(state.debug_summary())
     }
 }
 
+impl Debug for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Panics with the formatted violation, if there is one.
+fn panic_on_violation(result: Result<(), CrdtViolation>) {
+    if let Err(violation) = result {
+        panic!("{}", violation);
+    }
+}
+
+/// Helper to handle test results and provide clean error messages.
+///
+/// The `seed` the runner was created with is included in the message, so the
+/// failing run can be replayed with [`create_seeded_runner`].
+pub fn handle_test_result<T: Debug>(
+    result: Result<(), TestError<T>>,
+    input_labels: &str,
+    seed: [u8; 32],
+) {
+    // The property name is not part of the panic message, which already names it.
+    panic_on_violation(into_violation(result, "", input_labels, seed));
+}
+
 /// Returns a TestRunner configured for CRDT property checks.
 pub fn create_runner() -> TestRunner {
+    create_runner_with(Config::default())
+}
+
+/// Returns a TestRunner for CRDT property checks using the given `config`.
+///
+/// Failure persistence is always disabled, since checks run against types
+/// from the caller's crate rather than a fixed test file.
+pub fn create_runner_with(config: Config) -> TestRunner {
     TestRunner::new(Config {
         failure_persistence: None,
-        ..Config::default()
+        ..config
     })
 }
 
+/// Returns a TestRunner whose inputs are generated deterministically from `seed`.
+pub fn create_seeded_runner(config: Config, seed: [u8; 32]) -> TestRunner {
+    TestRunner::new_with_rng(
+        Config {
+            failure_persistence: None,
+            ..config
+        },
+        TestRng::from_seed(RngAlgorithm::ChaCha, &seed),
+    )
+}
+
+/// Returns a fresh random seed for [`create_seeded_runner`].
+pub fn random_seed() -> [u8; 32] {
+    let mut seed = [0; 32];
+    TestRunner::default().rng().fill_bytes(&mut seed);
+    seed
+}
+
 /// Checks that the Idempotence property holds: `A ⊔ A = A`
 pub fn check_idempotence<T>()
 where
     T: Crdt + Arbitrary,
 {
-    let mut runner = create_runner();
-    let result = runner.run(&(any::<T>()), |a| {
-        let mut b = a.clone();
-        b.merge(&a);
+    check_idempotence_with::<T>(Config::default());
+}
 
-        if a != b {
-            return Err(TestCaseError::fail(format!(
-                "Idempotence failed (A ⊔ A != A):\n\
-                Legend: < A (Expected) / > A ⊔ A (Actual Result)\n{}",
-                Comparison::new(&a, &b)
-            )));
-        }
-        Ok(())
+/// Like [`check_idempotence`], but runs with a caller-supplied proptest [`Config`].
+pub fn check_idempotence_with<T>(config: Config)
+where
+    T: Crdt + Arbitrary,
+{
+    panic_on_violation(try_check_idempotence_with::<T>(config));
+}
+
+/// Like [`check_idempotence`], but returns the violation instead of panicking.
+pub fn try_check_idempotence<T>() -> Result<(), CrdtViolation>
+where
+    T: Crdt + Arbitrary,
+{
+    try_check_idempotence_with::<T>(Config::default())
+}
+
+/// Like [`try_check_idempotence`], but runs with a caller-supplied proptest [`Config`].
+pub fn try_check_idempotence_with<T>(config: Config) -> Result<(), CrdtViolation>
+where
+    T: Crdt + Arbitrary,
+{
+    run_idempotence::<T>(config, random_seed())
+}
+
+fn run_idempotence<T>(config: Config, seed: [u8; 32]) -> Result<(), CrdtViolation>
+where
+    T: Crdt + Arbitrary,
+{
+    let mut runner = create_seeded_runner(config, seed);
+    let result = runner.run(&(any::<T>()), |a| {
+        idempotence_law(&a).map_err(TestCaseError::fail)
     });
-    handle_test_result(result, "A");
+    into_violation_with(result, "Idempotence", "A", seed, |a| {
+        summarize_states(&[("A", a)])
+    })
 }
 
 /// Checks that the Commutativity property holds: `A ⊔ B = B ⊔ A`
@@ -56,24 +207,111 @@ pub fn check_commutativity<T>()
 where
     T: Crdt + Arbitrary,
 {
-    let mut runner = create_runner();
-    let result = runner.run(&(any::<T>(), any::<T>()), |(a, b)| {
-        let mut ab = a.clone();
-        ab.merge(&b);
+    check_commutativity_with::<T>(Config::default());
+}
 
-        let mut ba = b.clone();
-        ba.merge(&a);
+/// Like [`check_commutativity`], but runs with a caller-supplied proptest [`Config`].
+pub fn check_commutativity_with<T>(config: Config)
+where
+    T: Crdt + Arbitrary,
+{
+    panic_on_violation(try_check_commutativity_with::<T>(config));
+}
 
-        if ab != ba {
-            return Err(TestCaseError::fail(format!(
-                "Commutativity failed (A ⊔ B != B ⊔ A):\n\
-                Legend: < A ⊔ B (Left result) / > B ⊔ A (Right result)\n{}",
-                Comparison::new(&ab, &ba)
-            )));
-        }
-        Ok(())
+/// Like [`check_commutativity`], but returns the violation instead of panicking.
+pub fn try_check_commutativity<T>() -> Result<(), CrdtViolation>
+where
+    T: Crdt + Arbitrary,
+{
+    try_check_commutativity_with::<T>(Config::default())
+}
+
+/// Like [`try_check_commutativity`], but runs with a caller-supplied proptest [`Config`].
+pub fn try_check_commutativity_with<T>(config: Config) -> Result<(), CrdtViolation>
+where
+    T: Crdt + Arbitrary,
+{
+    run_commutativity::<T>(config, random_seed())
+}
+
+fn run_commutativity<T>(config: Config, seed: [u8; 32]) -> Result<(), CrdtViolation>
+where
+    T: Crdt + Arbitrary,
+{
+    let mut runner = create_seeded_runner(config, seed);
+    let result = runner.run(&(any::<T>(), any::<T>()), |(a, b)| {
+        commutativity_law(&a, &b).map_err(TestCaseError::fail)
     });
-    handle_test_result(result, "A, B");
+    into_violation_with(result, "Commutativity", "A, B", seed, |(a, b)| {
+        summarize_states(&[("A", a), ("B", b)])
+    })
+}
+
+/// Bytes of pseudo-random stream appended to fuzzer input.
+const FUZZ_TAIL_LEN: usize = 16 * 1024;
+
+/// Checks commutativity, `A ⊔ B = B ⊔ A`, on two states built from raw fuzzer
+/// input, for use as a `cargo fuzz` target body.
+///
+/// `data` is fed to the type's proptest strategy as its random stream, so
+/// mutations of `data` explore states the way proptest's own runs would. Once
+/// `data` is exhausted the stream continues pseudo-randomly, seeded from
+/// `data`, so every input deterministically builds some pair of states.
+/// There is no shrinking: the fuzzer minimizes the input instead.
+///
+/// # Panics
+/// Panics with both states and a diff of the results if the merge does not
+/// commute. Inputs the strategy rejects are skipped.
+///
+/// # Example
+/// ```
+/// use crdt::GCounter;
+/// use crdt::properties::fuzz_merge_commutativity;
+///
+/// // In `fuzz/fuzz_targets/gcounter.rs`:
+/// // fuzz_target!(|data: &[u8]| fuzz_merge_commutativity::<GCounter<String>>(data));
+/// fuzz_merge_commutativity::<GCounter<String>>(b"\x03a\x81\xffbc\x10");
+/// fuzz_merge_commutativity::<GCounter<String>>(&[]);
+/// ```
+pub fn fuzz_merge_commutativity<T>(data: &[u8])
+where
+    T: Crdt + Arbitrary,
+{
+    // Proptest pads an exhausted pass-through stream with zeros, on which
+    // strategies that retry (e.g. maps needing distinct keys) spin forever.
+    let mut seed = [0; 32];
+    let prefix = data.len().min(seed.len());
+    seed[..prefix].copy_from_slice(&data[..prefix]);
+    let mut stream = data.to_vec();
+    stream.resize(data.len() + FUZZ_TAIL_LEN, 0);
+    TestRng::from_seed(RngAlgorithm::ChaCha, &seed).fill_bytes(&mut stream[data.len()..]);
+
+    let mut runner = TestRunner::new_with_rng(
+        Config {
+            failure_persistence: None,
+            ..Config::default()
+        },
+        TestRng::from_seed(RngAlgorithm::PassThrough, &stream),
+    );
+    let strategy = any::<T>();
+    let (Ok(a), Ok(b)) = (
+        strategy.new_tree(&mut runner),
+        strategy.new_tree(&mut runner),
+    ) else {
+        return;
+    };
+    let (a, b) = (a.current(), b.current());
+
+    if let Err(reason) = commutativity_law(&a, &b) {
+        panic!(
+            "\n\n--- CRDT PROPERTY FAILURE ---\n\
+            {}\n\n\
+            Input values (A, B):\n{}\n\
+            -----------------------------\n",
+            reason,
+            summarize_states(&[("A", &a), ("B", &b)])
+        );
+    }
 }
 
 /// Checks that the Associativity property holds: `(A ⊔ B) ⊔ C = A ⊔ (B ⊔ C)`
@@ -81,35 +319,568 @@ pub fn check_associativity<T>()
 where
     T: Crdt + Arbitrary,
 {
-    let mut runner = create_runner();
+    check_associativity_with::<T>(Config::default());
+}
+
+/// Like [`check_associativity`], but runs with a caller-supplied proptest [`Config`].
+pub fn check_associativity_with<T>(config: Config)
+where
+    T: Crdt + Arbitrary,
+{
+    panic_on_violation(try_check_associativity_with::<T>(config));
+}
+
+/// Like [`check_associativity`], but returns the violation instead of panicking.
+pub fn try_check_associativity<T>() -> Result<(), CrdtViolation>
+where
+    T: Crdt + Arbitrary,
+{
+    try_check_associativity_with::<T>(Config::default())
+}
+
+/// Like [`try_check_associativity`], but runs with a caller-supplied proptest [`Config`].
+pub fn try_check_associativity_with<T>(config: Config) -> Result<(), CrdtViolation>
+where
+    T: Crdt + Arbitrary,
+{
+    run_associativity::<T>(config, random_seed())
+}
+
+fn run_associativity<T>(config: Config, seed: [u8; 32]) -> Result<(), CrdtViolation>
+where
+    T: Crdt + Arbitrary,
+{
+    let mut runner = create_seeded_runner(config, seed);
     let result = runner.run(&(any::<T>(), any::<T>(), any::<T>()), |(a, b, c)| {
-        let mut ab_c = a.clone();
-        ab_c.merge(&b);
-        ab_c.merge(&c);
+        associativity_law(&a, &b, &c).map_err(TestCaseError::fail)
+    });
+    into_violation_with(result, "Associativity", "A, B, C", seed, |(a, b, c)| {
+        summarize_states(&[("A", a), ("B", b), ("C", c)])
+    })
+}
+
+/// Checks that merge is inflationary (monotone): `A ≤ A ⊔ B` and `B ≤ A ⊔ B`
+///
+/// A merge must never lose information from either input.
+pub fn check_monotonicity<T>()
+where
+    T: Crdt + Arbitrary,
+{
+    check_monotonicity_with::<T>(Config::default());
+}
+
+/// Like [`check_monotonicity`], but runs with a caller-supplied proptest [`Config`].
+pub fn check_monotonicity_with<T>(config: Config)
+where
+    T: Crdt + Arbitrary,
+{
+    panic_on_violation(try_check_monotonicity_with::<T>(config));
+}
+
+/// Like [`check_monotonicity`], but returns the violation instead of panicking.
+pub fn try_check_monotonicity<T>() -> Result<(), CrdtViolation>
+where
+    T: Crdt + Arbitrary,
+{
+    try_check_monotonicity_with::<T>(Config::default())
+}
 
-        let mut bc = b.clone();
-        bc.merge(&c);
-        let mut a_bc = a.clone();
-        a_bc.merge(&bc);
+/// Like [`try_check_monotonicity`], but runs with a caller-supplied proptest [`Config`].
+pub fn try_check_monotonicity_with<T>(config: Config) -> Result<(), CrdtViolation>
+where
+    T: Crdt + Arbitrary,
+{
+    run_monotonicity::<T>(config, random_seed())
+}
+
+fn run_monotonicity<T>(config: Config, seed: [u8; 32]) -> Result<(), CrdtViolation>
+where
+    T: Crdt + Arbitrary,
+{
+    let mut runner = create_seeded_runner(config, seed);
+    let result = runner.run(&(any::<T>(), any::<T>()), |(a, b)| {
+        monotonicity_law(&a, &b).map_err(TestCaseError::fail)
+    });
+    into_violation_with(result, "Monotonicity", "A, B", seed, |(a, b)| {
+        summarize_states(&[("A", a), ("B", b)])
+    })
+}
 
-        if ab_c != a_bc {
+/// Checks that the default state is the lattice bottom: `⊥ ⊔ A = A` and `A ⊔ ⊥ = A`
+///
+/// `init()` must be a neutral element for merge, or a fresh replica would
+/// corrupt any state it syncs with.
+pub fn check_bottom_is_neutral<T>()
+where
+    T: Bottom + Arbitrary,
+{
+    check_bottom_is_neutral_with::<T>(Config::default());
+}
+
+/// Like [`check_bottom_is_neutral`], but runs with a caller-supplied proptest [`Config`].
+pub fn check_bottom_is_neutral_with<T>(config: Config)
+where
+    T: Bottom + Arbitrary,
+{
+    panic_on_violation(try_check_bottom_is_neutral_with::<T>(config));
+}
+
+/// Like [`check_bottom_is_neutral`], but returns the violation instead of panicking.
+pub fn try_check_bottom_is_neutral<T>() -> Result<(), CrdtViolation>
+where
+    T: Bottom + Arbitrary,
+{
+    try_check_bottom_is_neutral_with::<T>(Config::default())
+}
+
+/// Like [`try_check_bottom_is_neutral`], but runs with a caller-supplied proptest [`Config`].
+pub fn try_check_bottom_is_neutral_with<T>(config: Config) -> Result<(), CrdtViolation>
+where
+    T: Bottom + Arbitrary,
+{
+    run_bottom_is_neutral::<T>(config, random_seed())
+}
+
+fn run_bottom_is_neutral<T>(config: Config, seed: [u8; 32]) -> Result<(), CrdtViolation>
+where
+    T: Bottom + Arbitrary,
+{
+    let mut runner = create_seeded_runner(config, seed);
+    let result = runner.run(&(any::<T>()), |a| {
+        bottom_neutrality_law(&a, &T::init()).map_err(TestCaseError::fail)
+    });
+    into_violation_with(result, "Bottom neutrality", "A", seed, |a| {
+        summarize_states(&[("A", a)])
+    })
+}
+
+/// Checks that `leq` agrees with `merge`: `A ≤ B` iff `A ⊔ B = B`.
+///
+/// Useful for verifying an optimized `leq` override against the merge it shortcuts.
+pub fn check_leq_consistency<T>()
+where
+    T: Crdt + Arbitrary,
+{
+    check_leq_consistency_with::<T>(Config::default());
+}
+
+/// Like [`check_leq_consistency`], but runs with a caller-supplied proptest [`Config`].
+pub fn check_leq_consistency_with<T>(config: Config)
+where
+    T: Crdt + Arbitrary,
+{
+    panic_on_violation(try_check_leq_consistency_with::<T>(config));
+}
+
+/// Like [`check_leq_consistency`], but returns the violation instead of panicking.
+pub fn try_check_leq_consistency<T>() -> Result<(), CrdtViolation>
+where
+    T: Crdt + Arbitrary,
+{
+    try_check_leq_consistency_with::<T>(Config::default())
+}
+
+/// Like [`try_check_leq_consistency`], but runs with a caller-supplied proptest [`Config`].
+pub fn try_check_leq_consistency_with<T>(config: Config) -> Result<(), CrdtViolation>
+where
+    T: Crdt + Arbitrary,
+{
+    run_leq_consistency::<T>(config, random_seed())
+}
+
+fn run_leq_consistency<T>(config: Config, seed: [u8; 32]) -> Result<(), CrdtViolation>
+where
+    T: Crdt + Arbitrary,
+{
+    let mut runner = create_seeded_runner(config, seed);
+    let result = runner.run(&(any::<T>(), any::<T>()), |(a, b)| {
+        leq_consistency_law(&a, &b).map_err(TestCaseError::fail)
+    });
+    into_violation_with(result, "Leq consistency", "A, B", seed, |(a, b)| {
+        summarize_states(&[("A", a), ("B", b)])
+    })
+}
+
+/// Checks that operations commute: applying `x` then `y` to a state gives the
+/// same result as applying `y` then `x`.
+///
+/// Op-based CRDTs deliver concurrent operations in different orders on
+/// different replicas, so this is what makes them converge.
+pub fn check_op_commutativity<T>()
+where
+    T: Apply + Crdt + Arbitrary,
+    T::Op: Arbitrary + Clone + Debug,
+    T::Context: Arbitrary + Clone + Debug,
+{
+    check_op_commutativity_with::<T>(Config::default());
+}
+
+/// Like [`check_op_commutativity`], but runs with a caller-supplied proptest [`Config`].
+pub fn check_op_commutativity_with<T>(config: Config)
+where
+    T: Apply + Crdt + Arbitrary,
+    T::Op: Arbitrary + Clone + Debug,
+    T::Context: Arbitrary + Clone + Debug,
+{
+    panic_on_violation(try_check_op_commutativity_with::<T>(config));
+}
+
+/// Like [`check_op_commutativity`], but returns the violation instead of panicking.
+pub fn try_check_op_commutativity<T>() -> Result<(), CrdtViolation>
+where
+    T: Apply + Crdt + Arbitrary,
+    T::Op: Arbitrary + Clone + Debug,
+    T::Context: Arbitrary + Clone + Debug,
+{
+    try_check_op_commutativity_with::<T>(Config::default())
+}
+
+/// Like [`try_check_op_commutativity`], but runs with a caller-supplied proptest [`Config`].
+pub fn try_check_op_commutativity_with<T>(config: Config) -> Result<(), CrdtViolation>
+where
+    T: Apply + Crdt + Arbitrary,
+    T::Op: Arbitrary + Clone + Debug,
+    T::Context: Arbitrary + Clone + Debug,
+{
+    run_op_commutativity::<T>(config, random_seed())
+}
+
+fn run_op_commutativity<T>(config: Config, seed: [u8; 32]) -> Result<(), CrdtViolation>
+where
+    T: Apply + Crdt + Arbitrary,
+    T::Op: Arbitrary + Clone + Debug,
+    T::Context: Arbitrary + Clone + Debug,
+{
+    let mut runner = create_seeded_runner(config, seed);
+    let strategy = (
+        any::<T>(),
+        any::<(T::Op, T::Context)>(),
+        any::<(T::Op, T::Context)>(),
+    );
+    let result = runner.run(&strategy, |(state, (x, x_ctx), (y, y_ctx))| {
+        let mut xy = state.clone();
+        xy.apply(x.clone(), x_ctx.clone());
+        xy.apply(y.clone(), y_ctx.clone());
+
+        let mut yx = state;
+        yx.apply(y, y_ctx);
+        yx.apply(x, x_ctx);
+
+        if xy != yx {
             return Err(TestCaseError::fail(format!(
-                "Associativity failed ((A ⊔ B) ⊔ C != A ⊔ (B ⊔ C)):\n\
-                Legend: < (A ⊔ B) ⊔ C (Left result) / > A ⊔ (B ⊔ C) (Right result)\n{}",
-                Comparison::new(&ab_c, &a_bc)
+                "Op commutativity failed (apply X then Y != apply Y then X):\n\
+                Legend: < X then Y (Left result) / > Y then X (Right result)\n{}",
+                Comparison::new(&Summary::of(&xy), &Summary::of(&yx))
             )));
         }
         Ok(())
     });
-    handle_test_result(result, "A, B, C");
+    into_violation_with(
+        result,
+        "Op commutativity",
+        "State, (X, context), (Y, context)",
+        seed,
+        |(state, x, y)| {
+            format!(
+                "{}\nX = {:#?}\nY = {:#?}",
+                summarize_states(&[("State", state)]),
+                x,
+                y
+            )
+        },
+    )
 }
 
 /// Runs all CRDT property checks for type T.
 pub fn check_eventual_consistency<T>()
 where
-    T: Crdt + Arbitrary,
+    T: Bottom + Arbitrary,
+{
+    check_eventual_consistency_with::<T>(Config::default());
+}
+
+/// Runs all CRDT property checks for type T with a caller-supplied proptest [`Config`].
+///
+/// Raising `cases` improves coverage for types with large state spaces, such as
+/// ITC trees, where the default 256 cases may not reach interesting shapes
+/// (e.g. `Config::with_cases(4096)` for nightly runs).
+pub fn check_eventual_consistency_with<T>(config: Config)
+where
+    T: Bottom + Arbitrary,
 {
-    check_idempotence::<T>();
-    check_commutativity::<T>();
-    check_associativity::<T>();
+    check_idempotence_with::<T>(config.clone());
+    check_commutativity_with::<T>(config.clone());
+    check_associativity_with::<T>(config.clone());
+    check_monotonicity_with::<T>(config.clone());
+    check_bottom_is_neutral_with::<T>(config);
+}
+
+/// Runs all CRDT property checks for type T with inputs generated from a fixed `seed`.
+///
+/// Every failure message includes the seed of the run that produced it, so a
+/// failure seen in CI can be replayed locally by passing that seed here.
+pub fn check_eventual_consistency_seeded<T>(seed: [u8; 32])
+where
+    T: Bottom + Arbitrary,
+{
+    panic_on_violation(run_idempotence::<T>(Config::default(), seed));
+    panic_on_violation(run_commutativity::<T>(Config::default(), seed));
+    panic_on_violation(run_associativity::<T>(Config::default(), seed));
+    panic_on_violation(run_monotonicity::<T>(Config::default(), seed));
+    panic_on_violation(run_bottom_is_neutral::<T>(Config::default(), seed));
+}
+
+/// Like [`check_eventual_consistency`], but runs every check and returns all
+/// violations instead of panicking at the first one.
+pub fn try_check_eventual_consistency<T>() -> Result<(), Vec<CrdtViolation>>
+where
+    T: Bottom + Arbitrary,
+{
+    try_check_eventual_consistency_with::<T>(Config::default())
+}
+
+/// Like [`try_check_eventual_consistency`], but runs with a caller-supplied proptest [`Config`].
+pub fn try_check_eventual_consistency_with<T>(config: Config) -> Result<(), Vec<CrdtViolation>>
+where
+    T: Bottom + Arbitrary,
+{
+    let violations: Vec<CrdtViolation> = [
+        try_check_idempotence_with::<T>(config.clone()),
+        try_check_commutativity_with::<T>(config.clone()),
+        try_check_associativity_with::<T>(config.clone()),
+        try_check_monotonicity_with::<T>(config.clone()),
+        try_check_bottom_is_neutral_with::<T>(config),
+    ]
+    .into_iter()
+    .filter_map(Result::err)
+    .collect();
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(violations)
+    }
+}
+
+/// A law broken by one field of a composite CRDT, returned by
+/// [`try_check_composite`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldViolation {
+    /// The name of the offending field (its index, for tuple structs).
+    pub field: &'static str,
+    /// The law the field's sub-CRDT broke, with inputs shown for that field only.
+    pub violation: CrdtViolation,
+}
+
+impl fmt::Display for FieldViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\n\nIn field `{}`:{}", self.field, self.violation)
+    }
+}
+
+impl std::error::Error for FieldViolation {}
+
+/// Checks every CRDT law on each sub-CRDT field of a composite, one field at a
+/// time, and panics naming the first field that breaks one.
+///
+/// States are generated for the whole composite and projected onto each field,
+/// so the counterexample shows only the offending field rather than the whole
+/// struct. The bottom of each field is its value in `T::init()`. Besides the
+/// laws [`check_eventual_consistency`] runs, each field is checked for
+/// [`leq` consistency](check_leq_consistency). `T` gets [`Fields`] from
+/// `#[derive(Crdt)]`.
+pub fn check_composite<T>()
+where
+    T: Bottom + Arbitrary + Fields,
+{
+    check_composite_with::<T>(Config::default());
+}
+
+/// Like [`check_composite`], but runs with a caller-supplied proptest [`Config`].
+pub fn check_composite_with<T>(config: Config)
+where
+    T: Bottom + Arbitrary + Fields,
+{
+    if let Err(violation) = try_check_composite_with::<T>(config) {
+        panic!("{}", violation);
+    }
+}
+
+/// Like [`check_composite`], but returns the violation instead of panicking.
+pub fn try_check_composite<T>() -> Result<(), FieldViolation>
+where
+    T: Bottom + Arbitrary + Fields,
+{
+    try_check_composite_with::<T>(Config::default())
+}
+
+/// Like [`try_check_composite`], but runs with a caller-supplied proptest [`Config`].
+pub fn try_check_composite_with<T>(config: Config) -> Result<(), FieldViolation>
+where
+    T: Bottom + Arbitrary + Fields,
+{
+    let mut checker = CompositeChecker::<T> {
+        config,
+        seed: random_seed(),
+        violation: None,
+        composite: PhantomData,
+    };
+    T::visit_fields(&mut checker);
+    match checker.violation {
+        Some(violation) => Err(violation),
+        None => Ok(()),
+    }
+}
+
+/// Runs the field laws on each visited field until one fails.
+struct CompositeChecker<T> {
+    config: Config,
+    seed: [u8; 32],
+    violation: Option<FieldViolation>,
+    composite: PhantomData<T>,
+}
+
+impl<T> FieldVisitor<T> for CompositeChecker<T>
+where
+    T: Bottom + Arbitrary,
+{
+    fn visit<F: Crdt>(&mut self, name: &'static str, project: fn(&T) -> &F) {
+        if self.violation.is_some() {
+            return;
+        }
+        let bottom = T::init();
+        for (property, law) in field_laws::<F>() {
+            let mut runner = create_seeded_runner(self.config.clone(), self.seed);
+            let result = runner.run(&(any::<T>(), any::<T>(), any::<T>()), |(a, b, c)| {
+                law(project(&a), project(&b), project(&c), project(&bottom))
+                    .map_err(TestCaseError::fail)
+            });
+            let violation =
+                into_violation_with(result, property, "A, B, C", self.seed, |(a, b, c)| {
+                    summarize_states(&[("A", project(a)), ("B", project(b)), ("C", project(c))])
+                });
+            if let Err(violation) = violation {
+                self.violation = Some(FieldViolation {
+                    field: name,
+                    violation,
+                });
+                return;
+            }
+        }
+    }
+}
+
+/// A law checked on one field: takes three states and the field's bottom.
+type FieldLaw<F> = fn(&F, &F, &F, &F) -> Result<(), String>;
+
+/// The laws [`check_composite`] checks on each field: those
+/// [`check_eventual_consistency`] runs, in the same order, then leq consistency.
+fn field_laws<F: Crdt>() -> [(&'static str, FieldLaw<F>); 6] {
+    [
+        ("Idempotence", |a, _, _, _| idempotence_law(a)),
+        ("Commutativity", |a, b, _, _| commutativity_law(a, b)),
+        ("Associativity", |a, b, c, _| associativity_law(a, b, c)),
+        ("Monotonicity", |a, b, _, _| monotonicity_law(a, b)),
+        ("Bottom neutrality", |a, _, _, bottom| {
+            bottom_neutrality_law(a, bottom)
+        }),
+        ("Leq consistency", |a, b, _, _| leq_consistency_law(a, b)),
+    ]
+}
+
+// --- LAWS ---
+//
+// Each law checks one set of inputs and explains a failure with a diff. The
+// `run_*` checks, the fuzz target and `check_composite` all share them.
+
+/// `A ⊔ A = A`
+fn idempotence_law<T: Crdt>(a: &T) -> Result<(), String> {
+    let aa = a.merged(a);
+    if aa != *a {
+        return Err(format!(
+            "Idempotence failed (A ⊔ A != A):\n\
+            Legend: < A (Expected) / > A ⊔ A (Actual Result)\n{}",
+            Comparison::new(&Summary::of(a), &Summary::of(&aa))
+        ));
+    }
+    Ok(())
+}
+
+/// `A ⊔ B = B ⊔ A`
+fn commutativity_law<T: Crdt>(a: &T, b: &T) -> Result<(), String> {
+    let (ab, ba) = (a.merged(b), b.merged(a));
+    if ab != ba {
+        return Err(format!(
+            "Commutativity failed (A ⊔ B != B ⊔ A):\n\
+            Legend: < A ⊔ B (Left result) / > B ⊔ A (Right result)\n{}",
+            Comparison::new(&Summary::of(&ab), &Summary::of(&ba))
+        ));
+    }
+    Ok(())
+}
+
+/// `(A ⊔ B) ⊔ C = A ⊔ (B ⊔ C)`
+fn associativity_law<T: Crdt>(a: &T, b: &T, c: &T) -> Result<(), String> {
+    let left = a.merged(b).merged(c);
+    let right = a.merged(&b.merged(c));
+    if left != right {
+        return Err(format!(
+            "Associativity failed ((A ⊔ B) ⊔ C != A ⊔ (B ⊔ C)):\n\
+            Legend: < (A ⊔ B) ⊔ C (Left result) / > A ⊔ (B ⊔ C) (Right result)\n{}",
+            Comparison::new(&Summary::of(&left), &Summary::of(&right))
+        ));
+    }
+    Ok(())
+}
+
+/// `A ≤ A ⊔ B` and `B ≤ A ⊔ B`
+fn monotonicity_law<T: Crdt>(a: &T, b: &T) -> Result<(), String> {
+    let ab = a.merged(b);
+    for (label, input) in [("A", a), ("B", b)] {
+        if !input.leq(&ab) {
+            return Err(format!(
+                "Monotonicity failed ({} ≰ A ⊔ B):\n\
+                Legend: < {} (Input) / > A ⊔ B (Merged result)\n{}",
+                label,
+                label,
+                Comparison::new(&Summary::of(input), &Summary::of(&ab))
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// `⊥ ⊔ A = A` and `A ⊔ ⊥ = A`
+fn bottom_neutrality_law<T: Crdt>(a: &T, bottom: &T) -> Result<(), String> {
+    for (label, merged) in [("⊥ ⊔ A", bottom.merged(a)), ("A ⊔ ⊥", a.merged(bottom))] {
+        if merged != *a {
+            return Err(format!(
+                "Bottom neutrality failed ({} != A):\n\
+                Legend: < A (Expected) / > {} (Actual Result)\n{}",
+                label,
+                label,
+                Comparison::new(&Summary::of(a), &Summary::of(&merged))
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// `A ≤ B` iff `A ⊔ B = B`
+fn leq_consistency_law<T: Crdt>(a: &T, b: &T) -> Result<(), String> {
+    // Also compare against a state known to dominate `a`.
+    for other in [b, &a.merged(b)] {
+        let merged = a.merged(other);
+        let expected = merged == *other;
+        if a.leq(other) != expected {
+            return Err(format!(
+                "Leq consistency failed (A ≤ B is {} but A ⊔ B = B is {}):\n\
+                Legend: < A ⊔ B / > B\n{}",
+                !expected,
+                expected,
+                Comparison::new(&Summary::of(&merged), &Summary::of(other))
+            ));
+        }
+    }
+    Ok(())
 }
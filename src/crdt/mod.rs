@@ -1,7 +1,11 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
 use core::fmt::Debug;
 
 #[cfg(feature = "proptest")]
 pub mod checks;
+mod std_impls;
 
 /// The core trait for Conflict-Free Replicated Datatypes.
 ///
@@ -13,16 +17,261 @@ pub mod checks;
 /// When the `proptest` feature is enabled, a `properties` module is available
 /// containing helper functions to verify CRDT properties. These functions
 /// require the type to implement `proptest::arbitrary::Arbitrary`.
-pub trait Crdt: Clone + Debug + PartialEq + Default {
+///
+/// `Crdt` does not require `Default`: types with a natural empty state get
+/// [`Bottom::init`] through `Default`, and the checks that need such a state
+/// (bottom neutrality, and so [`check_eventual_consistency`]) ask for [`Bottom`].
+///
+/// [`check_eventual_consistency`]: crate::properties::check_eventual_consistency
+pub trait Crdt: Clone + Debug + PartialEq {
     /// The Rust type that represents the actual value of the CRDT (e.g. u32 for a GCounter).
     type Value;
 
-    fn init() -> Self {
-        Self::default()
-    }
     /// Merges another CRDT into this one.
     fn merge(&mut self, other: &Self);
 
+    /// Returns a new state equal to `self ⊔ other`, leaving both inputs untouched.
+    fn merged(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        result.merge(other);
+        result
+    }
+
     /// Returns the current value of the CRDT.
     fn value(&self) -> Self::Value;
+
+    /// Borrows the value, for types that store it directly.
+    ///
+    /// Returns `None` by default, in which case callers fall back to
+    /// [`value`](Crdt::value). Types whose state is their value (e.g. `GSet`)
+    /// override this so that reading it does not clone the whole collection.
+    fn value_ref(&self) -> Option<&Self::Value> {
+        None
+    }
+
+    /// Returns true if this state is dominated by `other` in the semilattice order,
+    /// i.e. merging `self` into `other` would not change it (`self ⊔ other = other`).
+    ///
+    /// The default performs a full merge; implementations should override it with
+    /// a cheaper comparison where one exists.
+    fn leq(&self, other: &Self) -> bool {
+        let mut merged = other.clone();
+        merged.merge(self);
+        merged == *other
+    }
+
+    /// Renders the state for property-check failure messages.
+    ///
+    /// Defaults to `{:#?}`. Types implementing [`CrdtDebug`] override this to
+    /// return [`CrdtDebug::summarize`] instead.
+    fn debug_summary(&self) -> String {
+        format!("{self:#?}")
+    }
+}
+
+/// A CRDT with a bottom state: the state of a fresh replica, which merging
+/// into any other state leaves unchanged.
+///
+/// Implemented for every `Crdt` that is also `Default`, with `Default` as the
+/// bottom, so most types never implement it by hand. A type without a sensible
+/// empty state (e.g. an op-based type that only exists once seeded) can skip
+/// `Default` and still implement [`Crdt`] and [`Apply`](crate::Apply). A type
+/// without `Default` can also implement `Bottom` by hand.
+///
+/// # Migrating from `Crdt::init`
+/// `init` used to be a provided method of [`Crdt`], and `Default` a supertrait.
+/// * Code calling `T::init()` needs `Bottom` in scope (`use crdt::Bottom;`).
+/// * Generic code that creates fresh states should bound on `T: Bottom`
+///   instead of `T: Crdt`.
+/// * Types that overrode `init` should return that state from `Default`, or
+///   drop `Default` and implement `Bottom` by hand.
+///
+/// This trait was first proposed as `Bounded`. It is called `Bottom` because
+/// [`Bounded`](crate::Bounded) already names the ordered types with a least
+/// and greatest value that [`Max`](crate::Max) and [`Min`](crate::Min) hold,
+/// and both are exported from the crate root.
+pub trait Bottom: Crdt {
+    /// Returns the bottom state.
+    fn init() -> Self;
+}
+
+impl<T: Crdt + Default> Bottom for T {
+    fn init() -> Self {
+        T::default()
+    }
+}
+
+/// Per-field introspection for a composite CRDT, generated by `#[derive(Crdt)]`
+/// on structs.
+///
+/// Lists the fields the struct merges as sub-CRDTs, so the property checks can
+/// test each one on its own and name the field that breaks a law (see
+/// [`check_composite`]). Fields marked `#[crdt(skip)]` or merged with a
+/// `strategy` are not sub-CRDTs and are not visited.
+///
+/// [`check_composite`]: crate::properties::check_composite
+pub trait Fields: Sized {
+    /// Calls [`FieldVisitor::visit`] once per sub-CRDT field, in declaration order.
+    fn visit_fields<V: FieldVisitor<Self>>(visitor: &mut V);
+}
+
+/// Receives the sub-CRDT fields of a [`Fields`] type.
+pub trait FieldVisitor<S> {
+    /// Visits the field called `name` (its index, for tuple structs), which
+    /// `project` reads out of a state of the composite.
+    fn visit<F: Crdt>(&mut self, name: &'static str, project: fn(&S) -> &F);
+}
+
+/// A compact, deterministic rendering of a CRDT state for failure messages.
+///
+/// The derived `Debug` output of a map-backed state lists entries in hash
+/// order and includes bookkeeping fields, so two states that differ in one
+/// replica can print as entirely different blocks. A summary puts one entry
+/// per line in a stable order, so the diff in a counterexample only marks the
+/// entries that actually differ.
+///
+/// The property checks only see a summary through [`Crdt::debug_summary`];
+/// implementors should override it to call [`CrdtDebug::summarize`].
+pub trait CrdtDebug {
+    /// Returns the summary, one entry per line.
+    fn summarize(&self) -> String;
+}
+
+/// Formats `header { entry, ... }` with one entry per line, sorted so the
+/// output does not depend on the map's iteration order.
+pub(crate) fn summarize_entries(header: &str, entries: impl Iterator<Item = String>) -> String {
+    let mut entries: Vec<String> = entries.collect();
+    if entries.is_empty() {
+        return format!("{header} {{}}");
+    }
+    entries.sort();
+    let mut out = format!("{header} {{\n");
+    for entry in entries {
+        out.push_str("    ");
+        out.push_str(&entry);
+        out.push_str(",\n");
+    }
+    out.push('}');
+    out
+}
+
+/// Writes `header{entry, ...}` on one line, sorted so the output does not
+/// depend on the map's iteration order. Backs the `Display` impls.
+pub(crate) fn write_sorted_entries(
+    f: &mut core::fmt::Formatter<'_>,
+    header: &str,
+    entries: impl Iterator<Item = String>,
+) -> core::fmt::Result {
+    let mut entries: Vec<String> = entries.collect();
+    entries.sort();
+    write!(f, "{header}{{{}}}", entries.join(", "))
+}
+
+/// Lazily initialized CRDT state: `None` is the bottom, below every `Some`.
+///
+/// Merging `Some` into `None` adopts it, and merging two `Some`s merges the
+/// inner states.
+impl<T: Crdt> Crdt for Option<T> {
+    type Value = Option<T::Value>;
+
+    fn merge(&mut self, other: &Self) {
+        match (self.as_mut(), other) {
+            (_, None) => {}
+            (Some(inner), Some(other_inner)) => inner.merge(other_inner),
+            (None, Some(other_inner)) => *self = Some(other_inner.clone()),
+        }
+    }
+
+    fn value(&self) -> Self::Value {
+        self.as_ref().map(Crdt::value)
+    }
+
+    fn leq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(inner), Some(other_inner)) => inner.leq(other_inner),
+        }
+    }
+}
+
+/// Implements `Crdt` for a tuple of CRDTs as their product: elements are merged
+/// pairwise, as `#[derive(Crdt)]` does for struct fields, and `value()` returns
+/// the tuple of element values.
+macro_rules! impl_crdt_for_tuple {
+    ($($name:ident $index:tt),+) => {
+        impl<$($name: Crdt),+> Crdt for ($($name,)+) {
+            type Value = ($($name::Value,)+);
+
+            fn merge(&mut self, other: &Self) {
+                $(self.$index.merge(&other.$index);)+
+            }
+
+            fn value(&self) -> Self::Value {
+                ($(self.$index.value(),)+)
+            }
+
+            fn leq(&self, other: &Self) -> bool {
+                $(self.$index.leq(&other.$index))&&+
+            }
+        }
+    };
+}
+
+impl_crdt_for_tuple!(A 0);
+impl_crdt_for_tuple!(A 0, B 1);
+impl_crdt_for_tuple!(A 0, B 1, C 2);
+impl_crdt_for_tuple!(A 0, B 1, C 2, D 3);
+impl_crdt_for_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_crdt_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+
+/// What a merge changed, as reported by `merge_report` on types that track it.
+///
+/// A sync loop can use this to skip re-broadcasting after a merge that was a
+/// no-op, i.e. when the incoming state was already dominated by the local one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MergeReport {
+    /// Whether the merge changed the local state at all.
+    pub changed: bool,
+    /// How many entries (replicas or elements) the merge added or raised.
+    pub updated: usize,
+}
+
+impl MergeReport {
+    /// Builds a report from the number of entries a merge updated.
+    pub(crate) fn from_updated(updated: usize) -> Self {
+        Self {
+            changed: updated > 0,
+            updated,
+        }
+    }
+}
+
+/// Merges a collection of states into one, or returns `None` if it is empty.
+///
+/// Because `merge` is commutative and associative, the result does not depend
+/// on the order of `items`.
+pub fn join_all<T: Crdt>(items: impl IntoIterator<Item = T>) -> Option<T> {
+    items.into_iter().reduce(|mut acc, item| {
+        acc.merge(&item);
+        acc
+    })
+}
+
+/// Merges borrowed states into one, or returns `None` if there are none.
+///
+/// Clones only the first state, then merges in each of the rest unless it is
+/// already [`leq`](Crdt::leq) the result so far. Skipping is cheap when many
+/// replicas are up to date, since `leq` can usually compare without building a
+/// merged state. Like [`join_all`], the result does not depend on the order of
+/// `states`.
+pub fn merge_all<'a, T: Crdt + 'a>(states: impl IntoIterator<Item = &'a T>) -> Option<T> {
+    let mut states = states.into_iter();
+    let mut acc = states.next()?.clone();
+    for state in states {
+        if !state.leq(&acc) {
+            acc.merge(state);
+        }
+    }
+    Some(acc)
 }
@@ -0,0 +1,69 @@
+//! `Crdt` implementations for standard library collections and arrays.
+
+use super::Crdt;
+use alloc::collections::BTreeMap;
+use core::fmt::Debug;
+
+/// A map of CRDTs with grow-only keys, like [`GMap`](crate::GMap): merging
+/// inserts keys missing on one side and recursively merges the values of keys
+/// present on both. Keys are never removed by a merge.
+impl<K, V> Crdt for BTreeMap<K, V>
+where
+    K: Ord + Clone + Debug,
+    V: Crdt,
+{
+    type Value = BTreeMap<K, V::Value>;
+
+    fn merge(&mut self, other: &Self) {
+        for (key, other_value) in other {
+            match self.get_mut(key) {
+                Some(value) => value.merge(other_value),
+                None => {
+                    self.insert(key.clone(), other_value.clone());
+                }
+            }
+        }
+    }
+
+    fn value(&self) -> Self::Value {
+        self.iter()
+            .map(|(key, value)| (key.clone(), value.value()))
+            .collect()
+    }
+
+    fn leq(&self, other: &Self) -> bool {
+        self.iter().all(|(key, value)| {
+            other
+                .get(key)
+                .is_some_and(|other_value| value.leq(other_value))
+        })
+    }
+}
+
+/// A fixed-size array of CRDTs, merged element-wise like a tuple of the same
+/// type; `value()` returns the array of element values.
+///
+/// Arrays of up to 32 elements get [`Bottom`](super::Bottom) through `Default`,
+/// as an array of bottoms.
+impl<T, const N: usize> Crdt for [T; N]
+where
+    T: Crdt,
+{
+    type Value = [T::Value; N];
+
+    fn merge(&mut self, other: &Self) {
+        for (element, other_element) in self.iter_mut().zip(other) {
+            element.merge(other_element);
+        }
+    }
+
+    fn value(&self) -> Self::Value {
+        self.each_ref().map(Crdt::value)
+    }
+
+    fn leq(&self, other: &Self) -> bool {
+        self.iter()
+            .zip(other)
+            .all(|(element, other_element)| element.leq(other_element))
+    }
+}
@@ -0,0 +1,3 @@
+//! Opt-in tools for diagnosing how replicas diverge in the field.
+
+pub mod traced;
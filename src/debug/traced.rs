@@ -0,0 +1,146 @@
+use crate::Crdt;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "proptest")]
+use proptest::prelude::*;
+
+/// A state whose entries can be listed by key, for [`TracedCrdt`] to diff.
+///
+/// Keys name a field, replica or element; values render its state. Both are
+/// typically `Debug` output, so the trace reads like the state itself.
+pub trait TraceEntries {
+    /// Returns every entry of the state, keyed by field, replica or element.
+    fn trace_entries(&self) -> BTreeMap<String, String>;
+}
+
+/// One entry changed by a merge, as recorded by [`TracedCrdt`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeEvent {
+    /// The field, replica or element that changed.
+    pub key: String,
+    /// The entry before the merge, or `None` if it was absent.
+    pub old: Option<String>,
+    /// The entry after the merge, or `None` if the merge removed it.
+    pub new: Option<String>,
+}
+
+/// A CRDT wrapper that records which entries the last merge changed.
+///
+/// A debugging aid: once replicas converge, the contribution of each merge is
+/// lost, so when states diverge in the field it is hard to tell which replica
+/// brought in what. Wrapping a state records, on every [`Crdt::merge`], the
+/// entries that changed along with their old and new values.
+///
+/// Everything else is delegated to the inner state. The trace is not part of
+/// the state: it is ignored by `==` and only reflects merges made through
+/// this wrapper.
+///
+/// # Type Parameters
+/// * `T`: The wrapped CRDT. Must implement [`TraceEntries`].
+#[derive(Debug, Clone, Default)]
+pub struct TracedCrdt<T> {
+    inner: T,
+    /// The changes made by the last merge, sorted by key.
+    trace: Vec<ChangeEvent>,
+}
+
+impl<T: PartialEq> PartialEq for TracedCrdt<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<T: Eq> Eq for TracedCrdt<T> {}
+
+impl<T> Crdt for TracedCrdt<T>
+where
+    T: Crdt + TraceEntries,
+{
+    type Value = T::Value;
+
+    fn merge(&mut self, other: &Self) {
+        let mut before = self.inner.trace_entries();
+        self.inner.merge(&other.inner);
+        let after = self.inner.trace_entries();
+
+        self.trace.clear();
+        for (key, new) in after {
+            match before.remove(&key) {
+                Some(old) if old == new => {}
+                old => self.trace.push(ChangeEvent {
+                    key,
+                    old,
+                    new: Some(new),
+                }),
+            }
+        }
+        self.trace
+            .extend(before.into_iter().map(|(key, old)| ChangeEvent {
+                key,
+                old: Some(old),
+                new: None,
+            }));
+        self.trace.sort_by(|a, b| a.key.cmp(&b.key));
+    }
+
+    fn value(&self) -> Self::Value {
+        self.inner.value()
+    }
+
+    fn leq(&self, other: &Self) -> bool {
+        self.inner.leq(&other.inner)
+    }
+
+    fn debug_summary(&self) -> String {
+        self.inner.debug_summary()
+    }
+}
+
+impl<T> TracedCrdt<T> {
+    /// Wraps a state, starting with an empty trace.
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            trace: Vec::new(),
+        }
+    }
+
+    /// Returns the entries changed by the last merge, sorted by key.
+    ///
+    /// Empty before the first merge and after a merge that changed nothing.
+    pub fn last_merge_trace(&self) -> &[ChangeEvent] {
+        &self.trace
+    }
+
+    /// Returns the wrapped state.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Returns the wrapped state mutably, e.g. to apply local updates.
+    ///
+    /// Changes made this way are not traced.
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Unwraps the state, discarding the trace.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl<T> Arbitrary for TracedCrdt<T>
+where
+    T: Arbitrary + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<T>().prop_map(TracedCrdt::new).boxed()
+    }
+}
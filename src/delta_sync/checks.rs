@@ -1,8 +1,10 @@
 use super::DeltaSync;
-use crate::Crdt;
-use crate::crdt::checks::{check_eventual_consistency, create_runner, handle_test_result};
+use crate::crdt::checks::{
+    check_eventual_consistency, create_seeded_runner, handle_test_result, random_seed,
+};
+use crate::{Bottom, Crdt};
 use proptest::prelude::*;
-use proptest::test_runner::TestCaseError;
+use proptest::test_runner::{Config, TestCaseError};
 
 /// Checks that delta sync produces the same result as full-state merge.
 /// This is the fundamental correctness property of delta synchronization.
@@ -12,7 +14,8 @@ pub fn check_delta_merge_equivalence<T>()
 where
     T: DeltaSync + Arbitrary,
 {
-    let mut runner = create_runner();
+    let seed = random_seed();
+    let mut runner = create_seeded_runner(Config::default(), seed);
     let result = runner.run(&(any::<T>(), any::<T>()), |(a, b)| {
         // Full-state merge: A ⊔ B
         let mut full_merge = a.clone();
@@ -31,7 +34,7 @@ where
         }
         Ok(())
     });
-    handle_test_result(result, "A, B");
+    handle_test_result(result, "A, B", seed);
 }
 
 /// Checks that merging a delta never removes information.
@@ -42,7 +45,8 @@ pub fn check_delta_inflation<T>()
 where
     T: DeltaSync + Arbitrary,
 {
-    let mut runner = create_runner();
+    let seed = random_seed();
+    let mut runner = create_seeded_runner(Config::default(), seed);
     let result = runner.run(&(any::<T>(), any::<T>()), |(a, b)| {
         let delta = b.delta_from_summary(&a.summary());
         let mut with_delta = a.clone();
@@ -59,7 +63,7 @@ where
         }
         Ok(())
     });
-    handle_test_result(result, "A, B");
+    handle_test_result(result, "A, B", seed);
 }
 
 /// Checks that composing (batching) deltas is equivalent to applying them one at a time.
@@ -69,7 +73,8 @@ pub fn check_delta_composition<T>()
 where
     T: DeltaSync + Arbitrary,
 {
-    let mut runner = create_runner();
+    let seed = random_seed();
+    let mut runner = create_seeded_runner(Config::default(), seed);
     let result = runner.run(&(any::<T>(), any::<T>(), any::<T>()), |(a, b, c)| {
         let delta_b = b.delta_from_summary(&a.summary());
         let delta_c = c.delta_from_summary(&a.summary());
@@ -92,14 +97,14 @@ where
         }
         Ok(())
     });
-    handle_test_result(result, "A, B, C");
+    handle_test_result(result, "A, B, C", seed);
 }
 
 /// Runs all DeltaSync property checks for type T.
 /// Also runs the base Crdt eventual consistency checks.
 pub fn check_delta_sync_properties<T>()
 where
-    T: DeltaSync + Arbitrary,
+    T: DeltaSync + Bottom + Arbitrary,
 {
     check_eventual_consistency::<T>();
     check_delta_merge_equivalence::<T>();
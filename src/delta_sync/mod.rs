@@ -45,4 +45,16 @@ pub trait DeltaSync: Crdt {
     /// preventing manual specialization for types where `Delta = Self` but the merge
     /// path could be optimized.
     fn merge_delta(&mut self, delta: &Self::Delta);
+
+    /// Returns exactly what merging `other` into this state would add or raise,
+    /// without mutating either side.
+    ///
+    /// Applying the result with [`merge_delta`](DeltaSync::merge_delta) gives
+    /// the same state as `merge(other)`. Unlike `merge_report`, which only counts
+    /// the changes, the diff can be inspected (e.g. for an audit log) or
+    /// forwarded. The default computes `other`'s delta against this state's
+    /// summary.
+    fn merge_diff(&self, other: &Self) -> Self::Delta {
+        other.delta_from_summary(&self.summary())
+    }
 }
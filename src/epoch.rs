@@ -0,0 +1,99 @@
+use crate::{Bottom, Crdt};
+
+#[cfg(feature = "proptest")]
+use proptest::prelude::*;
+
+/// Wraps a CRDT with an epoch number, giving it a convergent "reset to empty".
+///
+/// [`Epoch::bump_epoch`] moves to the next epoch and resets the inner state to
+/// its bottom. Merging keeps the state of the higher epoch and discards the
+/// lower one's entirely, so a reset wins over every update made before it, on
+/// every replica, without tombstones. Within the same epoch the inner states
+/// merge as usual, so two replicas that reset concurrently simply share the
+/// new epoch.
+///
+/// Updates made concurrently with a reset, in the old epoch, are lost along
+/// with it.
+///
+/// # Type Parameters
+/// * `T`: The inner CRDT.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Epoch<T> {
+    epoch: u64,
+    inner: T,
+}
+
+impl<T: Bottom> Default for Epoch<T> {
+    fn default() -> Self {
+        Self::new(T::init())
+    }
+}
+
+impl<T: Crdt> Crdt for Epoch<T> {
+    type Value = T::Value;
+
+    fn merge(&mut self, other: &Self) {
+        if other.epoch > self.epoch {
+            self.epoch = other.epoch;
+            self.inner = other.inner.clone();
+        } else if other.epoch == self.epoch {
+            self.inner.merge(&other.inner);
+        }
+    }
+
+    fn value(&self) -> Self::Value {
+        self.inner.value()
+    }
+
+    fn leq(&self, other: &Self) -> bool {
+        self.epoch < other.epoch || (self.epoch == other.epoch && self.inner.leq(&other.inner))
+    }
+}
+
+impl<T> Epoch<T> {
+    /// Wraps `inner` at epoch 0.
+    pub fn new(inner: T) -> Self {
+        Self { epoch: 0, inner }
+    }
+
+    /// Returns the current epoch.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Returns the inner state of the current epoch.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Returns the inner state of the current epoch, for local updates.
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+}
+
+impl<T: Bottom> Epoch<T> {
+    /// Moves to the next epoch and resets the inner state to its bottom.
+    ///
+    /// Once merged, the reset reaches every replica still in an older epoch.
+    pub fn bump_epoch(&mut self) {
+        self.epoch += 1;
+        self.inner = T::init();
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl<T> Arbitrary for Epoch<T>
+where
+    T: Arbitrary + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (0..3u64, any::<T>())
+            .prop_map(|(epoch, inner)| Epoch { epoch, inner })
+            .boxed()
+    }
+}
@@ -0,0 +1,163 @@
+use crate::collections::{Key, Set};
+use crate::primitive::gset::GSet;
+use crate::{Apply, Crdt, IdempotentApply, MergeReport};
+use core::fmt::Debug;
+
+#[cfg(feature = "proptest")]
+use alloc::string::String;
+#[cfg(feature = "proptest")]
+use proptest::prelude::*;
+
+/// A Grow-only directed Graph (G-Graph) CRDT.
+///
+/// Vertices and edges live in two [`GSet`]s, so merging unions both and
+/// nothing is ever removed. An edge may arrive before either of its endpoints
+/// (e.g. when deltas are delivered out of order); it is kept in the state but
+/// only becomes visible, in [`Crdt::value`] and [`GGraph::neighbors`], once
+/// both endpoints have been added.
+///
+/// # Type Parameters
+/// * `V`: The type of the vertices. Must implement `Hash`, `Eq`, `Clone`, and `Debug`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GGraph<V>
+where
+    V: Key,
+{
+    vertices: GSet<V>,
+    /// Directed edges as `(from, to)`, including ones whose endpoints are not known yet.
+    edges: GSet<(V, V)>,
+}
+
+/// An operation on a [`GGraph`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GGraphOp<V> {
+    AddVertex(V),
+    AddEdge(V, V),
+}
+
+impl<V: Key> Default for GGraph<V> {
+    fn default() -> Self {
+        Self {
+            vertices: GSet::new(),
+            edges: GSet::new(),
+        }
+    }
+}
+
+impl<V> Crdt for GGraph<V>
+where
+    V: Key + Clone + Debug,
+{
+    /// The vertices, and the edges whose endpoints are both vertices.
+    type Value = (Set<V>, Set<(V, V)>);
+
+    fn merge(&mut self, other: &Self) {
+        self.merge_report(other);
+    }
+
+    fn value(&self) -> Self::Value {
+        let edges = self
+            .edges
+            .iter()
+            .filter(|(from, to)| self.vertices.contains(from) && self.vertices.contains(to))
+            .cloned()
+            .collect();
+        (self.vertices.value(), edges)
+    }
+
+    fn leq(&self, other: &Self) -> bool {
+        self.vertices.leq(&other.vertices) && self.edges.leq(&other.edges)
+    }
+}
+
+impl<V> Apply for GGraph<V>
+where
+    V: Key + Clone + Debug,
+{
+    type Op = GGraphOp<V>;
+    type Context = ();
+
+    fn apply(&mut self, op: Self::Op, _ctx: Self::Context) {
+        match op {
+            GGraphOp::AddVertex(vertex) => self.add_vertex(vertex),
+            GGraphOp::AddEdge(from, to) => self.add_edge(from, to),
+        }
+    }
+}
+
+impl<V> IdempotentApply for GGraph<V> where V: Key + Clone + Debug {}
+
+impl<V> GGraph<V>
+where
+    V: Key,
+{
+    /// Creates a new, empty G-Graph.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a vertex to the graph.
+    pub fn add_vertex(&mut self, vertex: V) {
+        self.vertices.insert(vertex);
+    }
+
+    /// Adds a directed edge from `from` to `to`.
+    ///
+    /// The endpoints do not need to be vertices yet; the edge stays hidden
+    /// until they are.
+    pub fn add_edge(&mut self, from: V, to: V) {
+        self.edges.insert((from, to));
+    }
+
+    /// Returns true if the graph contains the vertex.
+    pub fn contains_vertex(&self, vertex: &V) -> bool {
+        self.vertices.contains(vertex)
+    }
+
+    /// Iterates over the targets of the visible edges leaving `vertex`.
+    ///
+    /// Yields nothing if `vertex` itself has not been added.
+    pub fn neighbors<'a>(&'a self, vertex: &'a V) -> impl Iterator<Item = &'a V> + 'a {
+        let known = self.vertices.contains(vertex);
+        self.edges
+            .iter()
+            .filter(move |(from, to)| known && from == vertex && self.vertices.contains(to))
+            .map(|(_, to)| to)
+    }
+}
+
+impl<V: Key + Clone> GGraph<V> {
+    /// Merges another graph into this one, reporting how many vertices and
+    /// edges were new.
+    pub fn merge_report(&mut self, other: &Self) -> MergeReport {
+        let vertices = self.vertices.merge_report(&other.vertices);
+        let edges = self.edges.merge_report(&other.edges);
+        MergeReport::from_updated(vertices.updated + edges.updated)
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for GGraph<String> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        let vertex = || "[a-d]".prop_map(String::from);
+        (
+            proptest::collection::vec(vertex(), 0..4),
+            proptest::collection::vec((vertex(), vertex()), 0..5),
+        )
+            .prop_map(|(vertices, edges)| {
+                let mut graph = GGraph::new();
+                for vertex in vertices {
+                    graph.add_vertex(vertex);
+                }
+                for (from, to) in edges {
+                    graph.add_edge(from, to);
+                }
+                graph
+            })
+            .boxed()
+    }
+}
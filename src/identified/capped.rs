@@ -0,0 +1,163 @@
+use super::Identified;
+use super::gcounter::GCounter;
+use crate::collections::{Key, Map};
+use crate::{Apply, Crdt, DeltaSync};
+
+#[cfg(feature = "proptest")]
+use proptest::prelude::*;
+
+/// A Grow-only Counter whose reported value saturates at a fixed cap.
+///
+/// Increments accumulate per replica exactly as in a [`GCounter`]; the cap is
+/// only applied when reading the value, so merging is unaffected and replicas
+/// still converge once they have exchanged state. This suits rate limits
+/// ("at most N requests"), where overshooting locally is fine but readers must
+/// never see more than N.
+///
+/// The cap is local configuration, not replicated state: it is ignored by
+/// `merge` and `==`, and skipped by serde (deserialized counters are uncapped).
+///
+/// # Type Parameters
+/// * `I`: The type of the Replica ID. Must be `Hash`, `Eq`, `Clone`, and `Debug`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone)]
+pub struct CappedGCounter<I>
+where
+    I: Key,
+{
+    /// The uncapped per-replica counts.
+    counter: GCounter<I>,
+    /// The largest value `value()` will report.
+    #[cfg_attr(feature = "serde", serde(skip, default = "uncapped"))]
+    cap: u64,
+}
+
+#[cfg(feature = "serde")]
+fn uncapped() -> u64 {
+    u64::MAX
+}
+
+impl<I: Key> Default for CappedGCounter<I> {
+    /// An uncapped counter, i.e. one with a cap of `u64::MAX`.
+    fn default() -> Self {
+        Self {
+            counter: GCounter::default(),
+            cap: u64::MAX,
+        }
+    }
+}
+
+impl<I: Key> PartialEq for CappedGCounter<I> {
+    fn eq(&self, other: &Self) -> bool {
+        self.counter == other.counter
+    }
+}
+
+impl<I: Key> Eq for CappedGCounter<I> {}
+
+impl<I> Crdt for CappedGCounter<I>
+where
+    I: Key + Clone + core::fmt::Debug,
+{
+    type Value = u64;
+
+    fn merge(&mut self, other: &Self) {
+        // Keep our own cap; only the counts are replicated.
+        self.counter.merge(&other.counter);
+    }
+
+    fn value(&self) -> Self::Value {
+        self.counter.value().min(self.cap)
+    }
+
+    fn leq(&self, other: &Self) -> bool {
+        self.counter.leq(&other.counter)
+    }
+}
+
+impl<I> Apply for CappedGCounter<I>
+where
+    I: Key + Clone + core::fmt::Debug,
+{
+    type Op = u64;
+    type Context = I;
+
+    fn apply(&mut self, op: Self::Op, ctx: Self::Context) {
+        self.add(op, ctx);
+    }
+}
+
+impl<I> DeltaSync for CappedGCounter<I>
+where
+    I: Key + Clone + core::fmt::Debug,
+{
+    type Summary = Map<I, u64>;
+    type Delta = Self;
+
+    fn summary(&self) -> Self::Summary {
+        self.counter.summary()
+    }
+
+    fn delta_from_summary(&self, remote_summary: &Self::Summary) -> Self {
+        CappedGCounter {
+            counter: self.counter.delta_from_summary(remote_summary),
+            cap: self.cap,
+        }
+    }
+
+    fn merge_delta(&mut self, delta: &Self) {
+        self.merge(delta);
+    }
+}
+
+impl<I> Identified for CappedGCounter<I>
+where
+    I: Key + Clone + core::fmt::Debug,
+{
+    type ReplicaId = I;
+}
+
+impl<I> CappedGCounter<I>
+where
+    I: Key + Clone + core::fmt::Debug,
+{
+    /// Creates a new counter whose value never exceeds `cap`.
+    pub fn new(cap: u64) -> Self {
+        Self {
+            counter: GCounter::default(),
+            cap,
+        }
+    }
+
+    /// Returns the cap applied by `value()`.
+    pub fn cap(&self) -> u64 {
+        self.cap
+    }
+
+    /// Returns the sum of all increments, ignoring the cap.
+    pub fn uncapped_value(&self) -> u64 {
+        self.counter.value()
+    }
+
+    /// Increments the counter by 1 for the given replica.
+    pub fn inc(&mut self, replica: I) {
+        self.counter.inc(replica);
+    }
+
+    /// Adds the given amount to the counter for the given replica.
+    pub fn add(&mut self, amount: u64, replica: I) {
+        self.counter.add(amount, replica);
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for CappedGCounter<String> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<GCounter<String>>(), 0u64..300)
+            .prop_map(|(counter, cap)| CappedGCounter { counter, cap })
+            .boxed()
+    }
+}
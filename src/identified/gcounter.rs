@@ -1,11 +1,33 @@
 use super::Identified;
-use crate::{Apply, Crdt, DeltaSync};
-use std::collections::HashMap;
-use std::hash::Hash;
+use crate::collections::{CapacityHint, Key, Map, Set};
+use crate::crdt::summarize_entries;
+use crate::state_hash::hash_sorted;
+use crate::traits::DeltaCrdt;
+use crate::{Apply, Crdt, CrdtDebug, DeltaSync, MergeReport, TraceEntries, TryApply};
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+#[cfg(feature = "serde")]
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::Hash;
 
 #[cfg(feature = "proptest")]
 use proptest::prelude::*;
 
+/// Error returned by [`GCounter::try_add`] and [`GCounter::try_merge`] when the
+/// result would overflow a `u64`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CounterOverflow;
+
+impl fmt::Display for CounterOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "counter addition overflowed u64")
+    }
+}
+
+impl core::error::Error for CounterOverflow {}
+
 /// A Grow-only Counter (G-Counter) CRDT.
 ///
 /// The counter allows increments, but not decrements. The value of the counter
@@ -13,56 +35,155 @@ use proptest::prelude::*;
 ///
 /// # Type Parameters
 /// * `I`: The type of the Replica ID. Must be `Hash`, `Eq`, `Clone`, and `Debug`.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// With the `serde` feature only `counts` is serialized; the cached sum is
+/// recomputed on deserialize so it can never disagree with the counts.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone)]
 pub struct GCounter<I>
 where
-    I: Hash + Eq,
+    I: Key,
 {
     /// Map of replica IDs to their local counter values.
-    counts: HashMap<I, u64>,
+    counts: Map<I, u64>,
     /// Cached sum of all counts to allow O(1) reads.
+    #[cfg_attr(feature = "serde", serde(skip))]
     cached_value: u64,
+    /// Replicas whose entries changed since the last `reset_delta`.
+    /// Bookkeeping only: it is not part of the CRDT state and is ignored by `==`.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    dirty: Set<I>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, I> serde::Deserialize<'de> for GCounter<I>
+where
+    I: Key + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct Counts<I: Key> {
+            counts: Map<I, u64>,
+        }
+
+        let Counts { counts } = Counts::deserialize(deserializer)?;
+        let cached_value = checked_sum(counts.values()).map_err(serde::de::Error::custom)?;
+        Ok(GCounter {
+            counts,
+            cached_value,
+            dirty: Set::new(),
+        })
+    }
 }
 
-impl<I: Hash + Eq> Default for GCounter<I> {
+impl<I: Key> Default for GCounter<I> {
     fn default() -> Self {
         Self {
-            counts: HashMap::new(),
+            counts: Map::new(),
             cached_value: 0,
+            dirty: Set::new(),
+        }
+    }
+}
+
+impl<I: Key> PartialEq for GCounter<I> {
+    fn eq(&self, other: &Self) -> bool {
+        // `add(0, replica)` leaves an explicit zero entry, which must still
+        // compare equal to a missing one, as it does under `partial_cmp`.
+        if self.counts.len() == other.counts.len() && self.counts == other.counts {
+            return true;
+        }
+        self.dominated_by(other) && other.dominated_by(self)
+    }
+}
+
+impl<I: Key> Eq for GCounter<I> {}
+
+/// Orders counters by dominance, comparing replica entries like
+/// [`VectorClock`](crate::VectorClock): `Less` if every entry is `<=` the
+/// other's, `None` if each counter is ahead on some replica.
+impl<I: Key> PartialOrd for GCounter<I> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        use core::cmp::Ordering;
+        match (self.dominated_by(other), other.dominated_by(self)) {
+            (true, true) => Some(Ordering::Equal),
+            (true, false) => Some(Ordering::Less),
+            (false, true) => Some(Ordering::Greater),
+            (false, false) => None,
         }
     }
 }
 
 impl<I> Crdt for GCounter<I>
 where
-    I: Hash + Eq + Clone + std::fmt::Debug,
+    I: Key + Clone + core::fmt::Debug,
 {
     type Value = u64;
 
+    /// Returns early, without touching the counts, the cache or the delta
+    /// bookkeeping, when `other` is already dominated by this counter.
     fn merge(&mut self, other: &Self) {
-        let mut changed = false;
-        for (replica, &other_count) in &other.counts {
-            let entry = self.counts.entry(replica.clone()).or_insert(0);
-            if other_count > *entry {
-                *entry = other_count;
-                changed = true;
-            }
-        }
-
-        // If we updated any values, we must recompute the cache.
-        if changed {
-            self.cached_value = self.counts.values().sum();
+        if other.leq(self) {
+            return;
         }
+        self.merge_report(other);
     }
 
     fn value(&self) -> Self::Value {
         self.cached_value
     }
+
+    fn leq(&self, other: &Self) -> bool {
+        self.dominated_by(other)
+    }
+
+    fn debug_summary(&self) -> String {
+        self.summarize()
+    }
+}
+
+impl<I> CrdtDebug for GCounter<I>
+where
+    I: Key + core::fmt::Debug,
+{
+    fn summarize(&self) -> String {
+        summarize_entries(
+            &format!("GCounter (total {})", self.cached_value),
+            self.counts
+                .iter()
+                .map(|(replica, count)| format!("{replica:?}: {count}")),
+        )
+    }
+}
+
+/// Formats as `GCounter(total=42, replicas=3)`, counting only replicas with a
+/// non-zero entry.
+impl<I: Key> fmt::Display for GCounter<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let replicas = self.counts.values().filter(|count| **count != 0).count();
+        write!(
+            f,
+            "GCounter(total={}, replicas={})",
+            self.cached_value, replicas
+        )
+    }
+}
+
+impl<I> TraceEntries for GCounter<I>
+where
+    I: Key + core::fmt::Debug,
+{
+    fn trace_entries(&self) -> BTreeMap<String, String> {
+        self.counts
+            .iter()
+            .map(|(replica, count)| (format!("{replica:?}"), format!("{count}")))
+            .collect()
+    }
 }
 
 impl<I> Apply for GCounter<I>
 where
-    I: Hash + Eq + Clone + std::fmt::Debug,
+    I: Key + Clone + core::fmt::Debug,
 {
     type Op = u64;
     type Context = I;
@@ -72,11 +193,22 @@ where
     }
 }
 
+impl<I> TryApply for GCounter<I>
+where
+    I: Key + Clone + core::fmt::Debug,
+{
+    type Error = CounterOverflow;
+
+    fn try_apply(&mut self, op: Self::Op, ctx: Self::Context) -> Result<(), Self::Error> {
+        self.try_add(op, ctx)
+    }
+}
+
 impl<I> DeltaSync for GCounter<I>
 where
-    I: Hash + Eq + Clone + std::fmt::Debug,
+    I: Key + Clone + core::fmt::Debug,
 {
-    type Summary = HashMap<I, u64>;
+    type Summary = Map<I, u64>;
     type Delta = Self;
 
     fn summary(&self) -> Self::Summary {
@@ -91,10 +223,40 @@ where
                 delta.counts.insert(replica.clone(), count);
             }
         }
-        delta.cached_value = delta.counts.values().sum();
+        delta.recompute_cache();
+        delta
+    }
+
+    fn merge_delta(&mut self, delta: &Self) {
+        self.merge(delta);
+    }
+
+    fn merge_diff(&self, other: &Self) -> Self {
+        // The summary is the counts map itself, so borrow it instead of cloning.
+        other.delta_from_summary(&self.counts)
+    }
+}
+
+impl<I> DeltaCrdt for GCounter<I>
+where
+    I: Key + Clone + core::fmt::Debug,
+{
+    type Delta = Self;
+
+    fn delta(&self) -> Self {
+        // Entries raised by merges are included too, so deltas relay transitively.
+        let mut delta = GCounter::new();
+        for replica in &self.dirty {
+            delta.counts.insert(replica.clone(), self.counts[replica]);
+        }
+        delta.recompute_cache();
         delta
     }
 
+    fn reset_delta(&mut self) {
+        self.dirty.clear();
+    }
+
     fn merge_delta(&mut self, delta: &Self) {
         self.merge(delta);
     }
@@ -102,31 +264,218 @@ where
 
 impl<I> Identified for GCounter<I>
 where
-    I: Hash + Eq + Clone + std::fmt::Debug,
+    I: Key + Clone + core::fmt::Debug,
 {
     type ReplicaId = I;
 }
 
 impl<I> GCounter<I>
 where
-    I: Hash + Eq + Clone,
+    I: Key + Clone,
 {
     /// Creates a new GCounter.
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Creates a new GCounter with room for `capacity` replicas before its
+    /// entry map has to grow.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            counts: Map::with_capacity_hint(capacity),
+            ..Self::default()
+        }
+    }
+
+    /// Makes room for at least `additional` more replicas.
+    pub fn reserve(&mut self, additional: usize) {
+        self.counts.reserve_hint(additional);
+    }
+
     /// Increments the counter by 1 for the given replica.
     pub fn inc(&mut self, replica: I) {
         self.add(1, replica);
     }
 
     /// Adds the given amount to the counter for the given replica.
+    ///
+    /// Overflowing a `u64` panics in debug builds and wraps in release builds;
+    /// use [`GCounter::try_add`] when the amount comes from an untrusted source.
     pub fn add(&mut self, amount: u64, replica: I) {
+        self.dirty.insert(replica.clone());
         let entry = self.counts.entry(replica).or_insert(0);
         *entry += amount;
         self.cached_value += amount;
     }
+
+    /// Merges another counter into this one, reporting how many replica entries
+    /// were raised.
+    pub fn merge_report(&mut self, other: &Self) -> MergeReport {
+        self.merge_entries(
+            other
+                .counts
+                .iter()
+                .map(|(replica, &count)| (replica, count)),
+        )
+    }
+
+    /// Merges borrowed `(replica, count)` pairs, e.g. decoded from a wire delta,
+    /// as if they were the entries of another counter.
+    ///
+    /// Equivalent to building a `GCounter` from the pairs and merging it, without
+    /// the intermediate allocation. Only replicas that are actually raised are
+    /// cloned.
+    ///
+    /// The counts always merge, but if their total no longer fits in a `u64`
+    /// the value saturates at `u64::MAX` instead of wrapping. Use
+    /// [`try_merge`](Self::try_merge) to refuse such a merge instead.
+    pub fn merge_entries<'a>(
+        &mut self,
+        entries: impl IntoIterator<Item = (&'a I, u64)>,
+    ) -> MergeReport
+    where
+        I: 'a,
+    {
+        let mut updated = 0;
+        for (replica, other_count) in entries {
+            let current = self.counts.get(replica).copied().unwrap_or(0);
+            if other_count > current {
+                self.counts.insert(replica.clone(), other_count);
+                self.dirty.insert(replica.clone());
+                self.cached_value = self.cached_value.saturating_add(other_count - current);
+                updated += 1;
+            }
+        }
+        MergeReport::from_updated(updated)
+    }
+
+    /// Merges another counter into this one, unless the merged total would
+    /// overflow a `u64`.
+    ///
+    /// On error the counter is left unchanged, as with [`try_add`](Self::try_add).
+    pub fn try_merge(&mut self, other: &Self) -> Result<MergeReport, CounterOverflow> {
+        let mut total = self.cached_value;
+        for (replica, &count) in &other.counts {
+            let raise = count.saturating_sub(self.contribution(replica));
+            total = total.checked_add(raise).ok_or(CounterOverflow)?;
+        }
+        Ok(self.merge_report(other))
+    }
+
+    /// Adds the given amount to the counter for the given replica, unless that
+    /// would overflow either the replica's entry or the total.
+    ///
+    /// On error the counter is left unchanged.
+    pub fn try_add(&mut self, amount: u64, replica: I) -> Result<(), CounterOverflow> {
+        let current = self.counts.get(&replica).copied().unwrap_or(0);
+        let entry = current.checked_add(amount).ok_or(CounterOverflow)?;
+        let total = self
+            .cached_value
+            .checked_add(amount)
+            .ok_or(CounterOverflow)?;
+
+        self.dirty.insert(replica.clone());
+        self.counts.insert(replica, entry);
+        self.cached_value = total;
+        Ok(())
+    }
+}
+
+/// Builds a counter from `(replica, count)` pairs, keeping the largest count
+/// seen for each replica, as if merging one-entry counters.
+impl<I: Key + Clone> FromIterator<(I, u64)> for GCounter<I> {
+    fn from_iter<It: IntoIterator<Item = (I, u64)>>(iter: It) -> Self {
+        let mut counter = Self::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+/// Raises each replica to the given count if it is higher, like
+/// [`GCounter::merge_entries`] with owned pairs.
+impl<I: Key + Clone> Extend<(I, u64)> for GCounter<I> {
+    fn extend<It: IntoIterator<Item = (I, u64)>>(&mut self, iter: It) {
+        let mut updated = false;
+        for (replica, count) in iter {
+            if count > self.contribution(&replica) {
+                self.dirty.insert(replica.clone());
+                self.counts.insert(replica, count);
+                updated = true;
+            }
+        }
+        if updated {
+            self.recompute_cache();
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<I: Key + Ord + serde::Serialize> GCounter<I> {
+    /// Serializes the counts as a JSON array of `[replica, count]` pairs sorted
+    /// by replica ID, so equal counters always produce identical bytes (e.g.
+    /// for content addressing).
+    ///
+    /// Zero entries are left out, matching how `==` treats them. Pairs rather
+    /// than an object, so that IDs which are not strings, such as tuples, work.
+    ///
+    /// # Panics
+    /// Panics only if a replica ID's `Serialize` impl reports an error.
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        let mut sorted: Vec<(&I, &u64)> = self
+            .counts
+            .iter()
+            .filter(|(_, count)| **count != 0)
+            .collect();
+        sorted.sort();
+        serde_json::to_vec(&sorted).expect("replica IDs must serialize as JSON")
+    }
+}
+
+impl<I: Key + Hash + Ord> GCounter<I> {
+    /// Returns a hash of the counter's state that is stable across processes,
+    /// platforms and Rust versions.
+    ///
+    /// Equal counters always hash equal (zero entries are skipped), so replicas
+    /// can compare hashes before deciding whether to ship their full state.
+    pub fn state_hash(&self) -> u64 {
+        hash_sorted(self.counts.iter().filter(|(_, count)| **count != 0))
+    }
+}
+
+impl<I: Key> GCounter<I> {
+    /// Returns true if every replica's count is `<=` its count in `other`.
+    fn dominated_by(&self, other: &Self) -> bool {
+        self.counts
+            .iter()
+            .all(|(replica, &count)| count <= other.contribution(replica))
+    }
+
+    /// Returns the amount contributed by `replica`, or 0 if it never incremented.
+    pub fn contribution(&self, replica: &I) -> u64 {
+        self.counts.get(replica).copied().unwrap_or(0)
+    }
+
+    /// Iterates over each replica's contribution to the total, in no particular order.
+    pub fn contributions(&self) -> impl Iterator<Item = (&I, u64)> {
+        self.counts.iter().map(|(replica, &count)| (replica, count))
+    }
+
+    /// Recomputes the cached sum from the per-replica counts.
+    ///
+    /// Deserialization already does this; call it yourself after loading a
+    /// counter through any other path that could leave the cache stale. A sum
+    /// that overflows a `u64` saturates at `u64::MAX`, as in a merge.
+    pub fn recompute_cache(&mut self) {
+        self.cached_value = checked_sum(self.counts.values()).unwrap_or(u64::MAX);
+    }
+}
+
+/// Sums counts, failing instead of wrapping if the total overflows a `u64`.
+fn checked_sum<'a>(counts: impl IntoIterator<Item = &'a u64>) -> Result<u64, CounterOverflow> {
+    counts
+        .into_iter()
+        .try_fold(0u64, |total, &count| total.checked_add(count))
+        .ok_or(CounterOverflow)
 }
 
 #[cfg(feature = "proptest")]
@@ -135,14 +484,6 @@ impl Arbitrary for GCounter<String> {
     type Strategy = BoxedStrategy<Self>;
 
     fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
-        proptest::collection::hash_map("[a-c]".prop_map(String::from), 1u64..100, 0..4)
-            .prop_map(|counts| {
-                let mut counter = GCounter::new();
-                for (replica, amount) in counts {
-                    counter.add(amount, replica);
-                }
-                counter
-            })
-            .boxed()
+        crate::arbitrary::gcounter_strategy("[a-c]".prop_map(String::from), 0..4)
     }
 }
@@ -1,9 +1,13 @@
-#[cfg(feature = "std")]
+pub mod capped;
 pub mod gcounter;
+#[cfg(feature = "std")]
+pub mod pncounter;
+pub mod resettable;
+pub mod threshold;
 
 use crate::DeltaSync;
+use crate::collections::Key;
 use core::fmt::Debug;
-use core::hash::Hash;
 
 /// An Identified CRDT uses static replica IDs to partition state.
 ///
@@ -16,5 +20,5 @@ use core::hash::Hash;
 /// `DeltaSync` impls for composed structs. End users rarely need to
 /// reference it directly.
 pub trait Identified: DeltaSync {
-    type ReplicaId: Hash + Eq + Clone + Debug;
+    type ReplicaId: Key + Clone + Debug;
 }
@@ -0,0 +1,137 @@
+use super::Identified;
+use super::gcounter::GCounter;
+use crate::{Apply, Crdt, DeltaSync};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[cfg(feature = "proptest")]
+use proptest::prelude::*;
+
+/// A Positive-Negative Counter (PN-Counter) CRDT.
+///
+/// The counter supports both increments and decrements by keeping two
+/// grow-only counters: one for increments and one for decrements. The value
+/// of the counter is the difference between the two.
+///
+/// # Type Parameters
+/// * `I`: The type of the Replica ID. Must be `Hash`, `Eq`, `Clone`, and `Debug`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PNCounter<I>
+where
+    I: Hash + Eq,
+{
+    /// Increments, tracked per replica.
+    positive: GCounter<I>,
+    /// Decrements, tracked per replica.
+    negative: GCounter<I>,
+}
+
+impl<I: Hash + Eq> Default for PNCounter<I> {
+    fn default() -> Self {
+        Self {
+            positive: GCounter::default(),
+            negative: GCounter::default(),
+        }
+    }
+}
+
+impl<I> Crdt for PNCounter<I>
+where
+    I: Hash + Eq + Clone + std::fmt::Debug,
+{
+    type Value = i64;
+
+    fn merge(&mut self, other: &Self) {
+        // Each half is a G-Counter, so merging takes the per-replica max on both.
+        self.positive.merge(&other.positive);
+        self.negative.merge(&other.negative);
+    }
+
+    fn value(&self) -> Self::Value {
+        self.positive.value() as i64 - self.negative.value() as i64
+    }
+}
+
+impl<I> Apply for PNCounter<I>
+where
+    I: Hash + Eq + Clone + std::fmt::Debug,
+{
+    type Op = i64;
+    type Context = I;
+
+    fn apply(&mut self, op: Self::Op, ctx: Self::Context) {
+        self.add(op, ctx);
+    }
+}
+
+impl<I> DeltaSync for PNCounter<I>
+where
+    I: Hash + Eq + Clone + std::fmt::Debug,
+{
+    /// One version vector per half: `(increments, decrements)`.
+    type Summary = (HashMap<I, u64>, HashMap<I, u64>);
+    type Delta = Self;
+
+    fn summary(&self) -> Self::Summary {
+        (self.positive.summary(), self.negative.summary())
+    }
+
+    fn delta_from_summary(&self, remote_summary: &Self::Summary) -> Self {
+        PNCounter {
+            positive: self.positive.delta_from_summary(&remote_summary.0),
+            negative: self.negative.delta_from_summary(&remote_summary.1),
+        }
+    }
+
+    fn merge_delta(&mut self, delta: &Self) {
+        self.merge(delta);
+    }
+}
+
+impl<I> Identified for PNCounter<I>
+where
+    I: Hash + Eq + Clone + std::fmt::Debug,
+{
+    type ReplicaId = I;
+}
+
+impl<I> PNCounter<I>
+where
+    I: Hash + Eq + Clone,
+{
+    /// Creates a new PNCounter.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Increments the counter by 1 for the given replica.
+    pub fn inc(&mut self, replica: I) {
+        self.positive.add(1, replica);
+    }
+
+    /// Decrements the counter by 1 for the given replica.
+    pub fn dec(&mut self, replica: I) {
+        self.negative.add(1, replica);
+    }
+
+    /// Adds the given (possibly negative) amount to the counter for the given replica.
+    pub fn add(&mut self, delta: i64, replica: I) {
+        if delta >= 0 {
+            self.positive.add(delta as u64, replica);
+        } else {
+            self.negative.add(delta.unsigned_abs(), replica);
+        }
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for PNCounter<String> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<GCounter<String>>(), any::<GCounter<String>>())
+            .prop_map(|(positive, negative)| PNCounter { positive, negative })
+            .boxed()
+    }
+}
@@ -0,0 +1,160 @@
+use crate::collections::{Key, Map};
+use crate::{Apply, CausalContext, Crdt};
+use core::fmt::Debug;
+
+#[cfg(feature = "proptest")]
+use alloc::string::String;
+#[cfg(feature = "proptest")]
+use proptest::prelude::*;
+#[cfg(feature = "proptest")]
+use proptest::sample::Index;
+
+/// A grow-only counter that can be reset to zero.
+///
+/// Every increment is recorded under its own dot `(replica, sequence)`, like an
+/// element of an [`ORSet`](crate::ORSet). A reset discards the increments the
+/// resetting replica has observed, so an increment concurrent with a reset
+/// survives it (increment-wins), while everything the reset saw is gone on
+/// every replica once they have synced.
+///
+/// Discarded increments leave no tombstones, only an entry in the counter's
+/// [`CausalContext`]; live increments are kept individually until the next
+/// reset, since coalescing them would let a concurrent reset's survivors carry
+/// amounts the reset had already cleared.
+///
+/// # Type Parameters
+/// * `I`: The type of the Replica ID. Must be `Hash`, `Eq`, `Clone`, and `Debug`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResettableCounter<I>
+where
+    I: Key,
+{
+    /// The amount of each live increment, by its dot.
+    increments: Map<(I, u64), u64>,
+    /// Every dot observed, live or reset; also used to mint fresh dots.
+    context: CausalContext<I>,
+}
+
+/// An operation on a [`ResettableCounter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResettableCounterOp {
+    Add(u64),
+    Reset,
+}
+
+impl<I: Key> Default for ResettableCounter<I> {
+    fn default() -> Self {
+        Self {
+            increments: Map::new(),
+            context: CausalContext::default(),
+        }
+    }
+}
+
+impl<I> Crdt for ResettableCounter<I>
+where
+    I: Key + Clone + Debug,
+{
+    type Value = u64;
+
+    fn merge(&mut self, other: &Self) {
+        // An increment live on one side only was reset by the other side if the
+        // other side has seen its dot.
+        self.increments
+            .retain(|dot, _| other.increments.contains_key(dot) || !other.context.contains(dot));
+        for (dot, &amount) in &other.increments {
+            // Dots are unique, so an increment live on both sides has the same
+            // amount and is left as is.
+            if !self.increments.contains_key(dot) && !self.context.contains(dot) {
+                self.increments.insert(dot.clone(), amount);
+            }
+        }
+        self.context.merge(&other.context);
+    }
+
+    fn value(&self) -> Self::Value {
+        self.increments.values().sum()
+    }
+}
+
+impl<I> Apply for ResettableCounter<I>
+where
+    I: Key + Clone + Debug,
+{
+    type Op = ResettableCounterOp;
+    type Context = I;
+
+    fn apply(&mut self, op: Self::Op, ctx: Self::Context) {
+        match op {
+            ResettableCounterOp::Add(amount) => {
+                self.add(amount, ctx);
+            }
+            ResettableCounterOp::Reset => self.reset(),
+        }
+    }
+}
+
+impl<I> ResettableCounter<I>
+where
+    I: Key + Clone,
+{
+    /// Creates a new counter at zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Increments the counter by 1 for the given replica, returning the new dot.
+    pub fn inc(&mut self, replica: I) -> (I, u64) {
+        self.add(1, replica)
+    }
+
+    /// Adds the given amount to the counter for the given replica, returning
+    /// the new dot.
+    pub fn add(&mut self, amount: u64, replica: I) -> (I, u64) {
+        let dot = self.context.next_dot(replica);
+        self.increments.insert(dot.clone(), amount);
+        dot
+    }
+
+    /// Resets the counter to zero by discarding every increment observed so far.
+    ///
+    /// Concurrent increments that this replica has not yet seen are unaffected.
+    pub fn reset(&mut self) {
+        self.increments.clear();
+    }
+
+    /// Returns the causal context of every dot this replica has observed.
+    pub fn context(&self) -> &CausalContext<I> {
+        &self.context
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for ResettableCounter<String> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        // Replay a short history of increments (`Some`) and resets (`None`).
+        (
+            crate::arbitrary::replica_names_strategy(),
+            proptest::collection::vec(
+                (any::<Index>(), proptest::option::weighted(0.8, 1u64..10)),
+                0..8,
+            ),
+        )
+            .prop_map(|(replicas, ops)| {
+                let mut counter = ResettableCounter::new();
+                for (replica, amount) in ops {
+                    match amount {
+                        Some(amount) => {
+                            counter.add(amount, replica.get(&replicas).clone());
+                        }
+                        None => counter.reset(),
+                    }
+                }
+                counter
+            })
+            .boxed()
+    }
+}
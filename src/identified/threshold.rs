@@ -0,0 +1,143 @@
+use super::gcounter::GCounter;
+use crate::collections::Key;
+use crate::{Apply, Crdt};
+
+#[cfg(feature = "proptest")]
+use proptest::prelude::*;
+
+/// A Grow-only Counter with a sticky flag recording that it reached a threshold.
+///
+/// Suits rollouts and quotas that should trigger exactly once: the flag turns
+/// on as soon as the counter's value reaches the threshold, on whichever
+/// replica sees it first, and merging ORs the flags so it never turns off
+/// again, even if a later state somehow reports a lower count.
+///
+/// Every replica should be created with the same threshold. Merging keeps the
+/// lower of the two thresholds, so a default counter, whose threshold is
+/// `u64::MAX`, adopts the threshold of the first state merged into it.
+///
+/// # Type Parameters
+/// * `I`: The type of the Replica ID. Must be `Hash`, `Eq`, `Clone`, and `Debug`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThresholdCounter<I>
+where
+    I: Key,
+{
+    /// The per-replica counts.
+    counter: GCounter<I>,
+    /// The value at which the flag turns on.
+    threshold: u64,
+    /// True once any replica has seen the value reach the threshold.
+    reached: bool,
+}
+
+impl<I: Key> Default for ThresholdCounter<I> {
+    /// A counter with no threshold yet, i.e. a threshold of `u64::MAX`.
+    fn default() -> Self {
+        Self {
+            counter: GCounter::default(),
+            threshold: u64::MAX,
+            reached: false,
+        }
+    }
+}
+
+impl<I> Crdt for ThresholdCounter<I>
+where
+    I: Key + Clone + core::fmt::Debug,
+{
+    /// The counter's value and whether the threshold has been reached.
+    type Value = (u64, bool);
+
+    fn merge(&mut self, other: &Self) {
+        self.counter.merge(&other.counter);
+        self.threshold = self.threshold.min(other.threshold);
+        self.reached |= other.reached;
+        self.check_threshold();
+    }
+
+    fn value(&self) -> Self::Value {
+        (self.counter.value(), self.reached)
+    }
+
+    fn leq(&self, other: &Self) -> bool {
+        self.counter.leq(&other.counter)
+            && self.threshold >= other.threshold
+            && (!self.reached || other.reached)
+    }
+}
+
+impl<I> Apply for ThresholdCounter<I>
+where
+    I: Key + Clone + core::fmt::Debug,
+{
+    type Op = u64;
+    type Context = I;
+
+    fn apply(&mut self, op: Self::Op, ctx: Self::Context) {
+        self.add(op, ctx);
+    }
+}
+
+impl<I> ThresholdCounter<I>
+where
+    I: Key + Clone + core::fmt::Debug,
+{
+    /// Creates a counter whose flag turns on once its value reaches `threshold`.
+    pub fn new(threshold: u64) -> Self {
+        let mut counter = Self {
+            threshold,
+            ..Self::default()
+        };
+        counter.check_threshold();
+        counter
+    }
+
+    /// Returns the value at which the flag turns on.
+    pub fn threshold(&self) -> u64 {
+        self.threshold
+    }
+
+    /// Returns true once the threshold has been reached on any replica.
+    pub fn reached(&self) -> bool {
+        self.reached
+    }
+
+    /// Increments the counter by 1 for the given replica.
+    pub fn inc(&mut self, replica: I) {
+        self.add(1, replica);
+    }
+
+    /// Adds the given amount to the counter for the given replica.
+    pub fn add(&mut self, amount: u64, replica: I) {
+        self.counter.add(amount, replica);
+        self.check_threshold();
+    }
+
+    fn check_threshold(&mut self) {
+        self.reached |= self.counter.value() >= self.threshold;
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for ThresholdCounter<String> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        // All replicas share a threshold that is reached about half the time.
+        // The flag may also be set below it, as after a lost update.
+        (any::<GCounter<String>>(), any::<bool>())
+            .prop_map(|(counter, reached)| {
+                let mut counter = ThresholdCounter {
+                    counter,
+                    threshold: 100,
+                    reached,
+                };
+                counter.check_threshold();
+                counter
+            })
+            .boxed()
+    }
+}
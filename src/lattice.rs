@@ -0,0 +1,86 @@
+use crate::{Bottom, Crdt};
+use core::fmt::Debug;
+
+#[cfg(feature = "proptest")]
+use proptest::prelude::*;
+
+/// A join-semilattice: a set of states with a least upper bound (`join`) and a
+/// least element (`bottom`).
+///
+/// This is the algebra behind [`Crdt`] without the mutation and the `value`
+/// projection, which makes it convenient for generic lattice algorithms and
+/// recursive combinators. `join` must be idempotent, commutative and
+/// associative, and `bottom` must be its identity.
+///
+/// Every [`Crdt`] with a [`Bottom`] is a `Semilattice` through a blanket impl. To go the other
+/// way, wrap a `Semilattice` in [`LatticeCrdt`].
+pub trait Semilattice: Sized {
+    /// Returns the least upper bound of `self` and `other`.
+    fn join(&self, other: &Self) -> Self;
+
+    /// Returns the least element, which every `join` leaves unchanged.
+    fn bottom() -> Self;
+}
+
+impl<T: Bottom> Semilattice for T {
+    fn join(&self, other: &Self) -> Self {
+        self.merged(other)
+    }
+
+    fn bottom() -> Self {
+        T::init()
+    }
+}
+
+/// Adapts a [`Semilattice`] into a [`Crdt`] whose value is the lattice state itself.
+///
+/// A blanket impl in this direction would overlap with the one above, so the
+/// adapter is an explicit newtype instead.
+///
+/// # Type Parameters
+/// * `L`: The lattice type. Must be `Clone`, `Debug`, and `PartialEq`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LatticeCrdt<L>(pub L);
+
+impl<L: Semilattice> Default for LatticeCrdt<L> {
+    fn default() -> Self {
+        Self(L::bottom())
+    }
+}
+
+impl<L> Crdt for LatticeCrdt<L>
+where
+    L: Semilattice + Clone + Debug + PartialEq,
+{
+    type Value = L;
+
+    fn merge(&mut self, other: &Self) {
+        self.0 = self.0.join(&other.0);
+    }
+
+    fn value(&self) -> Self::Value {
+        self.0.clone()
+    }
+}
+
+impl<L> LatticeCrdt<L> {
+    /// Unwraps the lattice state.
+    pub fn into_inner(self) -> L {
+        self.0
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl<L> Arbitrary for LatticeCrdt<L>
+where
+    L: Arbitrary + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<L>().prop_map(LatticeCrdt).boxed()
+    }
+}
@@ -2,28 +2,93 @@
 
 extern crate alloc;
 
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
+#[cfg(feature = "futures")]
+pub mod async_ext;
 pub mod causal;
+pub mod clock;
+pub mod collections;
 pub mod crdt;
+pub mod debug;
 pub mod delta_sync;
+pub mod epoch;
+pub mod graph;
 pub mod identified;
+pub mod lattice;
+pub mod map;
 pub mod primitive;
+pub mod register;
 pub mod replica;
+pub mod sequence;
+pub mod size;
+mod state_hash;
 pub mod traits;
+#[cfg(feature = "bincode")]
+pub mod wire;
 
+#[cfg(feature = "futures")]
+pub use crate::async_ext::MergeStreamExt;
 pub use crate::causal::Causal;
-pub use crate::causal::itc::{ItcClock, ItcId, ItcReplica};
+pub use crate::causal::buffer::CausalBuffer;
+pub use crate::causal::context::CausalContext;
+pub use crate::causal::itc::{DecodeError, IdOverlap, ItcClock, ItcId, ItcReplica, ItcStamp};
+pub use crate::causal::oplog::OpLog;
+pub use crate::causal::vector::{CompactDecodeError, VectorClock, stable_frontier};
+#[cfg(target_has_atomic = "64")]
+pub use crate::clock::LamportClock;
 #[cfg(feature = "std")]
-pub use crate::causal::vector::VectorClock;
-pub use crate::crdt::Crdt;
+pub use crate::clock::SystemClock;
+pub use crate::clock::{Clock, Hlc, HlcTimestamp};
+pub use crate::crdt::{
+    Bottom, Crdt, CrdtDebug, FieldVisitor, Fields, MergeReport, join_all, merge_all,
+};
+pub use crate::debug::traced::{ChangeEvent, TraceEntries, TracedCrdt};
 pub use crate::delta_sync::DeltaSync;
+pub use crate::epoch::Epoch;
+pub use crate::graph::ggraph::{GGraph, GGraphOp};
 pub use crate::identified::Identified;
+pub use crate::identified::capped::CappedGCounter;
+pub use crate::identified::gcounter::{CounterOverflow, GCounter};
+#[cfg(feature = "std")]
+pub use crate::identified::pncounter::PNCounter;
+pub use crate::identified::resettable::{ResettableCounter, ResettableCounterOp};
+pub use crate::identified::threshold::ThresholdCounter;
+pub use crate::lattice::{LatticeCrdt, Semilattice};
 #[cfg(feature = "std")]
-pub use crate::identified::gcounter::GCounter;
+pub use crate::map::gmap::GMap;
 #[cfg(feature = "std")]
+pub use crate::map::ormap::ORMap;
 pub use crate::primitive::gset::GSet;
+pub use crate::primitive::lwwset::{LWWSet, LWWSetOp};
+pub use crate::primitive::ordered_gset::OrderedGSet;
+#[cfg(feature = "std")]
+pub use crate::primitive::orset::{ORSet, ORSetOp};
+#[cfg(feature = "std")]
+pub use crate::primitive::pnset::{PNSet, PNSetOp};
+#[cfg(feature = "std")]
+pub use crate::primitive::twopset::{TwoPSet, TwoPSetError, TwoPSetOp};
+pub use crate::register::dot::DotRegister;
+pub use crate::register::flag::{DisableWinsFlag, EnableWinsFlag};
+pub use crate::register::fnreg::FnRegister;
+pub use crate::register::lww::LWWRegister;
+pub use crate::register::minmax::{Bounded, Max, Min};
+#[cfg(feature = "std")]
+pub use crate::register::mv::MVRegister;
+#[cfg(target_has_atomic = "64")]
+pub use crate::replica::CounterReplica;
 pub use crate::replica::Replica;
-pub use crate::traits::Apply;
+#[cfg(feature = "uuid")]
+pub use crate::replica::UuidReplica;
+pub use crate::sequence::growlog::GrowLog;
+pub use crate::sequence::rga::{ElementId, Rga, RgaOp};
+pub use crate::size::{EstimateSize, SizeHint};
+pub use crate::traits::{Apply, ApplyChecked, DeltaCrdt, IdempotentApply, TryApply};
+#[cfg(feature = "bincode")]
+pub use crate::wire::{Wire, WireError};
 
+#[cfg(feature = "derive")]
+pub use crdt_derive::Apply;
 #[cfg(feature = "derive")]
 pub use crdt_derive::Crdt;
 #[cfg(feature = "derive")]
@@ -34,3 +99,53 @@ pub mod properties {
     pub use crate::crdt::checks::*;
     pub use crate::delta_sync::checks::*;
 }
+
+/// Asserts at compile time that a type implements [`Crdt`], and with the
+/// `proptest` feature also generates a `#[test]` running
+/// [`check_eventual_consistency`](crate::properties::check_eventual_consistency) on it.
+///
+/// The test is named after the type. Pass a name as the second argument for
+/// generic types, or for tuple and unit structs, whose constructor already
+/// takes that name:
+///
+/// ```ignore
+/// assert_crdt!(Stats);
+/// assert_crdt!(Pair<u8>, pair_is_crdt);
+/// ```
+///
+/// The test needs the type to implement [`Bottom`] and `Arbitrary`; the
+/// compile-time check only needs [`Crdt`].
+#[macro_export]
+macro_rules! assert_crdt {
+    ($ty:ident) => {
+        $crate::assert_crdt!($ty, $ty);
+    };
+    ($ty:ty, $test:ident) => {
+        const _: () = {
+            const fn assert_crdt<T: $crate::Crdt>() {}
+            assert_crdt::<$ty>();
+        };
+        $crate::__assert_crdt_test!($ty, $test);
+    };
+}
+
+#[cfg(feature = "proptest")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_crdt_test {
+    ($ty:ty, $test:ident) => {
+        #[cfg(test)]
+        #[test]
+        #[allow(non_snake_case)]
+        fn $test() {
+            $crate::properties::check_eventual_consistency::<$ty>();
+        }
+    };
+}
+
+#[cfg(not(feature = "proptest"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_crdt_test {
+    ($ty:ty, $test:ident) => {};
+}
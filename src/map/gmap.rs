@@ -0,0 +1,166 @@
+use crate::{Apply, Bottom, Crdt, IdempotentApply};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[cfg(feature = "proptest")]
+use proptest::prelude::*;
+
+/// A Grow-only Map (G-Map) of nested CRDTs.
+///
+/// Keys can be added but never removed. Each value is itself a CRDT, so merging
+/// two maps inserts keys missing on one side and recursively merges the values
+/// of keys present on both.
+///
+/// # Type Parameters
+/// * `K`: The type of the keys. Must implement `Hash`, `Eq`, `Clone`, and `Debug`.
+/// * `V`: The type of the values. Must implement `Crdt`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GMap<K, V>
+where
+    K: Hash + Eq,
+{
+    entries: HashMap<K, V>,
+}
+
+impl<K: Hash + Eq, V> Default for GMap<K, V> {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+}
+
+impl<K, V> Crdt for GMap<K, V>
+where
+    K: Hash + Eq + Clone + std::fmt::Debug,
+    V: Crdt,
+{
+    type Value = HashMap<K, V::Value>;
+
+    fn merge(&mut self, other: &Self) {
+        for (key, other_value) in &other.entries {
+            match self.entries.get_mut(key) {
+                Some(value) => value.merge(other_value),
+                None => {
+                    self.entries.insert(key.clone(), other_value.clone());
+                }
+            }
+        }
+    }
+
+    fn value(&self) -> Self::Value {
+        self.entries
+            .iter()
+            .map(|(key, value)| (key.clone(), value.value()))
+            .collect()
+    }
+}
+
+impl<K, V> Apply for GMap<K, V>
+where
+    K: Hash + Eq + Clone + std::fmt::Debug,
+    V: Bottom + Apply,
+{
+    type Op = (K, V::Op); // The key to update and the nested operation
+    type Context = V::Context;
+
+    fn apply(&mut self, op: Self::Op, ctx: Self::Context) {
+        let (key, inner_op) = op;
+        self.entry_or_default(key).apply(inner_op, ctx);
+    }
+}
+
+impl<K, V> IdempotentApply for GMap<K, V>
+where
+    K: Hash + Eq + Clone + std::fmt::Debug,
+    V: Bottom + IdempotentApply,
+{
+}
+
+impl<K, V> GMap<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Creates a new, empty G-Map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a mutable reference to the value for `key`, inserting the
+    /// bottom value if the key is absent.
+    pub fn entry_or_default(&mut self, key: K) -> &mut V
+    where
+        V: Bottom,
+    {
+        self.entries.entry(key).or_insert_with(V::init)
+    }
+
+    /// Returns a reference to the value for `key`, if present.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.entries.get(key)
+    }
+
+    /// Returns true if the map contains the key.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// Returns the number of keys in the map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the map is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<K, V> GMap<K, V>
+where
+    K: Hash + Eq + Ord + serde::Serialize,
+    V: serde::Serialize,
+{
+    /// Serializes the map as a JSON array of `[key, value]` pairs sorted by key,
+    /// so equal maps always produce identical bytes (e.g. for content
+    /// addressing). Pairs rather than an object, so that keys which are not
+    /// strings, such as tuples, work.
+    ///
+    /// Values go through `serde_json::Value`, whose objects keep their keys
+    /// sorted, so nested map-backed CRDTs such as `GCounter` are canonical too
+    /// (as long as serde_json's `preserve_order` feature is not enabled).
+    ///
+    /// # Panics
+    /// Panics if a value cannot be represented as JSON, e.g. a nested map whose
+    /// keys are not strings, or if a `Serialize` impl reports an error.
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        let mut sorted: Vec<(&K, serde_json::Value)> = self
+            .entries
+            .iter()
+            .map(|(key, value)| {
+                let value = serde_json::to_value(value).expect("values must serialize as JSON");
+                (key, value)
+            })
+            .collect();
+        sorted.sort_by(|a, b| a.0.cmp(b.0));
+        serde_json::to_vec(&sorted).expect("keys must serialize as JSON")
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl<V> Arbitrary for GMap<String, V>
+where
+    V: Crdt + Arbitrary + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::collection::hash_map("[a-c]".prop_map(String::from), any::<V>(), 0..4)
+            .prop_map(|entries| GMap { entries })
+            .boxed()
+    }
+}
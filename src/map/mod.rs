@@ -0,0 +1,4 @@
+#[cfg(feature = "std")]
+pub mod gmap;
+#[cfg(feature = "std")]
+pub mod ormap;
@@ -0,0 +1,210 @@
+use crate::{Bottom, CausalContext, Crdt};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[cfg(feature = "proptest")]
+use proptest::prelude::*;
+#[cfg(feature = "proptest")]
+use proptest::sample::Index;
+
+/// An Observed-Remove Map (OR-Map) of nested CRDTs.
+///
+/// Keys can be added and removed. Each update of a key mints a fresh dot
+/// `(replica, sequence)` and stores the key's whole new value under it,
+/// superseding the versions the updater had observed. Removing a key discards
+/// the versions the remover has observed, so:
+///
+/// * an update concurrent with a remove survives it (update-wins), and the key
+///   comes back with the value the updater saw, including anything written
+///   before the remove;
+/// * an update made after observing a remove starts again from the default
+///   (bottom) value.
+///
+/// Concurrent versions of a key are kept side by side and merged on read. As in
+/// [`ORSet`](crate::ORSet), removed versions leave no tombstones behind, only an
+/// entry in the map's [`CausalContext`].
+///
+/// # Type Parameters
+/// * `K`: The type of the keys. Must implement `Hash`, `Eq`, `Clone`, and `Debug`.
+/// * `V`: The type of the values. Must implement `Crdt`.
+/// * `I`: The type of the Replica ID. Must be `Hash`, `Eq`, `Clone`, and `Debug`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ORMap<K, V, I>
+where
+    K: Hash + Eq,
+    I: Hash + Eq + Clone,
+{
+    /// The live versions of each present key, by the dot that wrote them.
+    entries: HashMap<K, HashMap<(I, u64), V>>,
+    /// Every dot observed, live or removed; also used to mint fresh dots.
+    context: CausalContext<I>,
+}
+
+impl<K: Hash + Eq, V, I: Hash + Eq + Clone> Default for ORMap<K, V, I> {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+            context: CausalContext::default(),
+        }
+    }
+}
+
+impl<K, V, I> Crdt for ORMap<K, V, I>
+where
+    K: Hash + Eq + Clone + std::fmt::Debug,
+    V: Bottom,
+    I: Hash + Eq + Clone + std::fmt::Debug,
+{
+    type Value = HashMap<K, V::Value>;
+
+    fn merge(&mut self, other: &Self) {
+        // A version live on one side only was removed or superseded by the
+        // other side if the other side has seen its dot.
+        let empty = HashMap::new();
+        for (key, versions) in self.entries.iter_mut() {
+            let other_versions = other.entries.get(key).unwrap_or(&empty);
+            versions
+                .retain(|dot, _| other_versions.contains_key(dot) || !other.context.contains(dot));
+        }
+        for (key, other_versions) in &other.entries {
+            let versions = self.entries.entry(key.clone()).or_default();
+            for (dot, other_value) in other_versions {
+                // Dots are unique, so a version live on both sides has the same
+                // value and is left as is.
+                if !versions.contains_key(dot) && !self.context.contains(dot) {
+                    versions.insert(dot.clone(), other_value.clone());
+                }
+            }
+        }
+        self.entries.retain(|_, versions| !versions.is_empty());
+        self.context.merge(&other.context);
+    }
+
+    fn value(&self) -> Self::Value {
+        self.entries
+            .iter()
+            .map(|(key, versions)| (key.clone(), join_versions(versions).value()))
+            .collect()
+    }
+}
+
+impl<K, V, I> ORMap<K, V, I>
+where
+    K: Hash + Eq,
+    V: Bottom,
+    I: Hash + Eq + Clone,
+{
+    /// Creates a new, empty OR-Map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates the value for `key` on behalf of the given replica, starting
+    /// from the bottom value if the key is absent.
+    ///
+    /// Returns the dot that now holds the key's value.
+    pub fn update(&mut self, key: K, replica: I, f: impl FnOnce(&mut V)) -> (I, u64) {
+        let mut value = self
+            .entries
+            .get(&key)
+            .map(join_versions)
+            .unwrap_or_else(V::init);
+        f(&mut value);
+
+        let dot = self.context.next_dot(replica);
+        self.entries
+            .insert(key, HashMap::from([(dot.clone(), value)]));
+        dot
+    }
+
+    /// Removes a key on behalf of the given replica by discarding every
+    /// version of it observed so far.
+    ///
+    /// Concurrent updates of the key that this replica has not yet seen are
+    /// unaffected and bring the key back when merged.
+    ///
+    /// The remove is recorded as an event of `replica` in the causal context,
+    /// so a state that has seen it is strictly newer than one that has not,
+    /// even when the key was already absent.
+    ///
+    /// Returns the dot minted for the remove.
+    pub fn remove(&mut self, key: &K, replica: I) -> (I, u64) {
+        self.entries.remove(key);
+        self.context.next_dot(replica)
+    }
+
+    /// Returns the value for `key`, merged across its concurrent versions.
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.entries.get(key).map(join_versions)
+    }
+
+    /// Returns true if the map contains the key.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// Returns the number of keys in the map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the map is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the causal context of every dot this replica has observed.
+    pub fn context(&self) -> &CausalContext<I> {
+        &self.context
+    }
+}
+
+/// Merges the concurrent versions of a key into one value.
+fn join_versions<I, V: Bottom>(versions: &HashMap<(I, u64), V>) -> V {
+    let mut value = V::init();
+    for version in versions.values() {
+        value.merge(version);
+    }
+    value
+}
+
+#[cfg(feature = "proptest")]
+impl<V> Arbitrary for ORMap<String, V, String>
+where
+    V: Bottom + Arbitrary + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        // Replay a short history of updates (`Some`) and removes (`None`).
+        (
+            crate::arbitrary::replica_names_strategy(),
+            proptest::collection::vec(
+                (
+                    any::<Index>(),
+                    "[a-c]".prop_map(String::from),
+                    proptest::option::of(any::<V>()),
+                ),
+                0..6,
+            ),
+        )
+            .prop_map(|(replicas, ops)| {
+                let mut map = ORMap::new();
+                for (replica, key, update) in ops {
+                    match update {
+                        Some(delta) => {
+                            map.update(key, replica.get(&replicas).clone(), |value: &mut V| {
+                                value.merge(&delta)
+                            });
+                        }
+                        None => {
+                            map.remove(&key, replica.get(&replicas).clone());
+                        }
+                    }
+                }
+                map
+            })
+            .boxed()
+    }
+}
@@ -1,6 +1,12 @@
-use crate::{Apply, Crdt, DeltaSync};
-use std::collections::HashSet;
-use std::hash::Hash;
+use crate::collections::{CapacityHint, Key, Set, set};
+use crate::crdt::{summarize_entries, write_sorted_entries};
+use crate::state_hash::hash_sorted;
+use crate::{Apply, Crdt, CrdtDebug, DeltaSync, IdempotentApply, MergeReport, TraceEntries};
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
+use core::hash::Hash;
 
 #[cfg(feature = "proptest")]
 use proptest::prelude::*;
@@ -12,38 +18,89 @@ use proptest::prelude::*;
 ///
 /// # Type Parameters
 /// * `T`: The type of elements in the set. Must implement `Hash`, `Eq`, `Clone`, and `Debug`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct GSet<T>(HashSet<T>)
+pub struct GSet<T>(Set<T>)
 where
-    T: Hash + Eq;
+    T: Key;
 
-impl<T: Hash + Eq> Default for GSet<T> {
+impl<T: Key> Default for GSet<T> {
     fn default() -> Self {
-        Self(HashSet::new())
+        Self(Set::new())
     }
 }
 
 impl<T> Crdt for GSet<T>
 where
-    T: Hash + Eq + Clone + std::fmt::Debug,
+    T: Key + Clone + core::fmt::Debug,
 {
-    type Value = HashSet<T>;
+    type Value = Set<T>;
 
+    /// Returns early, without cloning any element, when `other` is a subset of
+    /// this set, e.g. when a replica re-sends state that was already merged.
+    /// For `String` elements this roughly halves the cost of such a merge.
     fn merge(&mut self, other: &Self) {
-        // G-Set merge is set union
-        for item in &other.0 {
-            self.0.insert(item.clone());
+        if other.leq(self) {
+            return;
         }
+        self.merge_report(other);
     }
 
     fn value(&self) -> Self::Value {
         self.0.clone()
     }
+
+    fn value_ref(&self) -> Option<&Self::Value> {
+        Some(&self.0)
+    }
+
+    fn leq(&self, other: &Self) -> bool {
+        self.0.is_subset(&other.0)
+    }
+
+    fn debug_summary(&self) -> String {
+        self.summarize()
+    }
+}
+
+impl<T> CrdtDebug for GSet<T>
+where
+    T: Key + core::fmt::Debug,
+{
+    fn summarize(&self) -> String {
+        summarize_entries(
+            &format!("GSet ({} elements)", self.0.len()),
+            self.0.iter().map(|element| format!("{element:?}")),
+        )
+    }
+}
+
+/// Formats as `GSet{a, b, c}`, with elements sorted by their rendering.
+impl<T> fmt::Display for GSet<T>
+where
+    T: Key + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_sorted_entries(f, "GSet", self.0.iter().map(|element| format!("{element}")))
+    }
+}
+
+impl<T> TraceEntries for GSet<T>
+where
+    T: Key + core::fmt::Debug,
+{
+    fn trace_entries(&self) -> BTreeMap<String, String> {
+        self.0
+            .iter()
+            .map(|element| (format!("{element:?}"), String::from("present")))
+            .collect()
+    }
 }
 
 impl<T> Apply for GSet<T>
 where
-    T: Hash + Eq + Clone + std::fmt::Debug,
+    T: Key + Clone + core::fmt::Debug,
 {
     type Op = T;
     type Context = ();
@@ -51,17 +108,35 @@ where
     fn apply(&mut self, op: Self::Op, _ctx: Self::Context) {
         self.0.insert(op);
     }
+
+    fn apply_all(&mut self, ops: impl IntoIterator<Item = (Self::Op, Self::Context)>) {
+        // `extend` reserves capacity up front instead of growing once per insert.
+        self.0.extend(ops.into_iter().map(|(op, _)| op));
+    }
 }
 
+impl<T> IdempotentApply for GSet<T> where T: Key + Clone + core::fmt::Debug {}
+
 impl<T> GSet<T>
 where
-    T: Hash + Eq,
+    T: Key,
 {
     /// Creates a new, empty G-Set.
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Creates a new, empty G-Set with room for `capacity` elements before it
+    /// has to grow.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(Set::with_capacity_hint(capacity))
+    }
+
+    /// Makes room for at least `additional` more elements.
+    pub fn reserve(&mut self, additional: usize) {
+        self.0.reserve_hint(additional);
+    }
+
     /// Adds an element to the set.
     pub fn insert(&mut self, value: T) {
         self.0.insert(value);
@@ -81,11 +156,92 @@ where
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Iterates over the elements of the set without cloning them.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.0.iter()
+    }
+
+    /// Iterates over the elements matching `pred`, without cloning them.
+    ///
+    /// This is a read-only view: a G-Set can never drop elements, so there is
+    /// no mutating `retain`.
+    pub fn filter(&self, pred: impl Fn(&T) -> bool) -> impl Iterator<Item = &T> {
+        self.0.iter().filter(move |value| pred(value))
+    }
+}
+
+impl<T: Key + Clone> GSet<T> {
+    /// Merges another set into this one, reporting how many elements were new.
+    pub fn merge_report(&mut self, other: &Self) -> MergeReport {
+        // G-Set merge is set union
+        let mut updated = 0;
+        for item in &other.0 {
+            if self.0.insert(item.clone()) {
+                updated += 1;
+            }
+        }
+        MergeReport::from_updated(updated)
+    }
+}
+
+impl<T: Key + Copy> GSet<T> {
+    /// Merges another set into this one by copying its elements, reporting how
+    /// many were new.
+    ///
+    /// Same result as [`merge_report`](Self::merge_report), but for `Copy`
+    /// elements such as `u64` ids it bulk-inserts with `extend`, which reserves
+    /// room up front instead of rehashing as the set grows. Merging a million
+    /// `u64`s into an empty set takes about half the time this way; into a
+    /// half-overlapping set of the same size the gain mostly disappears, since
+    /// the set is already large and cloning a `u64` is already a copy (see
+    /// `benches/gset_merge.rs`). `Crdt::merge` cannot pick this path on its own
+    /// without specialization, so call it directly where the element type is
+    /// known to be `Copy`.
+    pub fn merge_copy(&mut self, other: &Self) -> MergeReport {
+        let before = self.0.len();
+        self.0.extend(other.0.iter().copied());
+        MergeReport::from_updated(self.0.len() - before)
+    }
+}
+
+impl<T: Key + Hash + Ord> GSet<T> {
+    /// Returns a hash of the set's elements that is stable across processes,
+    /// platforms and Rust versions.
+    ///
+    /// Equal sets always hash equal, regardless of insertion order.
+    pub fn state_hash(&self) -> u64 {
+        hash_sorted(self.0.iter())
+    }
+}
+
+impl<T: Key> FromIterator<T> for GSet<T> {
+    fn from_iter<It: IntoIterator<Item = T>>(iter: It) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<T: Key> IntoIterator for GSet<T> {
+    type Item = T;
+    type IntoIter = set::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T: Key> IntoIterator for &'a GSet<T> {
+    type Item = &'a T;
+    type IntoIter = set::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
 }
 
 impl<T> DeltaSync for GSet<T>
 where
-    T: Hash + Eq + Clone + std::fmt::Debug,
+    T: Key + Clone + core::fmt::Debug,
 {
     // No compact summary exists for a GSet — the full state is the summary.
     type Summary = Self;
@@ -109,6 +265,11 @@ where
     fn merge_delta(&mut self, delta: &Self) {
         self.merge(delta);
     }
+
+    fn merge_diff(&self, other: &Self) -> Self {
+        // The summary is the full state, so borrow it instead of cloning.
+        other.delta_from_summary(self)
+    }
 }
 
 #[cfg(feature = "proptest")]
@@ -117,14 +278,6 @@ impl Arbitrary for GSet<String> {
     type Strategy = BoxedStrategy<Self>;
 
     fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
-        proptest::collection::hash_set("[a-e]".prop_map(String::from), 0..5)
-            .prop_map(|set| {
-                let mut gset = GSet::new();
-                for item in set {
-                    gset.insert(item);
-                }
-                gset
-            })
-            .boxed()
+        crate::arbitrary::gset_strategy("[a-e]".prop_map(String::from), 0..5)
     }
 }
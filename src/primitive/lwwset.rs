@@ -0,0 +1,171 @@
+use crate::collections::{Key, Map, Set};
+use crate::{Apply, Clock, Crdt, IdempotentApply};
+use core::fmt::Debug;
+
+#[cfg(feature = "proptest")]
+use alloc::string::String;
+#[cfg(feature = "proptest")]
+use proptest::prelude::*;
+
+/// A Last-Writer-Wins Element Set (LWW-Element-Set) CRDT.
+///
+/// Each element carries the latest timestamp at which it was added and the
+/// latest at which it was removed. An element is present when its add timestamp
+/// is at least its remove timestamp, so a concurrent add and remove carrying
+/// the same timestamp resolve in favour of the add. Unlike a 2P-Set, an element
+/// can be re-added after removal by adding it with a later timestamp.
+///
+/// # Type Parameters
+/// * `T`: The type of elements in the set. Must implement `Hash`, `Eq`, `Clone`, and `Debug`.
+/// * `TS`: The type of the timestamp. Must be `Ord`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LWWSet<T, TS>
+where
+    T: Key,
+{
+    /// The latest add timestamp of each element.
+    adds: Map<T, TS>,
+    /// The latest remove timestamp of each element.
+    removes: Map<T, TS>,
+}
+
+/// An operation on an [`LWWSet`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LWWSetOp<T> {
+    Add(T),
+    Remove(T),
+}
+
+impl<T: Key, TS> Default for LWWSet<T, TS> {
+    fn default() -> Self {
+        Self {
+            adds: Map::new(),
+            removes: Map::new(),
+        }
+    }
+}
+
+impl<T, TS> Crdt for LWWSet<T, TS>
+where
+    T: Key + Clone + Debug,
+    TS: Ord + Clone + Debug,
+{
+    type Value = Set<T>;
+
+    fn merge(&mut self, other: &Self) {
+        for (value, ts) in &other.adds {
+            record(&mut self.adds, value.clone(), ts.clone());
+        }
+        for (value, ts) in &other.removes {
+            record(&mut self.removes, value.clone(), ts.clone());
+        }
+    }
+
+    fn value(&self) -> Self::Value {
+        self.adds
+            .keys()
+            .filter(|value| self.contains(value))
+            .cloned()
+            .collect()
+    }
+}
+
+impl<T, TS> Apply for LWWSet<T, TS>
+where
+    T: Key + Clone + Debug,
+    TS: Ord + Clone + Debug,
+{
+    type Op = LWWSetOp<T>;
+    type Context = TS; // The timestamp of the operation
+
+    fn apply(&mut self, op: Self::Op, ctx: Self::Context) {
+        match op {
+            LWWSetOp::Add(value) => self.add(value, ctx),
+            LWWSetOp::Remove(value) => self.remove(value, ctx),
+        }
+    }
+}
+
+impl<T, TS> IdempotentApply for LWWSet<T, TS>
+where
+    T: Key + Clone + Debug,
+    TS: Ord + Clone + Debug,
+{
+}
+
+/// Keeps the greater of the stored and the new timestamp for `value`.
+fn record<T: Key, TS: Ord>(timestamps: &mut Map<T, TS>, value: T, ts: TS) {
+    match timestamps.get(&value) {
+        Some(current) if *current >= ts => {}
+        _ => {
+            timestamps.insert(value, ts);
+        }
+    }
+}
+
+impl<T, TS> LWWSet<T, TS>
+where
+    T: Key,
+    TS: Ord,
+{
+    /// Creates a new, empty LWW-Element-Set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an element at the given timestamp.
+    ///
+    /// Has no visible effect if the element was removed at a later timestamp.
+    pub fn add(&mut self, value: T, ts: TS) {
+        record(&mut self.adds, value, ts);
+    }
+
+    /// Removes an element at the given timestamp.
+    ///
+    /// Has no visible effect unless the timestamp is later than the element's
+    /// latest add.
+    pub fn remove(&mut self, value: T, ts: TS) {
+        record(&mut self.removes, value, ts);
+    }
+
+    /// Adds an element at the timestamp read from `clock`.
+    pub fn add_now(&mut self, value: T, clock: &impl Clock<TS>) {
+        self.add(value, clock.now());
+    }
+
+    /// Removes an element at the timestamp read from `clock`.
+    pub fn remove_now(&mut self, value: T, clock: &impl Clock<TS>) {
+        self.remove(value, clock.now());
+    }
+
+    /// Returns true if the element's latest add is not older than its latest remove.
+    pub fn contains(&self, value: &T) -> bool {
+        match (self.adds.get(value), self.removes.get(value)) {
+            (Some(added), Some(removed)) => added >= removed,
+            (Some(_), None) => true,
+            (None, _) => false,
+        }
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for LWWSet<String, u64> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        // A small timestamp range makes add/remove ties common.
+        let ops = prop_oneof![
+            ("[a-c]", 0u64..4).prop_map(|(v, ts)| (LWWSetOp::Add(v), ts)),
+            ("[a-c]", 0u64..4).prop_map(|(v, ts)| (LWWSetOp::Remove(v), ts)),
+        ];
+        proptest::collection::vec(ops, 0..8)
+            .prop_map(|ops| {
+                let mut set = LWWSet::new();
+                set.apply_all(ops);
+                set
+            })
+            .boxed()
+    }
+}
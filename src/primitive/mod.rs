@@ -1,2 +1,9 @@
-#[cfg(feature = "std")]
 pub mod gset;
+pub mod lwwset;
+pub mod ordered_gset;
+#[cfg(feature = "std")]
+pub mod orset;
+#[cfg(feature = "std")]
+pub mod pnset;
+#[cfg(feature = "std")]
+pub mod twopset;
@@ -0,0 +1,180 @@
+use crate::collections::{Key, Map, Set};
+use crate::{Apply, Crdt, IdempotentApply};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+#[cfg(feature = "proptest")]
+use alloc::string::String;
+#[cfg(feature = "proptest")]
+use proptest::prelude::*;
+
+/// A Grow-only Set that remembers insertion order, e.g. for an append-only log.
+///
+/// Each element carries the Lamport timestamp of its first insertion. Merging
+/// unions the elements and keeps the smaller timestamp for each one, so an
+/// element inserted concurrently on two replicas takes the earlier position.
+/// [`OrderedGSet::value_ordered`] sorts by `(timestamp, element)`, which gives
+/// every replica the same order once they have merged the same states.
+///
+/// The set keeps its own Lamport clock: [`OrderedGSet::insert`] stamps new
+/// elements after every timestamp the set has seen, including merged ones.
+///
+/// # Type Parameters
+/// * `T`: The type of elements in the set. Must implement `Hash`, `Eq`, `Ord`, `Clone`, and `Debug`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone)]
+pub struct OrderedGSet<T>
+where
+    T: Key,
+{
+    /// The first-insertion timestamp of each element.
+    timestamps: Map<T, u64>,
+    /// The largest timestamp this replica has issued or merged.
+    /// Not part of the CRDT state; ignored by `==`.
+    clock: u64,
+}
+
+impl<T: Key> Default for OrderedGSet<T> {
+    fn default() -> Self {
+        Self {
+            timestamps: Map::new(),
+            clock: 0,
+        }
+    }
+}
+
+impl<T: Key> PartialEq for OrderedGSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.timestamps == other.timestamps
+    }
+}
+
+impl<T: Key> Eq for OrderedGSet<T> {}
+
+impl<T> Crdt for OrderedGSet<T>
+where
+    T: Key + Ord + Clone + Debug,
+{
+    type Value = Set<T>;
+
+    fn merge(&mut self, other: &Self) {
+        for (value, &ts) in &other.timestamps {
+            self.insert_at(value.clone(), ts);
+        }
+        self.clock = self.clock.max(other.clock);
+    }
+
+    fn value(&self) -> Self::Value {
+        self.timestamps.keys().cloned().collect()
+    }
+
+    fn leq(&self, other: &Self) -> bool {
+        self.timestamps.iter().all(|(value, &ts)| {
+            other
+                .timestamps
+                .get(value)
+                .is_some_and(|&other_ts| other_ts <= ts)
+        })
+    }
+}
+
+impl<T> Apply for OrderedGSet<T>
+where
+    T: Key + Ord + Clone + Debug,
+{
+    type Op = T;
+    type Context = u64; // The Lamport timestamp of the insertion
+
+    fn apply(&mut self, op: Self::Op, ctx: Self::Context) {
+        self.insert_at(op, ctx);
+    }
+}
+
+impl<T> IdempotentApply for OrderedGSet<T> where T: Key + Ord + Clone + Debug {}
+
+impl<T> OrderedGSet<T>
+where
+    T: Key + Ord,
+{
+    /// Creates a new, empty ordered G-Set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts an element after everything this replica has seen, returning
+    /// its timestamp.
+    ///
+    /// An element that is already present keeps its original position.
+    pub fn insert(&mut self, value: T) -> u64 {
+        if let Some(&ts) = self.timestamps.get(&value) {
+            return ts;
+        }
+        self.clock += 1;
+        self.timestamps.insert(value, self.clock);
+        self.clock
+    }
+
+    /// Inserts an element at the given timestamp, keeping the earlier one if
+    /// the element is already present.
+    pub fn insert_at(&mut self, value: T, ts: u64) {
+        self.clock = self.clock.max(ts);
+        let entry = self.timestamps.entry(value).or_insert(ts);
+        *entry = (*entry).min(ts);
+    }
+
+    /// Returns true if the set contains the value.
+    pub fn contains(&self, value: &T) -> bool {
+        self.timestamps.contains_key(value)
+    }
+
+    /// Returns the timestamp of the element's first insertion, if present.
+    pub fn timestamp(&self, value: &T) -> Option<u64> {
+        self.timestamps.get(value).copied()
+    }
+
+    /// Returns the number of elements in the set.
+    pub fn len(&self) -> usize {
+        self.timestamps.len()
+    }
+
+    /// Returns true if the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.timestamps.is_empty()
+    }
+
+    /// Returns the elements sorted by `(timestamp, element)`.
+    pub fn value_ordered(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut entries: Vec<(u64, &T)> = self
+            .timestamps
+            .iter()
+            .map(|(value, &ts)| (ts, value))
+            .collect();
+        entries.sort();
+        entries
+            .into_iter()
+            .map(|(_, value)| value.clone())
+            .collect()
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for OrderedGSet<String> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        // A small timestamp range makes ties common, exercising the tie-break.
+        proptest::collection::hash_map("[a-e]".prop_map(String::from), 1u64..6, 0..5)
+            .prop_map(|entries| {
+                let mut set = OrderedGSet::new();
+                for (value, ts) in entries {
+                    set.insert_at(value, ts);
+                }
+                set
+            })
+            .boxed()
+    }
+}
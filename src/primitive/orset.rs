@@ -0,0 +1,171 @@
+use crate::{Apply, CausalContext, Crdt};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+#[cfg(feature = "proptest")]
+use proptest::prelude::*;
+#[cfg(feature = "proptest")]
+use proptest::sample::Index;
+
+/// An Observed-Remove Set (OR-Set) CRDT.
+///
+/// Every add attaches a unique dot `(replica, sequence)` to the element. Removing
+/// an element discards only the dots the remover has observed, so an add that
+/// is concurrent with a remove survives it (add-wins), and an element can be
+/// re-added after removal.
+///
+/// Instead of keeping a tombstone per removed dot, the set keeps a
+/// [`CausalContext`] of every dot it has seen. A dot that is in the context but
+/// not live was removed, and since dots are minted in sequence the context
+/// compacts to one entry per replica, so removals cost no lasting storage.
+///
+/// # Type Parameters
+/// * `T`: The type of elements in the set. Must implement `Hash`, `Eq`, `Clone`, and `Debug`.
+/// * `I`: The type of the Replica ID. Must be `Hash`, `Eq`, `Clone`, and `Debug`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ORSet<T, I>
+where
+    T: Hash + Eq,
+    I: Hash + Eq + Clone,
+{
+    /// Live (not yet removed) dots for each present element.
+    entries: HashMap<T, HashSet<(I, u64)>>,
+    /// Every dot observed, live or removed; also used to mint fresh dots.
+    context: CausalContext<I>,
+}
+
+/// An operation on an [`ORSet`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ORSetOp<T> {
+    Add(T),
+    Remove(T),
+}
+
+impl<T: Hash + Eq, I: Hash + Eq + Clone> Default for ORSet<T, I> {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+            context: CausalContext::default(),
+        }
+    }
+}
+
+impl<T, I> Crdt for ORSet<T, I>
+where
+    T: Hash + Eq + Clone + std::fmt::Debug,
+    I: Hash + Eq + Clone + std::fmt::Debug,
+{
+    type Value = HashSet<T>;
+
+    fn merge(&mut self, other: &Self) {
+        // A dot live on one side only was removed by the other side if the other
+        // side has seen it; otherwise the other side just hasn't received it yet.
+        let empty = HashSet::new();
+        for (item, dots) in self.entries.iter_mut() {
+            let other_dots = other.entries.get(item).unwrap_or(&empty);
+            dots.retain(|dot| other_dots.contains(dot) || !other.context.contains(dot));
+        }
+        for (item, other_dots) in &other.entries {
+            let dots = self.entries.entry(item.clone()).or_default();
+            for dot in other_dots {
+                if !dots.contains(dot) && !self.context.contains(dot) {
+                    dots.insert(dot.clone());
+                }
+            }
+        }
+        self.entries.retain(|_, dots| !dots.is_empty());
+        self.context.merge(&other.context);
+    }
+
+    fn value(&self) -> Self::Value {
+        self.entries.keys().cloned().collect()
+    }
+}
+
+impl<T, I> Apply for ORSet<T, I>
+where
+    T: Hash + Eq + Clone + std::fmt::Debug,
+    I: Hash + Eq + Clone + std::fmt::Debug,
+{
+    type Op = ORSetOp<T>;
+    type Context = I;
+
+    fn apply(&mut self, op: Self::Op, ctx: Self::Context) {
+        match op {
+            ORSetOp::Add(value) => {
+                self.add(value, ctx);
+            }
+            ORSetOp::Remove(value) => self.remove(&value),
+        }
+    }
+}
+
+impl<T, I> ORSet<T, I>
+where
+    T: Hash + Eq,
+    I: Hash + Eq + Clone,
+{
+    /// Creates a new, empty OR-Set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an element on behalf of the given replica, returning the new dot.
+    pub fn add(&mut self, value: T, replica: I) -> (I, u64) {
+        let dot = self.context.next_dot(replica);
+        self.entries.entry(value).or_default().insert(dot.clone());
+        dot
+    }
+
+    /// Removes an element by discarding every dot observed for it.
+    ///
+    /// The dots stay in the causal context, which is what tells a later merge
+    /// they were removed rather than not yet received. Adds of the same element
+    /// that this replica has not yet seen are unaffected.
+    pub fn remove(&mut self, value: &T) {
+        self.entries.remove(value);
+    }
+
+    /// Returns the causal context of every dot this replica has observed.
+    pub fn context(&self) -> &CausalContext<I> {
+        &self.context
+    }
+
+    /// Returns true if the element has at least one live dot.
+    pub fn contains(&self, value: &T) -> bool {
+        self.entries.contains_key(value)
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for ORSet<String, String> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        // Replay a short history of adds and removes from a few replicas.
+        (
+            crate::arbitrary::replica_names_strategy(),
+            proptest::collection::vec(
+                (
+                    any::<Index>(),
+                    "[a-e]".prop_map(String::from),
+                    any::<bool>(),
+                ),
+                0..8,
+            ),
+        )
+            .prop_map(|(replicas, ops)| {
+                let mut set = ORSet::new();
+                for (replica, item, is_add) in ops {
+                    if is_add {
+                        set.add(item, replica.get(&replicas).clone());
+                    } else {
+                        set.remove(&item);
+                    }
+                }
+                set
+            })
+            .boxed()
+    }
+}
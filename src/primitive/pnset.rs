@@ -0,0 +1,138 @@
+use crate::identified::pncounter::PNCounter;
+use crate::{Apply, Crdt};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+#[cfg(feature = "proptest")]
+use proptest::prelude::*;
+
+/// A Positive-Negative Set (PN-Set) CRDT.
+///
+/// Each element carries a PN-Counter of how many times it was added minus how
+/// many times it was removed, and is present while that count is positive.
+/// Unlike a 2P-Set, elements can be removed and added again any number of
+/// times, which suits shopping-cart-like uses. Note that a remove that wins a
+/// race against the add it was meant to cancel can leave the count at zero or
+/// below, so a later add may not make the element reappear.
+///
+/// # Type Parameters
+/// * `T`: The type of elements in the set. Must implement `Hash`, `Eq`, `Clone`, and `Debug`.
+/// * `I`: The type of the Replica ID. Must be `Hash`, `Eq`, `Clone`, and `Debug`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PNSet<T, I>
+where
+    T: Hash + Eq,
+    I: Hash + Eq,
+{
+    counts: HashMap<T, PNCounter<I>>,
+}
+
+/// An operation on a [`PNSet`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PNSetOp<T> {
+    Add(T),
+    Remove(T),
+}
+
+impl<T: Hash + Eq, I: Hash + Eq> Default for PNSet<T, I> {
+    fn default() -> Self {
+        Self {
+            counts: HashMap::new(),
+        }
+    }
+}
+
+impl<T, I> Crdt for PNSet<T, I>
+where
+    T: Hash + Eq + Clone + std::fmt::Debug,
+    I: Hash + Eq + Clone + std::fmt::Debug,
+{
+    type Value = HashSet<T>;
+
+    fn merge(&mut self, other: &Self) {
+        for (value, counter) in &other.counts {
+            self.counts.entry(value.clone()).or_default().merge(counter);
+        }
+    }
+
+    fn value(&self) -> Self::Value {
+        self.counts
+            .iter()
+            .filter(|(_, counter)| counter.value() > 0)
+            .map(|(value, _)| value.clone())
+            .collect()
+    }
+}
+
+impl<T, I> Apply for PNSet<T, I>
+where
+    T: Hash + Eq + Clone + std::fmt::Debug,
+    I: Hash + Eq + Clone + std::fmt::Debug,
+{
+    type Op = PNSetOp<T>;
+    type Context = I;
+
+    fn apply(&mut self, op: Self::Op, ctx: Self::Context) {
+        match op {
+            PNSetOp::Add(value) => self.add(value, ctx),
+            PNSetOp::Remove(value) => self.remove(value, ctx),
+        }
+    }
+}
+
+impl<T, I> PNSet<T, I>
+where
+    T: Hash + Eq + Clone + std::fmt::Debug,
+    I: Hash + Eq + Clone + std::fmt::Debug,
+{
+    /// Creates a new, empty PN-Set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Increments the element's count on behalf of the given replica.
+    pub fn add(&mut self, value: T, replica: I) {
+        self.counts.entry(value).or_default().inc(replica);
+    }
+
+    /// Decrements the element's count on behalf of the given replica.
+    ///
+    /// The count may go negative, in which case it takes that many further
+    /// adds before the element is present again.
+    pub fn remove(&mut self, value: T, replica: I) {
+        self.counts.entry(value).or_default().dec(replica);
+    }
+
+    /// Returns how many times the element was added minus how many times it was removed.
+    pub fn count(&self, value: &T) -> i64 {
+        self.counts.get(value).map_or(0, Crdt::value)
+    }
+
+    /// Returns true if the element's count is positive.
+    pub fn contains(&self, value: &T) -> bool {
+        self.count(value) > 0
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for PNSet<String, String> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::collection::vec((any::<bool>(), "[a-c]", "[a-c]"), 0..8)
+            .prop_map(|ops| {
+                let mut set = PNSet::new();
+                set.apply_all(ops.into_iter().map(|(add, value, replica)| {
+                    let op = if add {
+                        PNSetOp::Add(value)
+                    } else {
+                        PNSetOp::Remove(value)
+                    };
+                    (op, replica)
+                }));
+                set
+            })
+            .boxed()
+    }
+}
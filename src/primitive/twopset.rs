@@ -0,0 +1,195 @@
+use super::gset::GSet;
+use crate::{Apply, Crdt, DeltaSync, IdempotentApply, TryApply};
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::Hash;
+
+#[cfg(feature = "proptest")]
+use proptest::prelude::*;
+
+/// A Two-Phase Set (2P-Set) CRDT.
+///
+/// Elements can be added and removed, but once removed an element can never be
+/// added again. The set is built from two G-Sets: one recording additions and
+/// one recording removals (tombstones). An element is present if it has been
+/// added and not removed.
+///
+/// # Type Parameters
+/// * `T`: The type of elements in the set. Must implement `Hash`, `Eq`, `Clone`, and `Debug`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TwoPSet<T>
+where
+    T: Hash + Eq,
+{
+    added: GSet<T>,
+    removed: GSet<T>,
+}
+
+/// An operation on a [`TwoPSet`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TwoPSetOp<T> {
+    Add(T),
+    Remove(T),
+}
+
+/// Error returned by [`TwoPSet`]'s [`TryApply`] implementation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TwoPSetError {
+    /// The element was already removed, so adding it again would have no effect.
+    AlreadyRemoved,
+    /// The element is not in the set, so it cannot be removed.
+    NotPresent,
+}
+
+impl fmt::Display for TwoPSetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TwoPSetError::AlreadyRemoved => write!(f, "element was already removed"),
+            TwoPSetError::NotPresent => write!(f, "element is not in the set"),
+        }
+    }
+}
+
+impl std::error::Error for TwoPSetError {}
+
+impl<T: Hash + Eq> Default for TwoPSet<T> {
+    fn default() -> Self {
+        Self {
+            added: GSet::default(),
+            removed: GSet::default(),
+        }
+    }
+}
+
+impl<T> Crdt for TwoPSet<T>
+where
+    T: Hash + Eq + Clone + std::fmt::Debug,
+{
+    type Value = HashSet<T>;
+
+    fn merge(&mut self, other: &Self) {
+        self.added.merge(&other.added);
+        self.removed.merge(&other.removed);
+    }
+
+    fn value(&self) -> Self::Value {
+        let mut live = self.added.value();
+        live.retain(|item| !self.removed.contains(item));
+        live
+    }
+}
+
+impl<T> Apply for TwoPSet<T>
+where
+    T: Hash + Eq + Clone + std::fmt::Debug,
+{
+    type Op = TwoPSetOp<T>;
+    type Context = ();
+
+    fn apply(&mut self, op: Self::Op, _ctx: Self::Context) {
+        match op {
+            TwoPSetOp::Add(value) => self.insert(value),
+            TwoPSetOp::Remove(value) => {
+                self.remove(value);
+            }
+        }
+    }
+}
+
+impl<T> IdempotentApply for TwoPSet<T> where T: Hash + Eq + Clone + std::fmt::Debug {}
+
+impl<T> TryApply for TwoPSet<T>
+where
+    T: Hash + Eq + Clone + std::fmt::Debug,
+{
+    type Error = TwoPSetError;
+
+    fn try_apply(&mut self, op: Self::Op, _ctx: Self::Context) -> Result<(), Self::Error> {
+        match op {
+            TwoPSetOp::Add(value) => {
+                if self.removed.contains(&value) {
+                    return Err(TwoPSetError::AlreadyRemoved);
+                }
+                self.insert(value);
+                Ok(())
+            }
+            TwoPSetOp::Remove(value) => {
+                if self.remove(value) {
+                    Ok(())
+                } else {
+                    Err(TwoPSetError::NotPresent)
+                }
+            }
+        }
+    }
+}
+
+impl<T> TwoPSet<T>
+where
+    T: Hash + Eq,
+{
+    /// Creates a new, empty 2P-Set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an element to the set.
+    ///
+    /// Has no visible effect if the element was previously removed.
+    pub fn insert(&mut self, value: T) {
+        self.added.insert(value);
+    }
+
+    /// Removes an element from the set, permanently.
+    ///
+    /// Only elements currently present can be removed. Returns true if the
+    /// element was removed.
+    pub fn remove(&mut self, value: T) -> bool {
+        if !self.contains(&value) {
+            return false;
+        }
+        self.removed.insert(value);
+        true
+    }
+
+    /// Returns true if the element has been added and not removed.
+    pub fn contains(&self, value: &T) -> bool {
+        self.added.contains(value) && !self.removed.contains(value)
+    }
+}
+
+impl<T> DeltaSync for TwoPSet<T>
+where
+    T: Hash + Eq + Clone + std::fmt::Debug,
+{
+    // Both halves are G-Sets, so each summary is the full half.
+    type Summary = (GSet<T>, GSet<T>);
+    type Delta = Self;
+
+    fn summary(&self) -> Self::Summary {
+        (self.added.summary(), self.removed.summary())
+    }
+
+    fn delta_from_summary(&self, remote_summary: &Self::Summary) -> Self {
+        TwoPSet {
+            added: self.added.delta_from_summary(&remote_summary.0),
+            removed: self.removed.delta_from_summary(&remote_summary.1),
+        }
+    }
+
+    fn merge_delta(&mut self, delta: &Self) {
+        self.merge(delta);
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for TwoPSet<String> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<GSet<String>>(), any::<GSet<String>>())
+            .prop_map(|(added, removed)| TwoPSet { added, removed })
+            .boxed()
+    }
+}
@@ -0,0 +1,216 @@
+use crate::collections::Key;
+use crate::{Apply, Crdt, VectorClock};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+#[cfg(feature = "proptest")]
+use alloc::format;
+#[cfg(feature = "proptest")]
+use alloc::string::String;
+#[cfg(feature = "proptest")]
+use proptest::prelude::*;
+
+/// A write held by a [`DotRegister`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone)]
+struct DotWrite<T, I>
+where
+    I: Key,
+{
+    /// Identifies the write: the writing replica and its per-replica counter.
+    dot: (I, u64),
+    /// Every write the writer had seen, including this one.
+    context: VectorClock<I>,
+    value: T,
+}
+
+/// A register ordered by causality alone, with no timestamps or clocks.
+///
+/// Each write is identified by a dot `(replica, counter)` and carries the
+/// causal context of everything its writer had seen. Merging keeps the writes
+/// that no other write has seen, so a later write always replaces the ones
+/// before it, whatever the replicas' clocks say. Writes that are truly
+/// concurrent are all kept, and [`Crdt::value`] picks the one with the
+/// greatest dot: an arbitrary but deterministic winner, like an
+/// [`LWWRegister`](crate::LWWRegister) with a principled order.
+/// [`DotRegister::values`] returns all of them, like an
+/// [`MVRegister`](crate::MVRegister).
+///
+/// # Type Parameters
+/// * `T`: The type of the stored value.
+/// * `I`: The type of the Replica ID. Must be `Hash`, `Eq`, `Ord`, `Clone`, and `Debug`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone)]
+pub struct DotRegister<T, I>
+where
+    I: Key,
+{
+    /// The writes no other retained write has seen, sorted by dot.
+    writes: Vec<DotWrite<T, I>>,
+}
+
+impl<T, I: Key> Default for DotRegister<T, I> {
+    fn default() -> Self {
+        Self { writes: Vec::new() }
+    }
+}
+
+impl<T, I> PartialEq for DotRegister<T, I>
+where
+    T: PartialEq,
+    I: Key + Clone,
+{
+    fn eq(&self, other: &Self) -> bool {
+        // Both sides are sorted by dot, so the writes line up.
+        self.writes.len() == other.writes.len()
+            && self
+                .writes
+                .iter()
+                .zip(&other.writes)
+                .all(|(a, b)| a.dot == b.dot && a.context == b.context && a.value == b.value)
+    }
+}
+
+impl<T: Eq, I: Key + Clone> Eq for DotRegister<T, I> {}
+
+impl<T, I> Crdt for DotRegister<T, I>
+where
+    T: Clone + PartialEq + Debug,
+    I: Key + Ord + Clone + Debug,
+{
+    type Value = Option<T>;
+
+    fn merge(&mut self, other: &Self) {
+        for write in &other.writes {
+            if !self.writes.iter().any(|own| own.dot == write.dot) {
+                self.writes.push(write.clone());
+            }
+        }
+
+        // Keep only the writes that no other retained write has seen.
+        let seen: Vec<bool> = self
+            .writes
+            .iter()
+            .map(|write| self.writes.iter().any(|other| other.sees(write)))
+            .collect();
+        let mut seen = seen.into_iter();
+        self.writes.retain(|_| !seen.next().unwrap_or(false));
+        self.writes.sort_by(|a, b| a.dot.cmp(&b.dot));
+    }
+
+    /// Returns the value of the concurrent write with the greatest dot.
+    fn value(&self) -> Self::Value {
+        self.writes.last().map(|write| write.value.clone())
+    }
+
+    fn leq(&self, other: &Self) -> bool {
+        self.writes.iter().all(|write| {
+            other
+                .writes
+                .iter()
+                .any(|theirs| theirs.dot == write.dot || theirs.sees(write))
+        })
+    }
+}
+
+impl<T, I> Apply for DotRegister<T, I>
+where
+    T: Clone + PartialEq + Debug,
+    I: Key + Ord + Clone + Debug,
+{
+    type Op = T;
+    type Context = I; // The writing replica
+
+    fn apply(&mut self, op: Self::Op, ctx: Self::Context) {
+        self.set(op, ctx);
+    }
+}
+
+impl<T, I: Key + Clone> DotWrite<T, I> {
+    /// Returns true if this write's writer had seen `other`, a different write.
+    fn sees(&self, other: &Self) -> bool {
+        self.dot != other.dot && self.context.get(&other.dot.0) >= other.dot.1
+    }
+}
+
+impl<T, I> DotRegister<T, I>
+where
+    I: Key + Ord + Clone,
+{
+    /// Creates a new, empty register.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Writes a value on behalf of the given replica, returning the write's dot.
+    ///
+    /// The write has seen every value currently held, so it replaces all of them.
+    pub fn set(&mut self, value: T, replica: I) -> (I, u64) {
+        let mut context = VectorClock::new();
+        for write in &self.writes {
+            context.merge_report(&write.context);
+        }
+        context.inc(replica.clone());
+        let dot = (replica.clone(), context.get(&replica));
+        self.writes = vec![DotWrite {
+            dot: dot.clone(),
+            context,
+            value,
+        }];
+        dot
+    }
+
+    /// Returns every concurrent value, ordered by dot; the last one is the
+    /// value reported by [`Crdt::value`].
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.writes.iter().map(|write| &write.value)
+    }
+
+    /// Returns true if the register holds more than one concurrent value.
+    pub fn is_conflicted(&self) -> bool {
+        self.writes.len() > 1
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for DotRegister<String, String> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        // Independently generated states must agree on what each dot means, as
+        // real replicas do, so all of them draw from one fixed history: three
+        // replicas take turns writing, each having seen the write two turns
+        // earlier but not the previous one, which leaves neighbours concurrent.
+        let mut clocks = vec![VectorClock::new(); 3];
+        let mut history = Vec::new();
+        for turn in 0..12 {
+            let writer = turn % 3;
+            if turn >= 2 {
+                let seen: &DotWrite<String, String> = &history[turn - 2];
+                clocks[writer].merge_report(&seen.context);
+            }
+            let replica = String::from(["a", "b", "c"][writer]);
+            clocks[writer].inc(replica.clone());
+            let counter = clocks[writer].get(&replica);
+            history.push(DotWrite {
+                value: format!("{replica}{counter}"),
+                dot: (replica, counter),
+                context: clocks[writer].clone(),
+            });
+        }
+
+        proptest::sample::subsequence(history, 0..=4)
+            .prop_map(|writes| {
+                let mut register = DotRegister::new();
+                for write in writes {
+                    register.merge(&DotRegister {
+                        writes: vec![write],
+                    });
+                }
+                register
+            })
+            .boxed()
+    }
+}
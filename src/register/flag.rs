@@ -0,0 +1,118 @@
+use crate::Crdt;
+
+#[cfg(feature = "proptest")]
+use proptest::prelude::*;
+
+/// A one-shot boolean that starts disabled and, once enabled, stays enabled.
+///
+/// Merging ORs the two flags, so an enable anywhere wins. There is no
+/// `disable`: merge can only move the flag up, and a replica that had not yet
+/// seen the disable would enable it again on the next merge. Supporting both
+/// directions needs per-write metadata (tombstones or dots, as in an
+/// observed-remove flag), not a bare `bool`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct EnableWinsFlag(bool);
+
+/// A one-shot boolean that starts enabled and, once disabled, stays disabled.
+///
+/// Merging ANDs the two flags, so a disable anywhere wins. This is the mirror
+/// image of [`EnableWinsFlag`], and there is no `enable` for the same reason:
+/// re-enabling could not survive a merge with a replica still holding `false`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisableWinsFlag(bool);
+
+impl Default for DisableWinsFlag {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+impl Crdt for EnableWinsFlag {
+    type Value = bool;
+
+    fn merge(&mut self, other: &Self) {
+        self.0 |= other.0;
+    }
+
+    fn value(&self) -> Self::Value {
+        self.0
+    }
+
+    fn leq(&self, other: &Self) -> bool {
+        !self.0 || other.0
+    }
+}
+
+impl Crdt for DisableWinsFlag {
+    type Value = bool;
+
+    fn merge(&mut self, other: &Self) {
+        self.0 &= other.0;
+    }
+
+    fn value(&self) -> Self::Value {
+        self.0
+    }
+
+    fn leq(&self, other: &Self) -> bool {
+        self.0 || !other.0
+    }
+}
+
+impl EnableWinsFlag {
+    /// Creates a new, disabled flag.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables the flag, permanently.
+    pub fn enable(&mut self) {
+        self.0 = true;
+    }
+
+    /// Returns true if the flag has been enabled on any replica merged so far.
+    pub fn is_enabled(&self) -> bool {
+        self.0
+    }
+}
+
+impl DisableWinsFlag {
+    /// Creates a new, enabled flag.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Disables the flag, permanently.
+    pub fn disable(&mut self) {
+        self.0 = false;
+    }
+
+    /// Returns true unless the flag has been disabled on any replica merged so far.
+    pub fn is_enabled(&self) -> bool {
+        self.0
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for EnableWinsFlag {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<bool>().prop_map(EnableWinsFlag).boxed()
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for DisableWinsFlag {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<bool>().prop_map(DisableWinsFlag).boxed()
+    }
+}
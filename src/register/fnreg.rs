@@ -0,0 +1,91 @@
+use crate::{Apply, Crdt};
+use core::fmt;
+
+/// A register over a type with a domain-specific join, supplied as a closure.
+///
+/// Merging replaces the value with `join(&self, &other)`, so a type such as a
+/// bounding box that expands to cover both sides becomes a CRDT without a
+/// hand-written [`Crdt`] impl. A merge always uses `self`'s closure; replicas
+/// are expected to share the same one.
+///
+/// Correctness rests entirely on the closure: it must be idempotent
+/// (`join(a, a) == a`), commutative and associative, i.e. a proper lattice
+/// join. Nothing here can enforce that, but the property checks can verify it.
+/// Wrap the register in a newtype deriving `Crdt` with `#[crdt(transparent)]`,
+/// implement `Arbitrary` for it, and run the `properties::check_*` functions.
+///
+/// There is no `Default`: a register starts from whatever value is passed to
+/// [`FnRegister::new`], which should be the bottom of the lattice.
+///
+/// # Type Parameters
+/// * `T`: The type of the stored value.
+/// * `F`: The join, a `Fn(&T, &T) -> T`.
+#[derive(Clone)]
+pub struct FnRegister<T, F> {
+    value: T,
+    join: F,
+}
+
+impl<T, F> FnRegister<T, F>
+where
+    F: Fn(&T, &T) -> T,
+{
+    /// Creates a register holding `value`, merged with `join`.
+    pub fn new(value: T, join: F) -> Self {
+        Self { value, join }
+    }
+
+    /// Joins `value` into the register.
+    pub fn set(&mut self, value: T) {
+        self.value = (self.join)(&self.value, &value);
+    }
+
+    /// Returns a reference to the current value.
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+}
+
+/// Registers compare by value alone: closures cannot be compared.
+impl<T: PartialEq, F> PartialEq for FnRegister<T, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq, F> Eq for FnRegister<T, F> {}
+
+impl<T: fmt::Debug, F> fmt::Debug for FnRegister<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FnRegister").field(&self.value).finish()
+    }
+}
+
+impl<T, F> Crdt for FnRegister<T, F>
+where
+    T: Clone + PartialEq + fmt::Debug,
+    F: Fn(&T, &T) -> T + Clone,
+{
+    type Value = T;
+
+    fn merge(&mut self, other: &Self) {
+        self.value = (self.join)(&self.value, &other.value);
+    }
+
+    fn value(&self) -> Self::Value {
+        self.value.clone()
+    }
+}
+
+impl<T, F> Apply for FnRegister<T, F>
+where
+    T: Clone + PartialEq + fmt::Debug,
+    F: Fn(&T, &T) -> T + Clone,
+{
+    type Op = T;
+    type Context = ();
+
+    fn apply(&mut self, op: Self::Op, _ctx: Self::Context) {
+        self.set(op);
+    }
+}
@@ -0,0 +1,210 @@
+use crate::{Apply, Crdt, IdempotentApply};
+use core::fmt::Debug;
+
+#[cfg(feature = "proptest")]
+use proptest::prelude::*;
+
+/// A totally ordered type with a least and a greatest value.
+///
+/// These are the bottoms of [`Min`] and [`Max`]: a fresh `Max` holds
+/// `Bounded::MIN`, so that merging it into any other `Max` changes nothing.
+pub trait Bounded: Ord {
+    /// The least value of the type.
+    const MIN: Self;
+    /// The greatest value of the type.
+    const MAX: Self;
+}
+
+macro_rules! impl_bounded {
+    ($($ty:ty),*) => {
+        $(
+            impl Bounded for $ty {
+                const MIN: Self = <$ty>::MIN;
+                const MAX: Self = <$ty>::MAX;
+            }
+        )*
+    };
+}
+
+impl_bounded!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char
+);
+
+impl Bounded for bool {
+    const MIN: Self = false;
+    const MAX: Self = true;
+}
+
+/// A register that only moves up: merging keeps the greater value.
+///
+/// # Type Parameters
+/// * `T`: The type of the stored value. Must be `Ord`. If it is also `Bounded`,
+///   the register is `Default`, and so has a [`Bottom`](crate::Bottom): a fresh
+///   register holding `MIN`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Max<T>(T);
+
+/// A register that only moves down: merging keeps the lesser value.
+///
+/// # Type Parameters
+/// * `T`: The type of the stored value. Must be `Ord`. If it is also `Bounded`,
+///   the register is `Default`, and so has a [`Bottom`](crate::Bottom): a fresh
+///   register holding `MAX`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Min<T>(T);
+
+impl<T: Bounded> Default for Max<T> {
+    fn default() -> Self {
+        Self(T::MIN)
+    }
+}
+
+impl<T: Bounded> Default for Min<T> {
+    fn default() -> Self {
+        Self(T::MAX)
+    }
+}
+
+impl<T> Crdt for Max<T>
+where
+    T: Ord + Clone + Debug,
+{
+    type Value = T;
+
+    fn merge(&mut self, other: &Self) {
+        self.set(other.0.clone());
+    }
+
+    fn value(&self) -> Self::Value {
+        self.0.clone()
+    }
+
+    fn leq(&self, other: &Self) -> bool {
+        self.0 <= other.0
+    }
+}
+
+impl<T> Crdt for Min<T>
+where
+    T: Ord + Clone + Debug,
+{
+    type Value = T;
+
+    fn merge(&mut self, other: &Self) {
+        self.set(other.0.clone());
+    }
+
+    fn value(&self) -> Self::Value {
+        self.0.clone()
+    }
+
+    fn leq(&self, other: &Self) -> bool {
+        self.0 >= other.0
+    }
+}
+
+impl<T> Apply for Max<T>
+where
+    T: Ord + Clone + Debug,
+{
+    type Op = T;
+    type Context = ();
+
+    fn apply(&mut self, op: Self::Op, _ctx: Self::Context) {
+        self.set(op);
+    }
+}
+
+impl<T> IdempotentApply for Max<T> where T: Ord + Clone + Debug {}
+
+impl<T> Apply for Min<T>
+where
+    T: Ord + Clone + Debug,
+{
+    type Op = T;
+    type Context = ();
+
+    fn apply(&mut self, op: Self::Op, _ctx: Self::Context) {
+        self.set(op);
+    }
+}
+
+impl<T> IdempotentApply for Min<T> where T: Ord + Clone + Debug {}
+
+impl<T: Ord> Max<T> {
+    /// Creates a register holding the given value.
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Raises the register to `value`; a lower value has no effect.
+    pub fn set(&mut self, value: T) {
+        if value > self.0 {
+            self.0 = value;
+        }
+    }
+
+    /// Returns a reference to the current value.
+    pub fn get(&self) -> &T {
+        &self.0
+    }
+
+    /// Consumes the register, returning its value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Ord> Min<T> {
+    /// Creates a register holding the given value.
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Lowers the register to `value`; a higher value has no effect.
+    pub fn set(&mut self, value: T) {
+        if value < self.0 {
+            self.0 = value;
+        }
+    }
+
+    /// Returns a reference to the current value.
+    pub fn get(&self) -> &T {
+        &self.0
+    }
+
+    /// Consumes the register, returning its value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl<T> Arbitrary for Max<T>
+where
+    T: Arbitrary + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<T>().prop_map(Max).boxed()
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl<T> Arbitrary for Min<T>
+where
+    T: Arbitrary + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<T>().prop_map(Min).boxed()
+    }
+}
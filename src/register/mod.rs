@@ -0,0 +1,7 @@
+pub mod dot;
+pub mod flag;
+pub mod fnreg;
+pub mod lww;
+pub mod minmax;
+#[cfg(feature = "std")]
+pub mod mv;
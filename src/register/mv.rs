@@ -0,0 +1,175 @@
+use crate::{Apply, Crdt, VectorClock};
+use std::collections::HashSet;
+use std::hash::Hash;
+
+#[cfg(feature = "proptest")]
+use proptest::prelude::*;
+
+/// A Multi-Value Register (MV-Register) CRDT.
+///
+/// Instead of picking a winner among concurrent writes, the register keeps all of
+/// them. Each value is tagged with the vector clock of the write that produced it;
+/// on merge, values whose clock happened before another retained clock are dropped,
+/// while concurrent values survive side by side until a later write supersedes them.
+///
+/// # Type Parameters
+/// * `I`: The type of the Replica ID. Must be `Hash`, `Eq`, `Clone`, and `Debug`.
+/// * `T`: The type of the stored values. Must be `Hash`, `Eq`, `Clone`, and `Debug`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone)]
+pub struct MVRegister<I, T>
+where
+    I: Hash + Eq,
+{
+    /// The concurrent writes, none of which happened before another.
+    entries: Vec<(VectorClock<I>, T)>,
+}
+
+impl<I: Hash + Eq, T> Default for MVRegister<I, T> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<I, T> PartialEq for MVRegister<I, T>
+where
+    I: Hash + Eq + Clone,
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        // Entries are a set: equal registers hold the same writes in any order.
+        self.entries.len() == other.entries.len()
+            && self
+                .entries
+                .iter()
+                .all(|entry| other.entries.contains(entry))
+    }
+}
+
+impl<I: Hash + Eq + Clone, T: Eq> Eq for MVRegister<I, T> {}
+
+impl<I, T> Crdt for MVRegister<I, T>
+where
+    I: Hash + Eq + Clone + std::fmt::Debug,
+    T: Hash + Eq + Clone + std::fmt::Debug,
+{
+    type Value = HashSet<T>;
+
+    fn merge(&mut self, other: &Self) {
+        for entry in &other.entries {
+            if !self.entries.contains(entry) {
+                self.entries.push(entry.clone());
+            }
+        }
+
+        // Keep only the writes that no other retained write supersedes.
+        let all = self.entries.clone();
+        self.entries.retain(|(clock, _)| {
+            !all.iter()
+                .any(|(other_clock, _)| clock.happened_before(other_clock))
+        });
+    }
+
+    fn value(&self) -> Self::Value {
+        self.entries
+            .iter()
+            .map(|(_, value)| value.clone())
+            .collect()
+    }
+}
+
+impl<I, T> Apply for MVRegister<I, T>
+where
+    I: Hash + Eq + Clone + std::fmt::Debug,
+    T: Hash + Eq + Clone + std::fmt::Debug,
+{
+    type Op = T;
+    type Context = I;
+
+    fn apply(&mut self, op: Self::Op, ctx: Self::Context) {
+        self.set(op, ctx);
+    }
+}
+
+impl<I, T> MVRegister<I, T>
+where
+    I: Hash + Eq + Clone + std::fmt::Debug,
+{
+    /// Creates a new, empty MV-Register.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Writes a value on behalf of the given replica.
+    ///
+    /// The write supersedes every value currently held, so all concurrent
+    /// values collapse into this one.
+    pub fn set(&mut self, value: T, replica: I) {
+        let mut clock = VectorClock::new();
+        for (entry_clock, _) in &self.entries {
+            clock.merge(entry_clock);
+        }
+        clock.inc(replica);
+        self.entries = vec![(clock, value)];
+    }
+
+    /// Merges another register, then collapses any concurrent values into the
+    /// one chosen by `on_conflict`.
+    ///
+    /// `on_conflict` is called pairwise, with the winner so far and the next
+    /// concurrent value, and returns the value to keep. The result is tagged
+    /// with the join of the conflicting clocks, so it supersedes every one of
+    /// them on later merges. Plain [`Crdt::merge`] keeps all concurrent values
+    /// instead. Replicas only converge if `on_conflict` is commutative and
+    /// associative, since the values may arrive in any order.
+    pub fn merge_with<F>(&mut self, other: &Self, mut on_conflict: F)
+    where
+        T: Hash + Eq + Clone + std::fmt::Debug,
+        F: FnMut(&T, &T) -> T,
+    {
+        self.merge(other);
+        let mut entries = std::mem::take(&mut self.entries).into_iter();
+        let Some((mut clock, mut winner)) = entries.next() else {
+            return;
+        };
+        for (entry_clock, value) in entries {
+            clock.merge(&entry_clock);
+            if value != winner {
+                winner = on_conflict(&winner, &value);
+            }
+        }
+        self.entries = vec![(clock, winner)];
+    }
+
+    /// Returns true if the register holds more than one concurrent value.
+    pub fn is_conflicted(&self) -> bool {
+        self.entries.len() > 1
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for MVRegister<String, String> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        // Fold random single-write registers together so the entries always form
+        // an antichain, as they would after real merges.
+        proptest::collection::vec(
+            (any::<VectorClock<String>>(), "[a-c]".prop_map(String::from)),
+            0..4,
+        )
+        .prop_map(|writes| {
+            let mut register = MVRegister::new();
+            for entry in writes {
+                register.merge(&MVRegister {
+                    entries: vec![entry],
+                });
+            }
+            register
+        })
+        .boxed()
+    }
+}
@@ -1,3 +1,8 @@
+#[cfg(target_has_atomic = "64")]
+use alloc::sync::Arc;
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::{AtomicU64, Ordering};
+
 /// A trait representing an entity capable of identifying itself in a distributed system.
 ///
 /// A `Replica` is responsible for managing its own identity. It produces an `Id` that can
@@ -27,3 +32,96 @@ pub trait Replica {
     /// - For structural identifiers, this recombines the identity space (e.g., merging intervals).
     fn join(&mut self, other: Self);
 }
+
+/// A [`Replica`] that hands out sequential `u64` ids.
+///
+/// Every replica forked from the same origin shares one counter, so ids never
+/// collide within a process. Useful for tests and benchmarks; ids are not
+/// unique across processes.
+#[cfg(target_has_atomic = "64")]
+#[derive(Debug, Clone)]
+pub struct CounterReplica {
+    id: u64,
+    next: Arc<AtomicU64>,
+}
+
+#[cfg(target_has_atomic = "64")]
+impl Default for CounterReplica {
+    fn default() -> Self {
+        CounterReplica {
+            id: 0,
+            next: Arc::new(AtomicU64::new(1)),
+        }
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl CounterReplica {
+    /// Creates the origin replica, with id 0 and a fresh sequence.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl Replica for CounterReplica {
+    type Id = u64;
+
+    fn id(&self) -> Self::Id {
+        self.id
+    }
+
+    fn fork(&mut self) -> Self {
+        CounterReplica {
+            id: self.next.fetch_add(1, Ordering::Relaxed),
+            next: Arc::clone(&self.next),
+        }
+    }
+
+    fn join(&mut self, _other: Self) {}
+}
+
+/// A [`Replica`] identified by a random (version 4) UUID.
+///
+/// The simplest identity strategy: every replica draws its own id, and with
+/// 122 random bits collisions are not a practical concern. `fork` creates an
+/// unrelated replica and `join` discards the other id.
+#[cfg(feature = "uuid")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UuidReplica {
+    id: uuid::Uuid,
+}
+
+#[cfg(feature = "uuid")]
+impl Default for UuidReplica {
+    fn default() -> Self {
+        UuidReplica {
+            id: uuid::Uuid::new_v4(),
+        }
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl UuidReplica {
+    /// Creates a replica with a fresh random id.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl Replica for UuidReplica {
+    type Id = uuid::Uuid;
+
+    fn id(&self) -> Self::Id {
+        self.id
+    }
+
+    fn fork(&mut self) -> Self {
+        UuidReplica::new()
+    }
+
+    fn join(&mut self, _other: Self) {}
+}
@@ -0,0 +1,154 @@
+use crate::collections::{Key, Map};
+use crate::sequence::rga::ElementId;
+use crate::{Apply, Crdt};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+#[cfg(feature = "proptest")]
+use alloc::string::String;
+#[cfg(feature = "proptest")]
+use proptest::prelude::*;
+#[cfg(feature = "proptest")]
+use proptest::sample::Index;
+
+/// A grow-only log: an append-only list, e.g. for an audit trail or chat history.
+///
+/// Every entry is tagged with the dot of its append, an [`ElementId`] whose
+/// `seq` is a Lamport timestamp. The log is read in id order, by timestamp
+/// and then by replica, which is a total order every replica agrees on. An
+/// entry always lands after every entry its replica had seen when appending
+/// it; concurrent appends are ordered by timestamp, then replica.
+///
+/// Entries are never removed, so merging is the union of the entries.
+///
+/// # Type Parameters
+/// * `T`: The type of entries in the log. Must implement `Clone` and `Debug`.
+/// * `I`: The type of the Replica ID. Must be `Hash`, `Eq`, `Ord`, `Clone`, and `Debug`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GrowLog<T, I>
+where
+    I: Key,
+{
+    entries: Map<ElementId<I>, T>,
+    /// The largest `seq` seen, used to mint the next Lamport timestamp.
+    clock: u64,
+}
+
+impl<T, I: Key> Default for GrowLog<T, I> {
+    fn default() -> Self {
+        Self {
+            entries: Map::new(),
+            clock: 0,
+        }
+    }
+}
+
+impl<T, I> Crdt for GrowLog<T, I>
+where
+    T: Clone + Debug + PartialEq,
+    I: Key + Ord + Clone + Debug,
+{
+    type Value = Vec<T>;
+
+    fn merge(&mut self, other: &Self) {
+        // Ids are unique per append, so the same id always names the same value.
+        for (id, value) in &other.entries {
+            self.entries
+                .entry(id.clone())
+                .or_insert_with(|| value.clone());
+        }
+        self.clock = self.clock.max(other.clock);
+    }
+
+    fn value(&self) -> Self::Value {
+        self.ordered()
+            .into_iter()
+            .map(|(_, value)| value.clone())
+            .collect()
+    }
+
+    fn leq(&self, other: &Self) -> bool {
+        self.clock <= other.clock && self.entries.keys().all(|id| other.entries.contains_key(id))
+    }
+}
+
+impl<T, I> Apply for GrowLog<T, I>
+where
+    T: Clone + Debug + PartialEq,
+    I: Key + Ord + Clone + Debug,
+{
+    type Op = T;
+    type Context = I;
+
+    fn apply(&mut self, op: Self::Op, ctx: Self::Context) {
+        self.append(op, ctx);
+    }
+}
+
+impl<T, I> GrowLog<T, I>
+where
+    I: Key + Ord + Clone,
+{
+    /// Creates a new, empty log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a value on behalf of the given replica, returning its id.
+    pub fn append(&mut self, value: T, replica: I) -> ElementId<I> {
+        self.clock += 1;
+        let id = ElementId {
+            replica,
+            seq: self.clock,
+        };
+        self.entries.insert(id.clone(), value);
+        id
+    }
+
+    /// Returns the entry with the given id, or `None` if it is unknown.
+    pub fn get(&self, id: &ElementId<I>) -> Option<&T> {
+        self.entries.get(id)
+    }
+
+    /// Returns the entries with their ids, in log order.
+    pub fn iter(&self) -> impl Iterator<Item = (&ElementId<I>, &T)> {
+        self.ordered().into_iter()
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the log has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn ordered(&self) -> Vec<(&ElementId<I>, &T)> {
+        let mut entries: Vec<_> = self.entries.iter().collect();
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+        entries
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for GrowLog<String, String> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            crate::arbitrary::replica_names_strategy(),
+            proptest::collection::vec((any::<Index>(), "[a-e]"), 0..8),
+        )
+            .prop_map(|(replicas, appends)| {
+                let mut log = GrowLog::new();
+                for (replica, value) in appends {
+                    log.append(value, replica.get(&replicas).clone());
+                }
+                log
+            })
+            .boxed()
+    }
+}
@@ -0,0 +1,2 @@
+pub mod growlog;
+pub mod rga;
@@ -0,0 +1,283 @@
+use crate::collections::{Key, Map};
+use crate::{Apply, Crdt};
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::Debug;
+
+#[cfg(feature = "proptest")]
+use alloc::string::String;
+#[cfg(feature = "proptest")]
+use proptest::prelude::*;
+#[cfg(feature = "proptest")]
+use proptest::sample::Index;
+
+/// The unique identifier of an element in an [`Rga`]: the dot of the insert
+/// that created it.
+///
+/// `seq` is a Lamport timestamp, so an element always has a larger `seq` than
+/// every element its inserting replica had seen. Ids are ordered by `seq`, then
+/// by `replica`, which is what breaks ties between concurrent inserts.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ElementId<I> {
+    pub replica: I,
+    pub seq: u64,
+}
+
+impl<I: Ord> Ord for ElementId<I> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.seq
+            .cmp(&other.seq)
+            .then_with(|| self.replica.cmp(&other.replica))
+    }
+}
+
+impl<I: Ord> PartialOrd for ElementId<I> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// An element of an [`Rga`], kept as a tombstone once removed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Node<T, I> {
+    value: T,
+    /// The element this one was inserted after, or `None` for the start.
+    parent: Option<ElementId<I>>,
+    removed: bool,
+}
+
+/// A Replicated Growable Array (RGA) sequence CRDT, e.g. for collaborative text.
+///
+/// Every element records the element it was inserted after, forming a tree
+/// rooted at the start of the sequence. The sequence is read by walking the
+/// tree depth-first, visiting the children of each element newest first
+/// (by [`ElementId`] order). Because an insert always carries a larger id than
+/// anything its replica had seen, an element typed after another lands right
+/// behind it, and concurrent inserts at the same position are ordered by id on
+/// every replica. A run of text typed by one replica is never interleaved with
+/// another replica's concurrent run.
+///
+/// Removed elements stay as tombstones so that later inserts can still refer
+/// to them as a position.
+///
+/// # Type Parameters
+/// * `T`: The type of elements in the sequence. Must implement `Clone` and `Debug`.
+/// * `I`: The type of the Replica ID. Must be `Hash`, `Eq`, `Ord`, `Clone`, and `Debug`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rga<T, I>
+where
+    I: Key,
+{
+    nodes: Map<ElementId<I>, Node<T, I>>,
+    /// The largest `seq` seen, used to mint the next Lamport timestamp.
+    clock: u64,
+}
+
+/// An operation on an [`Rga`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RgaOp<T, I> {
+    /// Insert a value after the given element, or at the start for `None`.
+    InsertAfter(Option<ElementId<I>>, T),
+    Remove(ElementId<I>),
+}
+
+impl<T, I: Key> Default for Rga<T, I> {
+    fn default() -> Self {
+        Self {
+            nodes: Map::new(),
+            clock: 0,
+        }
+    }
+}
+
+impl<T, I> Crdt for Rga<T, I>
+where
+    T: Clone + Debug + PartialEq,
+    I: Key + Ord + Clone + Debug,
+{
+    type Value = Vec<T>;
+
+    fn merge(&mut self, other: &Self) {
+        // Ids are unique per insert, so the same id always names the same
+        // value and parent; only the tombstone flag can differ.
+        for (id, node) in &other.nodes {
+            self.nodes
+                .entry(id.clone())
+                .and_modify(|existing| existing.removed |= node.removed)
+                .or_insert_with(|| node.clone());
+        }
+        self.clock = self.clock.max(other.clock);
+    }
+
+    fn value(&self) -> Self::Value {
+        self.ordered()
+            .into_iter()
+            .filter_map(|id| {
+                let node = &self.nodes[id];
+                (!node.removed).then(|| node.value.clone())
+            })
+            .collect()
+    }
+}
+
+impl<T, I> Apply for Rga<T, I>
+where
+    T: Clone + Debug + PartialEq,
+    I: Key + Ord + Clone + Debug,
+{
+    type Op = RgaOp<T, I>;
+    type Context = I;
+
+    fn apply(&mut self, op: Self::Op, ctx: Self::Context) {
+        match op {
+            RgaOp::InsertAfter(position, value) => {
+                self.insert_after(position.as_ref(), value, ctx);
+            }
+            RgaOp::Remove(id) => {
+                self.remove(&id);
+            }
+        }
+    }
+}
+
+impl<T, I> Rga<T, I>
+where
+    I: Key + Ord + Clone,
+{
+    /// Creates a new, empty sequence.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a value after the given element (or at the start for `None`) on
+    /// behalf of the given replica, returning the new element's id.
+    ///
+    /// The position may be a removed element.
+    ///
+    /// # Panics
+    /// Panics if `position` names an element this replica has never seen.
+    pub fn insert_after(
+        &mut self,
+        position: Option<&ElementId<I>>,
+        value: T,
+        replica: I,
+    ) -> ElementId<I> {
+        if let Some(position) = position {
+            assert!(
+                self.nodes.contains_key(position),
+                "cannot insert after an unknown element"
+            );
+        }
+        self.clock += 1;
+        let id = ElementId {
+            replica,
+            seq: self.clock,
+        };
+        self.nodes.insert(
+            id.clone(),
+            Node {
+                value,
+                parent: position.cloned(),
+                removed: false,
+            },
+        );
+        id
+    }
+
+    /// Removes an element, leaving a tombstone. Returns true if the element was
+    /// present and not already removed.
+    pub fn remove(&mut self, id: &ElementId<I>) -> bool {
+        match self.nodes.get_mut(id) {
+            Some(node) if !node.removed => {
+                node.removed = true;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the ids of the live elements, in sequence order.
+    pub fn ids(&self) -> Vec<ElementId<I>> {
+        self.ordered()
+            .into_iter()
+            .filter(|id| !self.nodes[*id].removed)
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the value of a live element, or `None` if it is unknown or removed.
+    pub fn get(&self, id: &ElementId<I>) -> Option<&T> {
+        self.nodes
+            .get(id)
+            .filter(|node| !node.removed)
+            .map(|node| &node.value)
+    }
+
+    /// Returns the number of live elements.
+    pub fn len(&self) -> usize {
+        self.nodes.values().filter(|node| !node.removed).count()
+    }
+
+    /// Returns true if the sequence has no live elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns every element id, tombstones included, in sequence order.
+    fn ordered(&self) -> Vec<&ElementId<I>> {
+        let mut children: BTreeMap<Option<&ElementId<I>>, Vec<&ElementId<I>>> = BTreeMap::new();
+        for (id, node) in &self.nodes {
+            children.entry(node.parent.as_ref()).or_default().push(id);
+        }
+        for siblings in children.values_mut() {
+            // Ascending, so popping from the stack visits the newest first.
+            siblings.sort();
+        }
+
+        let mut order = Vec::with_capacity(self.nodes.len());
+        let mut stack: Vec<&ElementId<I>> = children.get(&None).cloned().unwrap_or_default();
+        while let Some(id) = stack.pop() {
+            order.push(id);
+            if let Some(siblings) = children.get(&Some(id)) {
+                stack.extend(siblings.iter().copied());
+            }
+        }
+        order
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for Rga<String, String> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            crate::arbitrary::replica_names_strategy(),
+            proptest::collection::vec(
+                (any::<Index>(), any::<Index>(), "[a-e]", any::<bool>()),
+                0..8,
+            ),
+        )
+            .prop_map(|(replicas, ops)| {
+                let mut rga = Rga::new();
+                for (replica, position, value, is_insert) in ops {
+                    let ids = rga.ids();
+                    if is_insert {
+                        // Index 0 inserts at the start, index n after the nth element.
+                        let after = match position.index(ids.len() + 1) {
+                            0 => None,
+                            n => Some(&ids[n - 1]),
+                        };
+                        rga.insert_after(after, value, replica.get(&replicas).clone());
+                    } else if !ids.is_empty() {
+                        rga.remove(position.get(&ids));
+                    }
+                }
+                rga
+            })
+            .boxed()
+    }
+}
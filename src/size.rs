@@ -0,0 +1,89 @@
+//! Cheap estimates of how many bytes a state takes to serialize, for choosing
+//! between full-state and delta sync without encoding anything.
+//!
+//! Estimates assume a fixed-width layout: integers at their in-memory width,
+//! and strings and collections prefixed with a `u64` length. That is exactly
+//! bincode's `legacy` configuration, and an upper bound on the varint encoding
+//! used by [`Wire`](crate::Wire).
+
+use crate::collections::Key;
+use crate::{GCounter, GSet, VectorClock};
+use alloc::string::String;
+use core::mem::size_of;
+
+/// Bytes spent on the length of a string or collection.
+const LEN_PREFIX: usize = size_of::<u64>();
+
+/// The estimated encoded size of a key or element, in bytes.
+pub trait SizeHint {
+    /// Returns the estimated encoded size of `self`.
+    fn size_hint(&self) -> usize;
+}
+
+macro_rules! impl_size_hint_fixed {
+    ($($ty:ty),*) => {
+        $(
+            impl SizeHint for $ty {
+                fn size_hint(&self) -> usize {
+                    size_of::<$ty>()
+                }
+            }
+        )*
+    };
+}
+
+impl_size_hint_fixed!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, bool, char
+);
+
+impl SizeHint for str {
+    fn size_hint(&self) -> usize {
+        LEN_PREFIX + self.len()
+    }
+}
+
+impl SizeHint for String {
+    fn size_hint(&self) -> usize {
+        self.as_str().size_hint()
+    }
+}
+
+impl<T: SizeHint + ?Sized> SizeHint for &T {
+    fn size_hint(&self) -> usize {
+        (**self).size_hint()
+    }
+}
+
+/// A CRDT state whose serialized size can be estimated without serializing it.
+pub trait EstimateSize {
+    /// Returns the estimated encoded size of the whole state, in bytes.
+    fn estimated_size(&self) -> usize;
+}
+
+/// Each entry costs its replica ID plus a `u64` count.
+impl<I: Key + Clone + SizeHint> EstimateSize for GCounter<I> {
+    fn estimated_size(&self) -> usize {
+        LEN_PREFIX
+            + self
+                .contributions()
+                .map(|(replica, _)| replica.size_hint() + size_of::<u64>())
+                .sum::<usize>()
+    }
+}
+
+impl<T: Key + SizeHint> EstimateSize for GSet<T> {
+    fn estimated_size(&self) -> usize {
+        LEN_PREFIX + self.iter().map(SizeHint::size_hint).sum::<usize>()
+    }
+}
+
+/// Each entry costs its replica ID plus a `u64` count.
+impl<I: Key + Clone + SizeHint> EstimateSize for VectorClock<I> {
+    fn estimated_size(&self) -> usize {
+        LEN_PREFIX
+            + self
+                .replicas()
+                .map(|replica| replica.size_hint() + size_of::<u64>())
+                .sum::<usize>()
+    }
+}
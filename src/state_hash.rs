@@ -0,0 +1,62 @@
+use core::hash::{Hash, Hasher};
+
+/// A 64-bit FNV-1a hasher whose output is the same on every platform and
+/// Rust version, unlike `std`'s `DefaultHasher`.
+///
+/// Integers are written little-endian and `usize` is widened to 64 bits, so
+/// `Hash` impls that feed integers in native byte order still hash identically
+/// across architectures.
+pub(crate) struct StableHasher(u64);
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        StableHasher(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    fn write_u16(&mut self, n: u16) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, n: u32) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, n: u128) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, n: usize) {
+        self.write_u64(n as u64);
+    }
+}
+
+/// Hashes `items` in sorted order, so the result does not depend on the
+/// iteration order of the collection they came from.
+pub(crate) fn hash_sorted<T: Hash + Ord>(items: impl Iterator<Item = T>) -> u64 {
+    let mut items: alloc::vec::Vec<T> = items.collect();
+    items.sort_unstable();
+
+    let mut hasher = StableHasher::default();
+    items.len().hash(&mut hasher);
+    for item in items {
+        item.hash(&mut hasher);
+    }
+    hasher.finish()
+}
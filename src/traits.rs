@@ -1,3 +1,5 @@
+use crate::Crdt;
+
 /// A trait for Conflict-Free Replicated Data Types that support updates via operations.
 ///
 /// While `Crdt` handles merging state, `Apply` (Commutative Replicated Data Type)
@@ -19,4 +21,107 @@ pub trait Apply {
 
     /// Applies an operation to the CRDT.
     fn apply(&mut self, op: Self::Op, ctx: Self::Context);
+
+    /// Applies a batch of operations in order, e.g. when replaying an op log.
+    ///
+    /// Override this when the CRDT can apply a batch more cheaply than one op
+    /// at a time.
+    fn apply_all(&mut self, ops: impl IntoIterator<Item = (Self::Op, Self::Context)>) {
+        for (op, ctx) in ops {
+            self.apply(op, ctx);
+        }
+    }
+}
+
+/// Marks an [`Apply`] whose operations are idempotent: applying the same op
+/// with the same context twice leaves the state as applying it once.
+///
+/// Delta and op-based deployments that may redeliver an event rely on this.
+/// Implement it only when it holds for every op; [`ApplyChecked::apply_checked`]
+/// catches types that claim it wrongly.
+pub trait IdempotentApply: Apply {}
+
+/// Applies operations while checking, in debug builds, that they really are
+/// idempotent.
+///
+/// Implemented for every [`IdempotentApply`] CRDT whose op and context can be
+/// cloned.
+pub trait ApplyChecked: IdempotentApply {
+    /// Applies an operation like [`Apply::apply`].
+    ///
+    /// In debug builds the op is then applied a second time, as a redelivery
+    /// would, and the state must be unchanged.
+    ///
+    /// # Panics
+    /// Panics in debug builds if re-applying the op changed the state.
+    fn apply_checked(&mut self, op: Self::Op, ctx: Self::Context);
+}
+
+impl<T> ApplyChecked for T
+where
+    T: Crdt + IdempotentApply,
+    T::Op: Clone,
+    T::Context: Clone,
+{
+    fn apply_checked(&mut self, op: Self::Op, ctx: Self::Context) {
+        if !cfg!(debug_assertions) {
+            self.apply(op, ctx);
+            return;
+        }
+        self.apply(op.clone(), ctx.clone());
+        let once = self.clone();
+        self.apply(op, ctx);
+        debug_assert!(
+            *self == once,
+            "apply is not idempotent: re-applying the same op changed the state\n\
+            after one apply: {}\n\
+            after two:       {}",
+            once.debug_summary(),
+            self.debug_summary()
+        );
+    }
+}
+
+/// A companion to [`Apply`] for operations that can be rejected.
+///
+/// `Apply::apply` silently ignores operations that make no sense for the
+/// current state (e.g. removing an element that is not present). `try_apply`
+/// reports them instead, leaving the state unchanged.
+pub trait TryApply: Apply {
+    /// Why an operation was rejected.
+    type Error;
+
+    /// Applies an operation to the CRDT, or returns an error without changing it.
+    fn try_apply(&mut self, op: Self::Op, ctx: Self::Context) -> Result<(), Self::Error>;
+}
+
+/// A delta-state CRDT that can ship only its recent changes.
+///
+/// Instead of sending the full state after every update, a replica accumulates
+/// its changes and periodically ships them as a `Delta`, a state fragment that
+/// lives in the same join-semilattice as the full state. Receivers apply it with
+/// `merge_delta`.
+///
+/// ## Contract
+///
+/// Applying every delta a replica has produced (in any order, any number of
+/// times) must converge to the same state as merging that replica's full state.
+/// Equivalently: for a replica `A` that produced deltas `d1..dn` since it was in
+/// state `A0`, `B ⊔ A0 ⊔ d1 ⊔ ... ⊔ dn = B ⊔ A`.
+///
+/// Unlike [`DeltaSync`](crate::DeltaSync), which computes a delta against a
+/// peer's summary on demand, a `DeltaCrdt` tracks what changed locally, so no
+/// round trip is needed before sending.
+pub trait DeltaCrdt: Crdt {
+    /// The delta payload, usually the same type as `Self`.
+    type Delta: Crdt;
+
+    /// Returns the changes accumulated since the last [`reset_delta`](Self::reset_delta).
+    fn delta(&self) -> Self::Delta;
+
+    /// Marks the current state as shipped, so the next `delta` only contains newer changes.
+    fn reset_delta(&mut self);
+
+    /// Merges a delta received from another replica into this state.
+    fn merge_delta(&mut self, delta: &Self::Delta);
 }
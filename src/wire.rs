@@ -0,0 +1,137 @@
+//! A compact binary wire format for syncing CRDT states, built on the serde
+//! derives and [bincode](https://docs.rs/bincode).
+//!
+//! JSON spells out every field name and writes numbers as text; the bincode
+//! encoding writes integers as varints and nothing else, so states are usually
+//! several times smaller. Both ends must use the same version of this crate.
+
+use crate::collections::Key;
+use crate::{GCounter, GSet, ItcClock, ItcId, ItcReplica, ItcStamp, VectorClock};
+use alloc::vec::Vec;
+use core::fmt;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// Error returned by [`Wire::from_wire`] when the bytes are not a valid encoding.
+#[derive(Debug)]
+pub enum WireError {
+    /// The bytes do not decode as the expected type.
+    Malformed(bincode::error::DecodeError),
+    /// Bytes were left over after the value was decoded.
+    TrailingBytes,
+}
+
+impl fmt::Display for WireError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WireError::Malformed(err) => write!(f, "malformed wire encoding: {err}"),
+            WireError::TrailingBytes => write!(f, "trailing bytes after wire encoding"),
+        }
+    }
+}
+
+impl core::error::Error for WireError {}
+
+/// Types that can be sent between replicas in the compact binary format.
+///
+/// Requires the `bincode` feature.
+pub trait Wire: Sized {
+    /// Encodes `self` for the wire.
+    fn to_wire(&self) -> Vec<u8>;
+
+    /// Decodes a value produced by [`Wire::to_wire`].
+    fn from_wire(bytes: &[u8]) -> Result<Self, WireError>;
+}
+
+fn encode<T: Serialize>(value: &T) -> Vec<u8> {
+    bincode::serde::encode_to_vec(value, bincode::config::standard())
+        .expect("CRDT states always serialize with bincode")
+}
+
+fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, WireError> {
+    let (value, read) = bincode::serde::decode_from_slice(bytes, bincode::config::standard())
+        .map_err(WireError::Malformed)?;
+    if read != bytes.len() {
+        return Err(WireError::TrailingBytes);
+    }
+    Ok(value)
+}
+
+impl<I> Wire for GCounter<I>
+where
+    I: Key + Serialize + DeserializeOwned,
+{
+    fn to_wire(&self) -> Vec<u8> {
+        encode(self)
+    }
+
+    fn from_wire(bytes: &[u8]) -> Result<Self, WireError> {
+        decode(bytes)
+    }
+}
+
+impl<T> Wire for GSet<T>
+where
+    T: Key + Serialize + DeserializeOwned,
+{
+    fn to_wire(&self) -> Vec<u8> {
+        encode(self)
+    }
+
+    fn from_wire(bytes: &[u8]) -> Result<Self, WireError> {
+        decode(bytes)
+    }
+}
+
+impl<I> Wire for VectorClock<I>
+where
+    I: Key + Serialize + DeserializeOwned,
+{
+    fn to_wire(&self) -> Vec<u8> {
+        encode(self)
+    }
+
+    fn from_wire(bytes: &[u8]) -> Result<Self, WireError> {
+        decode(bytes)
+    }
+}
+
+impl Wire for ItcId {
+    fn to_wire(&self) -> Vec<u8> {
+        encode(self)
+    }
+
+    fn from_wire(bytes: &[u8]) -> Result<Self, WireError> {
+        decode(bytes)
+    }
+}
+
+impl Wire for ItcReplica {
+    fn to_wire(&self) -> Vec<u8> {
+        encode(self)
+    }
+
+    fn from_wire(bytes: &[u8]) -> Result<Self, WireError> {
+        decode(bytes)
+    }
+}
+
+impl Wire for ItcClock {
+    fn to_wire(&self) -> Vec<u8> {
+        encode(self)
+    }
+
+    fn from_wire(bytes: &[u8]) -> Result<Self, WireError> {
+        decode(bytes)
+    }
+}
+
+impl Wire for ItcStamp {
+    fn to_wire(&self) -> Vec<u8> {
+        encode(self)
+    }
+
+    fn from_wire(bytes: &[u8]) -> Result<Self, WireError> {
+        decode(bytes)
+    }
+}
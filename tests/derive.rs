@@ -0,0 +1,12 @@
+//! Compile tests for the `crdt-derive` macros.
+//!
+//! Passing cases are compiled and run (their `main` asserts the merge behavior);
+//! failing cases must be rejected with the error recorded in the `.stderr` file.
+
+#[cfg(feature = "derive")]
+#[test]
+fn derive_ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/pass/*.rs");
+    t.compile_fail("tests/ui/fail/*.rs");
+}
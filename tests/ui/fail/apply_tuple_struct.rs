@@ -0,0 +1,6 @@
+use crdt::{Apply, GCounter};
+
+#[derive(Apply)]
+struct Meters(GCounter<String>);
+
+fn main() {}
@@ -0,0 +1,14 @@
+use crdt::Crdt;
+
+#[derive(Debug, Clone, PartialEq, Default, Crdt)]
+enum Status {
+    #[default]
+    #[crdt(rank = 0)]
+    Draft,
+    #[crdt(rank = 1)]
+    Approved,
+    #[crdt(rank = 1)]
+    Rejected,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use crdt::Crdt;
+
+#[derive(Debug, Clone, PartialEq, Default, Crdt)]
+enum Status {
+    #[default]
+    #[crdt(rank = 0)]
+    Draft,
+    Approved,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use crdt::{Crdt, GCounter};
+
+#[derive(Debug, Clone, PartialEq, Default, Crdt)]
+#[crdt(transparent)]
+struct Pair(GCounter<String>, GCounter<String>);
+
+fn main() {}
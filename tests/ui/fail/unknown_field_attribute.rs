@@ -0,0 +1,10 @@
+use crdt::{Crdt, GCounter};
+
+#[derive(Debug, Clone, PartialEq, Default, Crdt)]
+struct Labeled {
+    #[crdt(ignore)]
+    label: String,
+    hits: GCounter<String>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use crdt::Crdt;
+
+#[derive(Debug, Clone, PartialEq, Default, Crdt)]
+struct Session {
+    #[crdt(strategy = "sum")]
+    requests: u64,
+}
+
+fn main() {}
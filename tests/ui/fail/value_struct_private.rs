@@ -0,0 +1,13 @@
+mod stats {
+    use crdt::{Crdt, GCounter};
+
+    #[derive(Debug, Clone, PartialEq, Default, Crdt)]
+    #[crdt(value_struct)]
+    struct Stats {
+        visits: GCounter<String>,
+    }
+}
+
+fn main() {
+    let _: Option<stats::StatsValue> = None;
+}
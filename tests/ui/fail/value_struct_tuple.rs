@@ -0,0 +1,7 @@
+use crdt::{Crdt, GCounter};
+
+#[derive(Debug, Clone, PartialEq, Default, Crdt)]
+#[crdt(value_struct)]
+struct Pair(GCounter<String>, GCounter<String>);
+
+fn main() {}
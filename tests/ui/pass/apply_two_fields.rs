@@ -0,0 +1,24 @@
+use crdt::{Apply, Crdt, GCounter, GSet};
+
+#[derive(Debug, Clone, PartialEq, Default, Crdt, Apply)]
+struct Stats {
+    visits: GCounter<String>,
+    error_codes: GSet<u16>,
+    #[crdt(skip)]
+    label: String,
+}
+
+fn main() {
+    let mut stats = Stats::default();
+    stats.apply(StatsOp::Visits(2, "a".to_string()), ());
+    stats.apply(StatsOp::ErrorCodes(404, ()), ());
+    stats.apply_all([
+        (StatsOp::Visits(1, "b".to_string()), ()),
+        (StatsOp::ErrorCodes(500, ()), ()),
+    ]);
+
+    assert_eq!(stats.visits.value(), 3);
+    assert!(stats.error_codes.contains(&404));
+    assert!(stats.error_codes.contains(&500));
+    assert_eq!(stats.label, "");
+}
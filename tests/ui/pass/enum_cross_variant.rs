@@ -0,0 +1,41 @@
+use crdt::{Crdt, GCounter};
+
+/// Ranked explicitly: declaration order is not the lifecycle order.
+#[derive(Debug, Clone, PartialEq, Default, Crdt)]
+enum Ticket {
+    #[crdt(rank = 2)]
+    Closed,
+    #[default]
+    #[crdt(rank = 0)]
+    Open,
+    #[crdt(rank = 1)]
+    InReview(GCounter<String>),
+}
+
+/// Ranked by declaration order: later variants win.
+#[derive(Debug, Clone, PartialEq, Default, Crdt)]
+enum Light {
+    #[default]
+    Off,
+    On,
+}
+
+fn main() {
+    let open = Ticket::Open;
+    let review = Ticket::InReview(GCounter::new());
+
+    let mut a = open.clone();
+    a.merge(&review);
+    let mut b = review.clone();
+    b.merge(&open);
+    assert_eq!(a, review);
+    assert_eq!(a, b);
+
+    let mut closed = Ticket::Closed;
+    closed.merge(&review);
+    assert_eq!(closed, Ticket::Closed);
+
+    let mut light = Light::On;
+    light.merge(&Light::Off);
+    assert_eq!(light, Light::On);
+}
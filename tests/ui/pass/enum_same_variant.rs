@@ -0,0 +1,39 @@
+use crdt::{Crdt, GCounter};
+
+#[derive(Debug, Clone, PartialEq, Default, Crdt)]
+enum Phase {
+    #[default]
+    Idle,
+    Running {
+        steps: GCounter<String>,
+    },
+    Done(GCounter<String>),
+}
+
+fn main() {
+    let mut a = GCounter::new();
+    a.add(2, "a".to_string());
+    let mut b = GCounter::new();
+    b.add(3, "b".to_string());
+
+    // Same variant on both sides: the fields merge pairwise.
+    let mut left = Phase::Running { steps: a.clone() };
+    left.merge(&Phase::Running { steps: b.clone() });
+    match &left {
+        Phase::Running { steps } => assert_eq!(steps.value(), 5),
+        other => panic!("unexpected variant {other:?}"),
+    }
+
+    let mut done = Phase::Done(a);
+    done.merge(&Phase::Done(b));
+    assert_eq!(done, {
+        let mut both = GCounter::new();
+        both.add(2, "a".to_string());
+        both.add(3, "b".to_string());
+        Phase::Done(both)
+    });
+
+    let mut idle = Phase::Idle;
+    idle.merge(&Phase::Idle);
+    assert_eq!(idle, Phase::Idle);
+}
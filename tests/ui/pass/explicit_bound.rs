@@ -0,0 +1,43 @@
+use crdt::{Crdt, GCounter};
+use std::collections::BTreeMap;
+
+/// The generated bound `BTreeMap<String, Tree<T>>: Crdt` would require
+/// `Tree<T>: Crdt`, the very impl being defined, so it can never hold.
+/// The explicit bound names only what the fields really need.
+#[derive(Debug, Clone, PartialEq, Default, Crdt)]
+#[crdt(bound = "T: Crdt")]
+struct Tree<T> {
+    value: T,
+    children: BTreeMap<String, Tree<T>>,
+}
+
+/// Explicit bounds are added to the struct's own where-clause.
+#[derive(Debug, Clone, PartialEq, Default, Crdt)]
+#[crdt(bound = "T: Crdt + Send")]
+struct Shared<T>
+where
+    T: Sync,
+{
+    inner: T,
+}
+
+fn main() {
+    let mut a: Tree<GCounter<String>> = Tree::default();
+    let mut b = a.clone();
+    a.value.inc("a".to_string());
+    let mut child: Tree<GCounter<String>> = Tree::default();
+    child.value.add(2, "b".to_string());
+    b.children.insert("child".to_string(), child);
+
+    a.merge(&b);
+    assert_eq!(a.value.value(), 1);
+    assert_eq!(a.children["child"].value.value(), 2);
+
+    let mut shared = Shared {
+        inner: GCounter::<String>::new(),
+    };
+    shared.merge(&Shared {
+        inner: [("a".to_string(), 3)].into_iter().collect(),
+    });
+    assert_eq!(shared.inner.value(), 3);
+}
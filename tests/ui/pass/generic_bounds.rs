@@ -0,0 +1,40 @@
+use crdt::{Crdt, GCounter, GSet};
+
+/// No bounds written by hand: the derive adds `A: Crdt`, `B: Crdt`,
+/// and `V: Ord + Clone`, and nothing for the skipped `L`.
+#[derive(Debug, Clone, PartialEq, Default, Crdt)]
+struct Tagged<A, B, V, L> {
+    first: A,
+    second: B,
+    #[crdt(strategy = "max")]
+    version: V,
+    #[crdt(skip)]
+    label: L,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Crdt)]
+enum Slot<T> {
+    #[default]
+    Empty,
+    Filled(T),
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+struct NotACrdt;
+
+fn main() {
+    let mut a: Tagged<GCounter<String>, GSet<u8>, u64, NotACrdt> = Tagged::default();
+    let mut b = a.clone();
+    a.first.add(1, "a".to_string());
+    b.second.insert(7);
+    b.version = 3;
+
+    a.merge(&b);
+    assert_eq!(a.first.value(), 1);
+    assert!(a.second.contains(&7));
+    assert_eq!(a.version, 3);
+
+    let mut slot = Slot::Empty;
+    slot.merge(&Slot::Filled(GSet::<u8>::new()));
+    assert_eq!(slot, Slot::Filled(GSet::new()));
+}
@@ -0,0 +1,22 @@
+use crdt::{Crdt, GCounter, GSet};
+
+/// Optional fields start out as `None` and are adopted on merge.
+#[derive(Debug, Clone, PartialEq, Default, Crdt)]
+struct Profile {
+    visits: GCounter<String>,
+    badges: Option<GSet<String>>,
+}
+
+fn main() {
+    let mut a = Profile::default();
+    let mut b = Profile::default();
+    a.visits.inc("a".to_string());
+    b.badges.get_or_insert_with(GSet::new).insert("early".to_string());
+
+    a.merge(&b);
+    assert_eq!(a.visits.value(), 1);
+    assert!(a.badges.as_ref().unwrap().contains(&"early".to_string()));
+
+    b.merge(&a);
+    assert_eq!(a, b);
+}
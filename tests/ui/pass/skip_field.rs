@@ -0,0 +1,39 @@
+use crdt::{Crdt, GCounter};
+
+#[derive(Debug, Clone, PartialEq, Default, Crdt)]
+struct Labeled {
+    #[crdt(skip)]
+    label: String,
+    hits: GCounter<String>,
+}
+
+/// Not a CRDT: skipped fields are never merged, so this needs no `Crdt` impl.
+#[derive(Debug, Clone, PartialEq, Default)]
+struct LocalCache(Vec<u8>);
+
+#[derive(Debug, Clone, PartialEq, Default, Crdt)]
+struct Cached(GCounter<String>, #[crdt(skip)] LocalCache);
+
+fn main() {
+    let mut a = Labeled {
+        label: "local".to_string(),
+        hits: GCounter::new(),
+    };
+    a.hits.add(2, "a".to_string());
+    let mut b = Labeled {
+        label: "remote".to_string(),
+        hits: GCounter::new(),
+    };
+    b.hits.add(3, "b".to_string());
+
+    a.merge(&b);
+    assert_eq!(a.label, "local");
+    assert_eq!(a.hits.value(), 5);
+
+    let mut c = Cached(GCounter::new(), LocalCache(vec![1]));
+    let mut d = Cached(GCounter::new(), LocalCache(vec![2]));
+    d.0.inc("d".to_string());
+    c.merge(&d);
+    assert_eq!(c.0.value(), 1);
+    assert_eq!(c.1, LocalCache(vec![1]));
+}
@@ -0,0 +1,34 @@
+use crdt::{Crdt, GCounter};
+
+#[derive(Debug, Clone, PartialEq, Default, Crdt)]
+struct Session {
+    #[crdt(strategy = "max")]
+    last_seen: u64,
+    #[crdt(strategy = "min")]
+    first_seen: u64,
+    requests: GCounter<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Crdt)]
+struct HighWater(#[crdt(strategy = "max")] String);
+
+fn main() {
+    let mut a = Session {
+        last_seen: 10,
+        first_seen: 3,
+        requests: GCounter::new(),
+    };
+    let b = Session {
+        last_seen: 42,
+        first_seen: 7,
+        requests: GCounter::new(),
+    };
+
+    a.merge(&b);
+    assert_eq!(a.last_seen, 42);
+    assert_eq!(a.first_seen, 3);
+
+    let mut mark = HighWater("apple".to_string());
+    mark.merge(&HighWater("banana".to_string()));
+    assert_eq!(mark.0, "banana");
+}
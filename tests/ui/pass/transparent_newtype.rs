@@ -0,0 +1,28 @@
+use crdt::{Crdt, GCounter};
+
+/// A newtype whose value is the inner counter's `u64`, not `Meters` itself.
+#[derive(Debug, Clone, PartialEq, Default, Crdt)]
+#[crdt(transparent)]
+struct Meters(GCounter<String>);
+
+#[derive(Debug, Clone, PartialEq, Default, Crdt)]
+#[crdt(transparent)]
+struct Named {
+    inner: GCounter<String>,
+}
+
+fn main() {
+    let mut a = Meters::default();
+    let mut b = Meters::default();
+    a.0.add(3, "a".to_string());
+    b.0.add(4, "b".to_string());
+    a.merge(&b);
+
+    let total: u64 = a.value();
+    assert_eq!(total, 7);
+
+    let mut named = Named::default();
+    named.inner.inc("a".to_string());
+    let total: u64 = named.value();
+    assert_eq!(total, 1);
+}
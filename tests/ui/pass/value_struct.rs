@@ -0,0 +1,35 @@
+use crdt::{Crdt, GCounter};
+
+#[derive(Debug, Clone, PartialEq, Default, Crdt)]
+#[crdt(value_struct)]
+struct Stats {
+    visits: GCounter<String>,
+    errors: GCounter<String>,
+    #[crdt(strategy = "max")]
+    peak: u32,
+    #[crdt(skip)]
+    label: String,
+}
+
+fn main() {
+    let mut a = Stats::default();
+    let mut b = Stats::default();
+    a.visits.add(3, "a".to_string());
+    a.peak = 5;
+    b.visits.inc("b".to_string());
+    b.errors.inc("b".to_string());
+    b.peak = 2;
+    b.label = "ignored".to_string();
+    a.merge(&b);
+
+    let value: StatsValue = a.value();
+    assert_eq!(
+        value,
+        StatsValue {
+            visits: 4,
+            errors: 1,
+            peak: 5,
+        }
+    );
+    assert_eq!(a.label, "");
+}